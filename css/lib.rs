@@ -3,3 +3,6 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 pub use parser;
+
+pub mod shorthands;
+pub mod values;
@@ -4,9 +4,30 @@
 
 use crate::{
     component_value::{CSSComponentValue, CSSComponentValuesList},
+    declaration::CSSDeclarationList,
+    grammars::{CSSRule, CSSRuleList},
+    preserved_tokens::CSSPreservedToken,
     simple_block::CSSSimpleBlock,
+    tokenization::{CSSToken, CSSTokenVariant},
+    CSSParser,
 };
 
+// --------- //
+// Constante //
+// --------- //
+
+/// Les règles de groupe conditionnelles, dont le bloc est une liste de
+/// règles.
+const CONDITIONAL_GROUP_RULES: [&str; 2] = ["media", "supports"];
+
+// ---- //
+// Type //
+// ---- //
+
+/// Une étape d'une animation : la position de l'étape, en pourcentage de
+/// la durée de l'animation, et ses déclarations.
+pub type CSSKeyframe = (f32, CSSDeclarationList);
+
 // --------- //
 // Structure //
 // --------- //
@@ -20,11 +41,28 @@ use crate::{
 /// et le bloc une liste de déclarations.
 #[derive(Debug)]
 #[derive(Default)]
-#[derive(PartialEq, Eq)]
 pub struct CSSAtRule {
     name: String,
     prelude: CSSComponentValuesList,
     block: Option<CSSSimpleBlock>,
+    /// Les règles du bloc d'une règle de groupe conditionnelle,
+    /// analysées une seule fois lors de la consommation de la règle.
+    rules: Option<CSSRuleList>,
+    /// Nombre de règles de [CSSAtRule::rules], à toute profondeur.
+    nested_rules_count: usize,
+    source_order: usize,
+}
+
+/// Une règle `@keyframes` : `@keyframes <keyframes-name> {
+/// <rule-list> }`, où chaque règle de la liste est une étape de
+/// l'animation.
+///
+/// Voir <https://drafts.csswg.org/css-animations/#keyframes>
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct CSSKeyframesRule {
+    name: String,
+    keyframes: Vec<CSSKeyframe>,
 }
 
 // -------------- //
@@ -49,6 +87,113 @@ impl CSSAtRule {
     }
 }
 
+impl CSSAtRule {
+    /// Index d'apparition de la règle dans la feuille de style.
+    pub fn source_order(&self) -> usize {
+        self.source_order
+    }
+
+    /// Les règles du bloc d'une règle de groupe conditionnelle
+    /// (`@media`, `@supports`). Elles suivent la règle elle-même dans
+    /// l'ordre d'apparition de la feuille de style.
+    pub fn rules(&self) -> Option<&CSSRuleList> {
+        self.rules.as_ref()
+    }
+
+    pub(super) fn block(&self) -> Option<&CSSSimpleBlock> {
+        self.block.as_ref()
+    }
+
+    pub(super) fn is_conditional_group_rule(&self) -> bool {
+        CONDITIONAL_GROUP_RULES
+            .iter()
+            .any(|name| self.name.eq_ignore_ascii_case(name))
+    }
+
+    pub(super) fn nested_rules_count(&self) -> usize {
+        self.nested_rules_count
+    }
+}
+
+impl CSSAtRule {
+    /// Reconnaît une règle `@keyframes`. Le nom est un `<custom-ident>`
+    /// ou une `<string>` du prélude. Les étapes sont les règles du bloc
+    /// dont le sélecteur est une liste de `from`, `to` ou
+    /// `<percentage [0,100]>` : une étape est créée pour chacun de ses
+    /// sélecteurs, dans l'ordre. Les règles dont un sélecteur n'est pas
+    /// valide sont ignorées.
+    pub fn as_keyframes(&self) -> Option<CSSKeyframesRule> {
+        if !self.name.eq_ignore_ascii_case("keyframes") {
+            return None;
+        }
+
+        let mut prelude =
+            self.prelude.iter().filter(|value| !value.is_whitespace());
+        let name = match (prelude.next(), prelude.next()) {
+            | (
+                Some(CSSComponentValue::Preserved(CSSPreservedToken(
+                    CSSToken::Ident(name),
+                ))),
+                None,
+            ) if !is_css_wide_keyword(name)
+                && !name.eq_ignore_ascii_case("none") =>
+            {
+                name.to_owned()
+            }
+            | (
+                Some(CSSComponentValue::Preserved(CSSPreservedToken(
+                    CSSToken::String(name),
+                ))),
+                None,
+            ) => name.to_owned(),
+            | _ => return None,
+        };
+
+        let block = self.block.as_ref()?;
+        let rules = CSSParser::from_iter(
+            block.values().iter().cloned().map(CSSTokenVariant::from),
+        )
+        .list_of_rules();
+
+        let keyframes = rules
+            .iter()
+            .filter_map(|rule| match rule {
+                | CSSRule::QualifiedRule(rule) => Some(rule),
+                | CSSRule::AtRule(_) => None,
+            })
+            .filter_map(|rule| {
+                let offsets = keyframe_selectors(rule.prelude())?;
+                Some(offsets.into_iter().map(|offset| {
+                    let declarations = CSSParser::from_iter(
+                        rule.block()
+                            .values()
+                            .iter()
+                            .cloned()
+                            .map(CSSTokenVariant::from),
+                    )
+                    .list_of_declarations();
+                    (offset, declarations)
+                }))
+            })
+            .flatten()
+            .collect();
+
+        Some(CSSKeyframesRule { name, keyframes })
+    }
+}
+
+impl CSSKeyframesRule {
+    /// Le nom de l'animation.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Les étapes de l'animation, dans l'ordre du bloc.
+    pub fn keyframes(&self) -> &[CSSKeyframe] {
+        &self.keyframes
+    }
+}
+
 impl CSSAtRule {
     pub(super) fn append(&mut self, component_value: CSSComponentValue) {
         self.prelude.push(component_value);
@@ -57,4 +202,161 @@ impl CSSAtRule {
     pub(super) fn set_block(&mut self, block: CSSSimpleBlock) {
         self.block.replace(block);
     }
+
+    pub(super) fn set_rules(
+        &mut self,
+        rules: CSSRuleList,
+        nested_rules_count: usize,
+    ) {
+        self.rules.replace(rules);
+        self.nested_rules_count = nested_rules_count;
+    }
+
+    pub(super) fn set_source_order(&mut self, source_order: usize) {
+        self.source_order = source_order;
+    }
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+// NOTE(phisyx): l'index d'apparition n'intervient pas dans la
+// comparaison de deux règles.
+impl PartialEq for CSSAtRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.prelude == other.prelude
+            && self.block == other.block
+    }
+}
+
+impl Eq for CSSAtRule {}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Les mots-clés CSS globaux, qui ne sont pas des `<custom-ident>`
+/// valides.
+fn is_css_wide_keyword(ident: &str) -> bool {
+    [
+        "initial",
+        "inherit",
+        "unset",
+        "revert",
+        "revert-layer",
+        "default",
+    ]
+    .iter()
+    .any(|keyword| ident.eq_ignore_ascii_case(keyword))
+}
+
+/// Analyse le sélecteur d'une étape : `<keyframe-selector>#`, où
+/// `<keyframe-selector> = from | to | <percentage [0,100]>`. Retourne les
+/// positions en pourcentage, ou [None] si un sélecteur n'est pas valide.
+fn keyframe_selectors(prelude: &[CSSComponentValue]) -> Option<Vec<f32>> {
+    prelude
+        .split(|value| value.is_comma())
+        .map(|selector| {
+            let mut selector =
+                selector.iter().filter(|value| !value.is_whitespace());
+            let offset = match selector.next()? {
+                | CSSComponentValue::Preserved(CSSPreservedToken(
+                    CSSToken::Ident(ident),
+                )) if ident.eq_ignore_ascii_case("from") => 0.0,
+                | CSSComponentValue::Preserved(CSSPreservedToken(
+                    CSSToken::Ident(ident),
+                )) if ident.eq_ignore_ascii_case("to") => 100.0,
+                | CSSComponentValue::Preserved(CSSPreservedToken(
+                    CSSToken::Percentage(n),
+                )) if (0.0..=100.0).contains(n) => *n as f32,
+                | _ => return None,
+            };
+            selector.next().is_none().then_some(offset)
+        })
+        .collect()
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{style_blocks_content::CSSStyleBlock, test_the_str};
+
+    fn keyframes_of(mut parser: CSSParser) -> Option<CSSKeyframesRule> {
+        match parser.stylesheet().pop() {
+            | Some(CSSRule::AtRule(rule)) => rule.as_keyframes(),
+            | _ => panic!("At-rule attendue."),
+        }
+    }
+
+    fn declaration_names(
+        declarations: &CSSDeclarationList,
+    ) -> Vec<String> {
+        declarations
+            .iter()
+            .map(|block| match block {
+                | CSSStyleBlock::Declaration(declaration) => {
+                    declaration.css_text()
+                }
+                | CSSStyleBlock::Rule(_) => {
+                    panic!("Déclaration attendue.")
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_as_keyframes() {
+        let keyframes = keyframes_of(test_the_str!(
+            "@keyframes fade { from { opacity: 0; } 100% { opacity: 1; \
+             color: red } }"
+        ))
+        .unwrap();
+
+        assert_eq!(keyframes.name(), "fade");
+        let (from, to) = match keyframes.keyframes() {
+            | [from, to] => (from, to),
+            | _ => panic!("Deux étapes attendues."),
+        };
+        let ((from, from_decls), (to, to_decls)) = (from, to);
+        assert_eq!((*from, *to), (0.0, 100.0));
+        assert_eq!(declaration_names(from_decls), ["opacity: 0"]);
+        assert_eq!(
+            declaration_names(to_decls),
+            ["opacity: 1", "color: red"]
+        );
+    }
+
+    #[test]
+    fn test_as_keyframes_selectors() {
+        let keyframes = keyframes_of(test_the_str!(
+            "@keyframes \"slide\" { 0%, 50% { left: 0 } 120% { left: 1px } \
+             to, middle { left: 2px } TO { left: 3px } }"
+        ))
+        .unwrap();
+
+        assert_eq!(keyframes.name(), "slide");
+        // Les règles dont un sélecteur est invalide sont ignorées.
+        assert_eq!(
+            keyframes
+                .keyframes()
+                .iter()
+                .map(|(offset, _)| *offset)
+                .collect::<Vec<_>>(),
+            [0.0, 50.0, 100.0]
+        );
+
+        assert!(
+            keyframes_of(test_the_str!("@keyframes none { }")).is_none()
+        );
+        assert!(
+            keyframes_of(test_the_str!("@keyframes a b { }")).is_none()
+        );
+        assert!(keyframes_of(test_the_str!("@media screen { }")).is_none());
+    }
 }
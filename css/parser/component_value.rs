@@ -2,6 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use core::fmt;
+
 use parser::StreamIterator;
 
 use crate::{
@@ -124,6 +126,30 @@ impl CSSParser {
 // -------------- //
 
 impl CSSComponentValue {
+    pub(super) fn delimiter(&self) -> Option<char> {
+        match self {
+            | Self::Preserved(CSSPreservedToken(CSSToken::Delim(ch))) => {
+                Some(*ch)
+            }
+            | _ => None,
+        }
+    }
+
+    pub(super) fn is_comma(&self) -> bool {
+        matches!(self, Self::Preserved(CSSPreservedToken(CSSToken::Comma)))
+    }
+
+    pub(super) fn is_colon(&self) -> bool {
+        matches!(self, Self::Preserved(CSSPreservedToken(CSSToken::Colon)))
+    }
+
+    pub fn is_whitespace(&self) -> bool {
+        matches!(
+            self,
+            Self::Preserved(CSSPreservedToken(CSSToken::Whitespace))
+        )
+    }
+
     pub(super) fn simple_block(&self) -> Option<&CSSSimpleBlock> {
         match self {
             | Self::SimpleBlock(simple_block) => Some(simple_block),
@@ -134,12 +160,76 @@ impl CSSComponentValue {
     pub(super) fn simple_block_unchecked(&self) -> &CSSSimpleBlock {
         self.simple_block().expect("Simple bloc")
     }
+
+    /// Le [jeton conservé](CSSPreservedToken) de la valeur de
+    /// composant, si elle n'est ni une fonction ni un bloc simple.
+    pub fn token(&self) -> Option<&CSSToken> {
+        match self {
+            | Self::Preserved(token) => Some(token),
+            | _ => None,
+        }
+    }
+
+    /// Les [jetons conservés](CSSPreservedToken) de la valeur de
+    /// composant, dans l'ordre. Les valeurs des fonctions et des blocs
+    /// simples sont aplaties récursivement.
+    ///
+    /// NOTE: les jetons de fonction et les jetons d'ouverture/fermeture
+    /// des blocs ne sont pas des jetons conservés : ils ne font pas
+    /// partie de l'itération.
+    pub fn tokens(&self) -> impl Iterator<Item = &CSSToken> {
+        let mut tokens = vec![];
+        self.collect_tokens(&mut tokens);
+        tokens.into_iter()
+    }
+
+    fn collect_tokens<'a>(&'a self, tokens: &mut Vec<&'a CSSToken>) {
+        let values = match self {
+            | Self::Preserved(token) => return tokens.push(token),
+            | Self::Function(function) => function.values(),
+            | Self::SimpleBlock(simple_block) => simple_block.values(),
+        };
+        values.iter().for_each(|value| value.collect_tokens(tokens));
+    }
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Retire les valeurs de composant `<whitespace-token>` au début et à la
+/// fin de la liste. Une liste ne contenant que des espaces blancs donne
+/// une liste vide.
+pub fn trim_whitespace(
+    values: &[CSSComponentValue],
+) -> &[CSSComponentValue] {
+    let start = values
+        .iter()
+        .position(|value| !value.is_whitespace())
+        .unwrap_or(values.len());
+    let end = values
+        .iter()
+        .rposition(|value| !value.is_whitespace())
+        .map_or(start, |idx| idx + 1);
+    &values[start..end]
 }
 
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl fmt::Display for CSSComponentValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            | Self::Preserved(token) => write!(f, "{}", **token),
+            | Self::Function(function) => write!(f, "{function}"),
+            | Self::SimpleBlock(simple_block) => {
+                write!(f, "{simple_block}")
+            }
+        }
+    }
+}
+
 impl From<CSSPreservedToken> for CSSComponentValue {
     fn from(token: CSSPreservedToken) -> Self {
         Self::Preserved(token)
@@ -218,7 +308,7 @@ mod tests {
     use super::*;
     use crate::{
         test_the_str,
-        tokenization::{DimensionUnit, NumberFlag},
+        tokenization::{DimensionUnit, HashFlag, NumberFlag},
     };
 
     #[test]
@@ -288,7 +378,6 @@ mod tests {
         block.append(CSSToken::Url("fonts/Roboto-Regular.ttf".into()));
         block.append(CSSToken::Semicolon);
         block.append(CSSToken::Whitespace);
-        block.append(CSSToken::Whitespace);
 
         assert_eq!(
             parser.list_of_component_values(),
@@ -302,6 +391,28 @@ mod tests {
                     .unwrap(),
                 CSSToken::Whitespace.try_into().unwrap(),
                 block.try_into().unwrap(),
+                CSSToken::Whitespace.try_into().unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simple_block_ending_token() {
+        let mut parser = test_the_str!("[a] (b) c");
+
+        let square = CSSSimpleBlock::new(CSSToken::LeftSquareBracket)
+            .set_values([CSSToken::Ident("a".into())]);
+        let paren = CSSSimpleBlock::new(CSSToken::LeftParenthesis)
+            .set_values([CSSToken::Ident("b".into())]);
+
+        assert_eq!(
+            parser.list_of_component_values(),
+            [
+                square.try_into().unwrap(),
+                CSSToken::Whitespace.try_into().unwrap(),
+                paren.try_into().unwrap(),
+                CSSToken::Whitespace.try_into().unwrap(),
+                CSSToken::Ident("c".into()).try_into().unwrap(),
             ]
         );
     }
@@ -320,4 +431,60 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_trim_whitespace() {
+        let mut parser = test_the_str!("  #foo > .bar  ");
+        let prelude = parser.list_of_component_values();
+        assert!(prelude.first().unwrap().is_whitespace());
+        assert!(prelude.last().unwrap().is_whitespace());
+
+        let trimmed = trim_whitespace(&prelude);
+        assert_eq!(trimmed.len(), prelude.len() - 2);
+        assert_eq!(
+            trimmed.first(),
+            Some(
+                &CSSToken::Hash("foo".into(), HashFlag::ID)
+                    .try_into()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            trimmed.last(),
+            Some(&CSSToken::Ident("bar".into()).try_into().unwrap())
+        );
+
+        let mut parser = test_the_str!("   ");
+        let values = parser.list_of_component_values();
+        assert!(!values.is_empty());
+        assert!(trim_whitespace(&values).is_empty());
+        assert!(trim_whitespace(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_tokens() {
+        let mut parser = test_the_str!("foo(1 + bar(2))");
+        let value = parser.component_value().unwrap();
+        assert_eq!(
+            value.tokens().collect::<Vec<_>>(),
+            [
+                &CSSToken::Number(1.0, NumberFlag::Integer),
+                &CSSToken::Whitespace,
+                &CSSToken::Delim('+'),
+                &CSSToken::Whitespace,
+                &CSSToken::Number(2.0, NumberFlag::Integer),
+            ]
+        );
+
+        let mut parser = test_the_str!("[a (b)]");
+        let value = parser.component_value().unwrap();
+        assert_eq!(
+            value.tokens().collect::<Vec<_>>(),
+            [
+                &CSSToken::Ident("a".into()),
+                &CSSToken::Whitespace,
+                &CSSToken::Ident("b".into()),
+            ]
+        );
+    }
 }
@@ -7,7 +7,9 @@ use std::ops::Deref;
 use parser::StreamIterator;
 
 use crate::{
-    component_value::{CSSComponentValue, CSSComponentValuesList},
+    component_value::{
+        trim_whitespace, CSSComponentValue, CSSComponentValuesList,
+    },
     grammars::CSSRuleError,
     style_blocks_content::CSSStyleBlock,
     tokenization::CSSToken,
@@ -64,20 +66,25 @@ pub enum CSSDeclarationCategory {
 impl CSSParser {
     /// Analyse d'une déclaration
     pub fn declaration(&mut self) -> Result<CSSDeclaration, CSSRuleError> {
-        self.tokens
-            .advance_as_long_as_possible(|token| token.is_whitespace());
+        self.measure(|parser| {
+            parser.tokens.advance_as_long_as_possible(|token| {
+                token.is_whitespace()
+            });
 
-        let declaration = match self.next_input_token() {
-            | variant if variant.is_ident() => self.consume_declaration(),
-            | _ => None,
-        };
+            let declaration = match parser.next_input_token() {
+                | variant if variant.is_ident() => {
+                    parser.consume_declaration()
+                }
+                | _ => None,
+            };
 
-        declaration.ok_or(CSSRuleError::SyntaxError)
+            declaration.ok_or(CSSRuleError::SyntaxError)
+        })
     }
 
     /// Analyse une liste de déclarations
     pub fn list_of_declarations(&mut self) -> CSSDeclarationList {
-        self.consume_list_of_declarations()
+        self.measure(Self::consume_list_of_declarations)
     }
 }
 
@@ -91,6 +98,21 @@ impl CSSDeclaration {
         self
     }
 
+    /// Les noms de propriété sont insensibles à la casse ASCII : ils sont
+    /// stockés en minuscules. Les noms des propriétés personnalisées
+    /// (`--Foo`), eux, sont sensibles à la casse et conservés tels quels.
+    pub(super) fn with_property_name(
+        self,
+        token_name: impl ToString,
+    ) -> Self {
+        let name = token_name.to_string();
+        if name.starts_with("--") {
+            self.with_name(name)
+        } else {
+            self.with_name(name.to_ascii_lowercase())
+        }
+    }
+
     pub(super) fn with_values(
         mut self,
         prelude: impl IntoIterator<Item = impl TryInto<CSSComponentValue>>,
@@ -131,6 +153,63 @@ impl CSSDeclaration {
     pub(super) fn last_token(&self) -> Option<&CSSToken> {
         self.last_n_tokens(1).next()
     }
+
+    pub(super) fn has_empty_value(&self) -> bool {
+        self.value.is_empty()
+    }
+}
+
+impl CSSDeclaration {
+    /// Nom de la propriété ou du descripteur.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Valeur de la déclaration : une liste de valeurs de composants,
+    /// sans le drapeau important.
+    pub fn values(&self) -> &[CSSComponentValue] {
+        &self.value
+    }
+
+    /// Sérialisation de la valeur seule, sans le nom, les deux-points ni
+    /// le drapeau important. Les espaces blancs de début et de fin sont
+    /// retirés et les espaces blancs consécutifs sont réduits à un seul.
+    pub fn value_string(&self) -> String {
+        let mut previous_is_whitespace = false;
+        trim_whitespace(&self.value)
+            .iter()
+            .filter(|value| {
+                let is_whitespace = value.is_whitespace();
+                let keep = !(is_whitespace && previous_is_whitespace);
+                previous_is_whitespace = is_whitespace;
+                keep
+            })
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Une propriété personnalisée est une propriété dont le nom
+    /// commence par deux tirets (U+002D HYPHEN-MINUS), comme `--foo`.
+    pub fn is_custom_property(&self) -> bool {
+        self.name.starts_with("--")
+    }
+
+    /// Sérialisation de la déclaration : `name: value`, suivi de
+    /// ` !important` lorsque le drapeau important est activé.
+    ///
+    /// La valeur est sérialisée à partir des valeurs de composants, ce
+    /// qui préserve la valeur brute des propriétés personnalisées (casse,
+    /// blocs, ...).
+    pub fn css_text(&self) -> String {
+        let value: String =
+            self.value.iter().map(ToString::to_string).collect();
+
+        let mut css_text = format!("{}: {value}", self.name);
+        if self.important_flag {
+            css_text.push_str(" !important");
+        }
+        css_text
+    }
 }
 
 impl CSSDeclaration {
@@ -156,7 +235,10 @@ impl CSSDeclaration {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_the_str;
+    use crate::{
+        grammars::CSSRule, test_the_str, tokenization::CSSTokenVariant,
+        CSSParserError,
+    };
 
     #[test]
     fn test_parse_declaration() {
@@ -176,6 +258,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_declaration_css_text() {
+        let mut parser = test_the_str!("color: red !important");
+        assert_eq!(
+            parser.declaration().map(|decl| decl.css_text()),
+            Ok("color: red !important".to_owned())
+        );
+
+        let mut parser =
+            test_the_str!("margin: calc(1px + 2%) 0 auto  !IMPORTANT");
+        assert_eq!(
+            parser.declaration().map(|decl| decl.css_text()),
+            Ok("margin: calc(1px + 2%) 0 auto !important".to_owned())
+        );
+
+        let mut parser = test_the_str!("--Foo: 1PX,'x'  var(--bar)");
+        let declaration = parser.declaration().unwrap();
+        assert!(declaration.is_custom_property());
+        assert_eq!(
+            declaration.css_text(),
+            "--Foo: 1PX,\"x\" var(--bar)".to_owned()
+        );
+    }
+
+    #[test]
+    fn test_declaration_value_string() {
+        let mut parser = test_the_str!("margin: 0 auto");
+        let declaration = parser.declaration().unwrap();
+        assert_eq!(declaration.name(), "margin");
+        assert_eq!(declaration.value_string(), "0 auto");
+
+        let mut parser = test_the_str!(
+            "font-family:   'Roboto',\n   sans-serif  !important"
+        );
+        let declaration = parser.declaration().unwrap();
+        assert_eq!(declaration.name(), "font-family");
+        assert_eq!(declaration.value_string(), "\"Roboto\", sans-serif");
+    }
+
     #[test]
     fn test_parse_declaration_is_not() {
         let mut parser = test_the_str!(r#".class {}"#);
@@ -207,4 +328,64 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_empty_declaration_value() {
+        let mut parser =
+            test_the_str!("color: ; background: blue; margin:/* c */;");
+        assert_eq!(
+            parser.list_of_declarations(),
+            [CSSStyleBlock::Declaration(
+                CSSDeclaration::default()
+                    .with_name("background")
+                    .with_values([CSSToken::Ident("blue".into())])
+            )]
+        );
+        assert_eq!(
+            parser.take_parse_errors(),
+            [
+                CSSParserError::EmptyDeclarationValue,
+                CSSParserError::EmptyDeclarationValue
+            ]
+        );
+        assert_eq!(
+            CSSParserError::EmptyDeclarationValue.to_string(),
+            "empty-declaration-value"
+        );
+
+        let mut parser = test_the_str!("a { color: }");
+        let block = match parser.stylesheet().pop() {
+            | Some(CSSRule::QualifiedRule(rule)) => {
+                rule.block().to_owned()
+            }
+            | _ => panic!("Règle qualifiée attendue."),
+        };
+        let mut parser = CSSParser::from_iter(
+            block.values().iter().cloned().map(CSSTokenVariant::from),
+        );
+        assert!(parser.list_of_declarations().is_empty());
+        assert_eq!(
+            parser.take_parse_errors(),
+            [CSSParserError::EmptyDeclarationValue]
+        );
+    }
+
+    #[test]
+    fn test_declaration_name_case() {
+        let mut parser = test_the_str!("COLOR: red");
+        let declaration = parser.declaration().unwrap();
+        assert_eq!(declaration.name(), "color");
+        assert_eq!(declaration.css_text(), "color: red");
+
+        let mut parser = test_the_str!("Background-Color: blue");
+        assert_eq!(
+            parser.declaration().unwrap().name(),
+            "background-color"
+        );
+
+        let mut parser = test_the_str!("--Foo: 1px");
+        let declaration = parser.declaration().unwrap();
+        assert!(declaration.is_custom_property());
+        assert_eq!(declaration.name(), "--Foo");
+    }
 }
@@ -0,0 +1,38 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Erreurs d'analyse rencontrées par l'analyseur CSS. Une erreur
+/// d'analyse n'interrompt pas l'analyse : la construction fautive est
+/// ignorée, conformément aux règles de traitement des erreurs.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum CSSParserError {
+    /// Cette erreur se produit si la valeur d'une déclaration est vide
+    /// (par exemple, `color: ;` ou `color: /* c */;`). La déclaration
+    /// est ignorée.
+    EmptyDeclarationValue,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for CSSParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                | Self::EmptyDeclarationValue => "empty-declaration-value",
+            }
+        )
+    }
+}
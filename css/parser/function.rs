@@ -2,9 +2,14 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use core::fmt;
+
 use crate::{
-    component_value::{CSSComponentValue, CSSComponentValuesList},
-    tokenization::CSSToken,
+    component_value::{
+        trim_whitespace, CSSComponentValue, CSSComponentValuesList,
+    },
+    preserved_tokens::CSSPreservedToken,
+    tokenization::{CSSToken, DimensionUnit, NumberFlag},
 };
 
 // --------- //
@@ -22,6 +27,46 @@ pub struct CSSFunction {
     value: CSSComponentValuesList,
 }
 
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Résultat de l'évaluation d'une fonction mathématique.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum CSSMathValue {
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, DimensionUnit),
+    /// Expression qui ne peut pas être réduite lors de l'analyse, car
+    /// elle mélange des unités qui dépendent du contexte de calcul (par
+    /// exemple `clamp(1px, 2vw, 3px)`).
+    Symbolic(CSSFunction),
+}
+
+/// Nombre de répétitions de la fonction `repeat()` des grilles.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum RepeatCount {
+    /// `repeat(<integer [1,∞]>, ...)`
+    Count(u32),
+    /// `repeat(auto-fill, ...)`
+    AutoFill,
+    /// `repeat(auto-fit, ...)`
+    AutoFit,
+}
+
+/// Raison pour laquelle une expression mathématique ne peut pas être
+/// réduite à une valeur.
+enum CSSMathError {
+    /// L'expression n'est pas valide (`calc(1px * 2px)`, `min()`, ...).
+    Invalid,
+    /// L'expression est valide, mais mélange des unités différentes.
+    Unresolved,
+}
+
 // -------------- //
 // Implémentation //
 // -------------- //
@@ -50,6 +95,190 @@ impl CSSFunction {
     pub(super) fn name(&self) -> &str {
         &self.name
     }
+
+    pub(super) fn values(&self) -> &CSSComponentValuesList {
+        &self.value
+    }
+}
+
+impl CSSFunction {
+    /// Noms des fonctions mathématiques évaluées par
+    /// [CSSFunction::eval_math].
+    const CALC_FUNCTIONS: [&'static str; 4] =
+        ["calc", "min", "max", "clamp"];
+    /// Noms des fonctions dont l'évaluation peut être différée jusqu'au
+    /// calcul de la valeur : fonctions mathématiques et substitutions de
+    /// variables.
+    const MATH_FUNCTIONS: [&'static str; 6] =
+        ["calc", "min", "max", "clamp", "var", "env"];
+
+    /// Vérifie que la fonction est une fonction mathématique (`calc()`,
+    /// `min()`, `max()`, `clamp()`) ou une fonction de substitution
+    /// (`var()`, `env()`), sans tenir compte de la casse.
+    ///
+    /// Seul le nom de la fonction est pris en compte : `calc(var(--x))`
+    /// est une fonction mathématique, `rgb(calc(1))` ne l'est pas.
+    pub fn is_math_function(&self) -> bool {
+        Self::MATH_FUNCTIONS
+            .iter()
+            .any(|name| self.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Évalue la fonction mathématique `calc()`, `min()`, `max()` ou
+    /// `clamp()`. Les arguments de `min()`, `max()` et `clamp()` sont
+    /// séparés par des virgules.
+    ///
+    /// Retourne [None] lorsque la fonction n'est pas une fonction
+    /// mathématique ou que ses arguments ne sont pas valides.
+    pub fn eval_math(&self) -> Option<CSSMathValue> {
+        match self.eval_math_function() {
+            | Ok(value) => Some(value),
+            | Err(CSSMathError::Unresolved) => {
+                Some(CSSMathValue::Symbolic(self.clone()))
+            }
+            | Err(CSSMathError::Invalid) => None,
+        }
+    }
+
+    fn eval_math_function(&self) -> Result<CSSMathValue, CSSMathError> {
+        let values = self
+            .value
+            .split(|value| value.is_comma())
+            .map(|argument| {
+                let argument: Vec<_> = argument
+                    .iter()
+                    .filter(|value| !value.is_whitespace())
+                    .collect();
+                eval_math_sum(&argument)
+            })
+            .collect::<Vec<_>>();
+
+        if values
+            .iter()
+            .any(|value| matches!(value, Err(CSSMathError::Invalid)))
+        {
+            return Err(CSSMathError::Invalid);
+        }
+
+        let values = values.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        match (self.name.to_ascii_lowercase().as_str(), values.as_slice())
+        {
+            | ("calc", [value]) => Ok(value.to_owned()),
+
+            | ("min", [first, rest @ ..]) => {
+                rest.iter().try_fold(first.to_owned(), |acc, value| {
+                    acc.min(value.to_owned())
+                })
+            }
+
+            | ("max", [first, rest @ ..]) => {
+                rest.iter().try_fold(first.to_owned(), |acc, value| {
+                    acc.max(value.to_owned())
+                })
+            }
+
+            // clamp(MIN, VAL, MAX) équivaut à max(MIN, min(VAL, MAX)).
+            | ("clamp", [min, value, max]) => {
+                min.to_owned().max(value.to_owned().min(max.to_owned())?)
+            }
+
+            | _ => Err(CSSMathError::Invalid),
+        }
+    }
+}
+
+impl CSSFunction {
+    /// Reconnaît la fonction `repeat()` des pistes de grille :
+    /// `repeat( [ <integer [1,∞]> | auto-fill | auto-fit ] , <track-list>
+    /// )`.
+    ///
+    /// Retourne le nombre de répétitions et la liste des pistes (sans
+    /// les espaces blancs de début et de fin), ou [None] lorsque la
+    /// fonction n'est pas une fonction `repeat()` valide.
+    pub fn as_repeat(
+        &self,
+    ) -> Option<(RepeatCount, CSSComponentValuesList)> {
+        if !self.name.eq_ignore_ascii_case("repeat") {
+            return None;
+        }
+
+        let comma_idx =
+            self.value.iter().position(|value| value.is_comma())?;
+        let (count, tracks) = (
+            trim_whitespace(&self.value[..comma_idx]),
+            trim_whitespace(&self.value[comma_idx + 1..]),
+        );
+
+        let count = match count {
+            | [CSSComponentValue::Preserved(CSSPreservedToken(
+                CSSToken::Number(n, NumberFlag::Integer),
+            ))] if *n >= 1.0 && *n <= u32::MAX as f64 => {
+                RepeatCount::Count(*n as u32)
+            }
+            | [CSSComponentValue::Preserved(CSSPreservedToken(
+                CSSToken::Ident(ident),
+            ))] if ident.eq_ignore_ascii_case("auto-fill") => {
+                RepeatCount::AutoFill
+            }
+            | [CSSComponentValue::Preserved(CSSPreservedToken(
+                CSSToken::Ident(ident),
+            ))] if ident.eq_ignore_ascii_case("auto-fit") => {
+                RepeatCount::AutoFit
+            }
+            | _ => return None,
+        };
+
+        if tracks.is_empty() {
+            return None;
+        }
+
+        Some((count, tracks.to_vec()))
+    }
+
+    /// Reconnaît la fonction `env()` des variables d'environnement :
+    /// `env( <custom-ident> <integer [0,∞]>*, <declaration-value>? )`.
+    ///
+    /// Retourne le nom de la variable et la valeur de repli (sans les
+    /// espaces blancs de début et de fin), [None] lorsqu'il n'y a pas de
+    /// virgule. Les indices entiers qui suivent le nom sont ignorés.
+    pub fn as_env(
+        &self,
+    ) -> Option<(String, Option<CSSComponentValuesList>)> {
+        if !self.name.eq_ignore_ascii_case("env") {
+            return None;
+        }
+
+        let comma_idx =
+            self.value.iter().position(|value| value.is_comma());
+        let (name, fallback) = match comma_idx {
+            | Some(idx) => (
+                &self.value[..idx],
+                Some(trim_whitespace(&self.value[idx + 1..])),
+            ),
+            | None => (self.value.as_slice(), None),
+        };
+
+        let mut name = name.iter().filter(|value| !value.is_whitespace());
+        let name = match name.next() {
+            | Some(CSSComponentValue::Preserved(CSSPreservedToken(
+                CSSToken::Ident(ident),
+            ))) if name.all(|value| {
+                matches!(
+                    value,
+                    CSSComponentValue::Preserved(CSSPreservedToken(
+                        CSSToken::Number(n, NumberFlag::Integer),
+                    )) if *n >= 0.0
+                )
+            }) =>
+            {
+                ident.to_owned()
+            }
+            | _ => return None,
+        };
+
+        Some((name, fallback.map(<[_]>::to_vec)))
+    }
 }
 
 impl CSSFunction {
@@ -58,10 +287,228 @@ impl CSSFunction {
     }
 }
 
+impl CSSMathValue {
+    fn value(&self) -> f64 {
+        match self {
+            | Self::Number(n)
+            | Self::Percentage(n)
+            | Self::Dimension(n, _) => *n,
+            | Self::Symbolic(_) => f64::NAN,
+        }
+    }
+
+    fn with_value(&self, n: f64) -> Self {
+        match self {
+            | Self::Number(_) => Self::Number(n),
+            | Self::Percentage(_) => Self::Percentage(n),
+            | Self::Dimension(_, unit) => {
+                Self::Dimension(n, unit.to_owned())
+            }
+            | Self::Symbolic(_) => self.to_owned(),
+        }
+    }
+
+    /// Vérifie que les deux valeurs sont du même type, et de la même
+    /// unité pour les dimensions.
+    fn check_compatibility(
+        &self,
+        other: &Self,
+    ) -> Result<(), CSSMathError> {
+        match (self, other) {
+            | (Self::Number(_), Self::Number(_))
+            | (Self::Percentage(_), Self::Percentage(_)) => Ok(()),
+            | (Self::Dimension(_, lhs), Self::Dimension(_, rhs))
+                if lhs.0.eq_ignore_ascii_case(&rhs.0) =>
+            {
+                Ok(())
+            }
+            | (Self::Number(_), _) | (_, Self::Number(_)) => {
+                Err(CSSMathError::Invalid)
+            }
+            | _ => Err(CSSMathError::Unresolved),
+        }
+    }
+
+    fn add(self, other: Self) -> Result<Self, CSSMathError> {
+        self.check_compatibility(&other)?;
+        Ok(self.with_value(self.value() + other.value()))
+    }
+
+    fn sub(self, other: Self) -> Result<Self, CSSMathError> {
+        self.check_compatibility(&other)?;
+        Ok(self.with_value(self.value() - other.value()))
+    }
+
+    fn mul(self, other: Self) -> Result<Self, CSSMathError> {
+        match (&self, &other) {
+            | (Self::Number(n), _) => {
+                Ok(other.with_value(n * other.value()))
+            }
+            | (_, Self::Number(n)) => {
+                Ok(self.with_value(self.value() * n))
+            }
+            | _ => Err(CSSMathError::Invalid),
+        }
+    }
+
+    fn div(self, other: Self) -> Result<Self, CSSMathError> {
+        match other {
+            | Self::Number(n) => Ok(self.with_value(self.value() / n)),
+            | _ => Err(CSSMathError::Invalid),
+        }
+    }
+
+    fn min(self, other: Self) -> Result<Self, CSSMathError> {
+        self.check_compatibility(&other)?;
+        Ok(if other.value() < self.value() {
+            other
+        } else {
+            self
+        })
+    }
+
+    fn max(self, other: Self) -> Result<Self, CSSMathError> {
+        self.check_compatibility(&other)?;
+        Ok(if other.value() > self.value() {
+            other
+        } else {
+            self
+        })
+    }
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Évalue une somme : `<calc-product> [ [ '+' | '-' ] <calc-product> ]*`
+fn eval_math_sum(
+    values: &[&CSSComponentValue],
+) -> Result<CSSMathValue, CSSMathError> {
+    let mut result = None;
+    let mut operator = '+';
+    let mut start = 0;
+
+    for idx in 0..=values.len() {
+        let next_operator = match values.get(idx) {
+            | Some(value) => match value.delimiter() {
+                | Some(ch @ ('+' | '-')) => ch,
+                | _ => continue,
+            },
+            | None => '+',
+        };
+
+        let product = eval_math_product(&values[start..idx])?;
+        result = Some(match result {
+            | None => product,
+            | Some(acc) if operator == '+' => {
+                CSSMathValue::add(acc, product)?
+            }
+            | Some(acc) => CSSMathValue::sub(acc, product)?,
+        });
+        operator = next_operator;
+        start = idx + 1;
+    }
+
+    result.ok_or(CSSMathError::Invalid)
+}
+
+/// Évalue un produit : `<calc-value> [ [ '*' | '/' ] <calc-value> ]*`
+fn eval_math_product(
+    values: &[&CSSComponentValue],
+) -> Result<CSSMathValue, CSSMathError> {
+    let mut result = None;
+    let mut operator = '*';
+    let mut start = 0;
+
+    for idx in 0..=values.len() {
+        let next_operator = match values.get(idx) {
+            | Some(value) => match value.delimiter() {
+                | Some(ch @ ('*' | '/')) => ch,
+                | _ => continue,
+            },
+            | None => '*',
+        };
+
+        let value = match &values[start..idx] {
+            | [value] => eval_math_value(value)?,
+            | _ => return Err(CSSMathError::Invalid),
+        };
+        result = Some(match result {
+            | None => value,
+            | Some(acc) if operator == '*' => {
+                CSSMathValue::mul(acc, value)?
+            }
+            | Some(acc) => CSSMathValue::div(acc, value)?,
+        });
+        operator = next_operator;
+        start = idx + 1;
+    }
+
+    result.ok_or(CSSMathError::Invalid)
+}
+
+/// Évalue une valeur : `<number> | <dimension> | <percentage> |
+/// <calc-function> | ( <calc-sum> )`
+fn eval_math_value(
+    value: &CSSComponentValue,
+) -> Result<CSSMathValue, CSSMathError> {
+    match value {
+        | CSSComponentValue::Preserved(CSSPreservedToken(token)) => {
+            match token {
+                | CSSToken::Number(n, _) => Ok(CSSMathValue::Number(*n)),
+                | CSSToken::Percentage(n) => {
+                    Ok(CSSMathValue::Percentage(*n))
+                }
+                | CSSToken::Dimension(n, _, unit) => {
+                    Ok(CSSMathValue::Dimension(*n, unit.to_owned()))
+                }
+                | _ => Err(CSSMathError::Invalid),
+            }
+        }
+        | CSSComponentValue::Function(function)
+            if CSSFunction::CALC_FUNCTIONS
+                .iter()
+                .any(|name| function.name.eq_ignore_ascii_case(name)) =>
+        {
+            function.eval_math_function()
+        }
+        // NOTE(css): les fonctions de substitution (`var()`, `env()`) ne
+        // peuvent être remplacées qu'au calcul de la valeur.
+        | CSSComponentValue::Function(function)
+            if function.is_math_function() =>
+        {
+            Err(CSSMathError::Unresolved)
+        }
+        | CSSComponentValue::Function(_) => Err(CSSMathError::Invalid),
+        | CSSComponentValue::SimpleBlock(block)
+            if block.token == CSSToken::LeftParenthesis =>
+        {
+            let values: Vec<_> = block
+                .values()
+                .iter()
+                .filter(|value| !value.is_whitespace())
+                .collect();
+            eval_math_sum(&values)
+        }
+        | CSSComponentValue::SimpleBlock(_) => Err(CSSMathError::Invalid),
+    }
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl fmt::Display for CSSFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        self.value
+            .iter()
+            .try_for_each(|value| write!(f, "{value}"))?;
+        write!(f, ")")
+    }
+}
+
 impl From<CSSToken> for CSSFunction {
     fn from(token: CSSToken) -> Self {
         match token {
@@ -70,3 +517,202 @@ impl From<CSSToken> for CSSFunction {
         }
     }
 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_the_str;
+
+    fn function(mut parser: crate::CSSParser) -> CSSFunction {
+        match parser.component_value() {
+            | Ok(CSSComponentValue::Function(function)) => function,
+            | _ => panic!("Fonction attendue."),
+        }
+    }
+
+    fn eval_math(mut parser: crate::CSSParser) -> Option<CSSMathValue> {
+        match parser.component_value() {
+            | Ok(CSSComponentValue::Function(function)) => {
+                function.eval_math()
+            }
+            | _ => panic!("Fonction attendue."),
+        }
+    }
+
+    #[test]
+    fn test_is_math_function() {
+        assert!(
+            function(test_the_str!("calc(1px + 2px)")).is_math_function()
+        );
+        assert!(function(test_the_str!("CLAMP(1px, 2vw, 3px)"))
+            .is_math_function());
+        assert!(function(test_the_str!("env(safe-area-inset-top)"))
+            .is_math_function());
+        assert!(
+            !function(test_the_str!("rgb(0, 0, 0)")).is_math_function()
+        );
+
+        let calc = function(test_the_str!("calc(var(--x) * 2)"));
+        assert!(calc.is_math_function());
+        match calc.values().first() {
+            | Some(CSSComponentValue::Function(var)) => {
+                assert!(var.is_math_function())
+            }
+            | _ => panic!("Fonction attendue."),
+        }
+
+        let rgb = function(test_the_str!("rgb(calc(1 + 2), 0, 0)"));
+        assert!(!rgb.is_math_function());
+    }
+
+    #[test]
+    fn test_eval_math_min_max() {
+        assert_eq!(
+            eval_math(test_the_str!("max(10px, 5px)")),
+            Some(CSSMathValue::Dimension(
+                10.0,
+                DimensionUnit("px".into())
+            ))
+        );
+        assert_eq!(
+            eval_math(test_the_str!("min(10px, 5px, 7px)")),
+            Some(CSSMathValue::Dimension(5.0, DimensionUnit("px".into())))
+        );
+        assert_eq!(
+            eval_math(test_the_str!("max(1 + 2, 2 * 2)")),
+            Some(CSSMathValue::Number(4.0))
+        );
+        assert_eq!(eval_math(test_the_str!("max()")), None);
+        assert_eq!(eval_math(test_the_str!("min(1px, 2)")), None);
+
+        // L'évaluation des fonctions de substitution est différée.
+        [
+            "calc(var(--x) * 2)",
+            "calc(env(x) + 1px)",
+            "max(var(--x), 1px)",
+        ]
+        .into_iter()
+        .for_each(|input| {
+            let function = function(crate::CSSParser::from_str(input));
+            assert_eq!(
+                function.eval_math(),
+                Some(CSSMathValue::Symbolic(function))
+            );
+        });
+        assert_eq!(eval_math(test_the_str!("calc(rgb(0) + 1px)")), None);
+    }
+
+    #[test]
+    fn test_eval_math_clamp() {
+        assert_eq!(
+            eval_math(test_the_str!("clamp(1px, 5px, 3px)")),
+            Some(CSSMathValue::Dimension(3.0, DimensionUnit("px".into())))
+        );
+        assert_eq!(
+            eval_math(test_the_str!("clamp(10%, calc(5% * 3), 20%)")),
+            Some(CSSMathValue::Percentage(15.0))
+        );
+        assert_eq!(eval_math(test_the_str!("clamp(1px, 2px)")), None);
+
+        let mut parser = test_the_str!("clamp(1px, 2vw, 3px)");
+        let function = match parser.component_value() {
+            | Ok(CSSComponentValue::Function(function)) => function,
+            | _ => panic!("Fonction attendue."),
+        };
+        assert_eq!(
+            function.eval_math(),
+            Some(CSSMathValue::Symbolic(function))
+        );
+    }
+
+    #[test]
+    fn test_as_repeat() {
+        assert_eq!(
+            function(test_the_str!("repeat(3, 1fr)")).as_repeat(),
+            Some((
+                RepeatCount::Count(3),
+                vec![CSSToken::Dimension(
+                    1.0,
+                    NumberFlag::Integer,
+                    DimensionUnit("fr".into())
+                )
+                .try_into()
+                .unwrap()]
+            ))
+        );
+
+        assert_eq!(
+            function(test_the_str!("repeat(auto-fill, 100px 1fr)"))
+                .as_repeat()
+                .map(|(count, tracks)| (count, tracks.len())),
+            Some((RepeatCount::AutoFill, 3))
+        );
+
+        let (count, tracks) = function(test_the_str!(
+            "repeat(auto-fit, minmax(100px, 1fr))"
+        ))
+        .as_repeat()
+        .unwrap();
+        assert_eq!(count, RepeatCount::AutoFit);
+        match tracks.as_slice() {
+            | [CSSComponentValue::Function(minmax)] => {
+                assert_eq!(minmax.name(), "minmax")
+            }
+            | _ => panic!("Fonction minmax() attendue."),
+        }
+
+        assert_eq!(
+            function(test_the_str!("repeat(0, 1fr)")).as_repeat(),
+            None
+        );
+        assert_eq!(
+            function(test_the_str!("repeat(1.5, 1fr)")).as_repeat(),
+            None
+        );
+        assert_eq!(function(test_the_str!("repeat(3)")).as_repeat(), None);
+        assert_eq!(
+            function(test_the_str!("repeat(3, )")).as_repeat(),
+            None
+        );
+        assert_eq!(
+            function(test_the_str!("minmax(3, 1fr)")).as_repeat(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_as_env() {
+        assert_eq!(
+            function(test_the_str!("env(x, 10px)")).as_env(),
+            Some((
+                "x".into(),
+                Some(vec![CSSToken::Dimension(
+                    10.0,
+                    NumberFlag::Integer,
+                    DimensionUnit("px".into())
+                )
+                .try_into()
+                .unwrap()])
+            ))
+        );
+
+        assert_eq!(
+            function(test_the_str!("env(safe-area-inset-top)")).as_env(),
+            Some(("safe-area-inset-top".into(), None))
+        );
+        assert_eq!(
+            function(test_the_str!("env(viewport-segment-width 1 0, 0)"))
+                .as_env()
+                .map(|(name, fallback)| (name, fallback.map(|v| v.len()))),
+            Some(("viewport-segment-width".into(), Some(1)))
+        );
+
+        assert_eq!(function(test_the_str!("env()")).as_env(), None);
+        assert_eq!(function(test_the_str!("env(10px)")).as_env(), None);
+        assert_eq!(function(test_the_str!("var(--x, 1)")).as_env(), None);
+    }
+}
@@ -62,11 +62,22 @@ pub enum CSSRuleError {
 impl CSSParser {
     /// Analyse une liste de règles
     pub fn list_of_rules(&mut self) -> CSSRuleList {
-        self.consume_list_of_rules(false)
+        self.measure(|parser| parser.consume_list_of_rules(false))
     }
 
     /// Analyse d'une règle
     pub fn rule(&mut self) -> Result<CSSRule, CSSRuleError> {
+        self.measure(Self::consume_rule)
+    }
+
+    /// Analyse d'une feuille de style.
+    pub fn stylesheet(&mut self) -> CSSStyleSheet {
+        self.measure(|parser| parser.consume_list_of_rules(true))
+    }
+}
+
+impl CSSParser {
+    fn consume_rule(&mut self) -> Result<CSSRule, CSSRuleError> {
         self.tokens
             .advance_as_long_as_possible(|token| token.is_whitespace());
 
@@ -103,10 +114,41 @@ impl CSSParser {
             .and(rule)
             .ok_or(CSSRuleError::SyntaxError) /* ------------------------------- ^ */
     }
+}
 
-    /// Analyse d'une feuille de style.
-    pub fn stylesheet(&mut self) -> CSSStyleSheet {
-        self.consume_list_of_rules(true)
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl CSSRule {
+    /// Index d'apparition de la règle dans la feuille de style, utilisé
+    /// par la cascade : attribué par l'algorithme de consommation d'une
+    /// liste de règles. Les règles d'un bloc `@media` (voir
+    /// [CSSAtRule::rules]) sont numérotées à la suite de la règle qui
+    /// les contient, de sorte qu'elles restent ordonnées par rapport à
+    /// la feuille de style globale.
+    pub fn source_order(&self) -> usize {
+        match self {
+            | Self::QualifiedRule(rule) => rule.source_order(),
+            | Self::AtRule(rule) => rule.source_order(),
+        }
+    }
+
+    /// Nombre de règles contenues dans la règle, à toute profondeur.
+    pub(super) fn nested_rules_count(&self) -> usize {
+        match self {
+            | Self::QualifiedRule(_) => 0,
+            | Self::AtRule(rule) => rule.nested_rules_count(),
+        }
+    }
+
+    pub(super) fn set_source_order(&mut self, source_order: usize) {
+        match self {
+            | Self::QualifiedRule(rule) => {
+                rule.set_source_order(source_order)
+            }
+            | Self::AtRule(rule) => rule.set_source_order(source_order),
+        }
     }
 }
 
@@ -148,43 +190,38 @@ mod tests {
             "
         );
 
-        let mut block = CSSSimpleBlock::new(CSSToken::LeftCurlyBracket)
-            .set_values([
-                CSSToken::Whitespace,
-                CSSToken::Ident("color".into()),
-                CSSToken::Colon,
-                CSSToken::Whitespace,
-                CSSToken::Ident("red".into()),
-                CSSToken::Semicolon,
-                CSSToken::Whitespace,
-                CSSToken::Whitespace,
-                CSSToken::Hash("foo-2".into(), HashFlag::ID),
-                CSSToken::Whitespace,
-            ]);
-
-        block.append(
+        let block = |color: &str| {
             CSSSimpleBlock::new(CSSToken::LeftCurlyBracket).set_values([
                 CSSToken::Whitespace,
                 CSSToken::Ident("color".into()),
                 CSSToken::Colon,
                 CSSToken::Whitespace,
-                CSSToken::Ident("blue".into()),
+                CSSToken::Ident(color.into()),
                 CSSToken::Semicolon,
                 CSSToken::Whitespace,
-                CSSToken::Whitespace,
-            ]),
-        );
+            ])
+        };
 
         assert_eq!(
             parser.list_of_rules(),
-            [CSSRule::QualifiedRule(
-                CSSQualifiedRule::default()
-                    .with_prelude([
-                        CSSToken::Hash("foo-1".into(), HashFlag::ID),
-                        CSSToken::Whitespace,
-                    ])
-                    .with_block(block)
-            ),],
+            [
+                CSSRule::QualifiedRule(
+                    CSSQualifiedRule::default()
+                        .with_prelude([
+                            CSSToken::Hash("foo-1".into(), HashFlag::ID),
+                            CSSToken::Whitespace,
+                        ])
+                        .with_block(block("red"))
+                ),
+                CSSRule::QualifiedRule(
+                    CSSQualifiedRule::default()
+                        .with_prelude([
+                            CSSToken::Hash("foo-2".into(), HashFlag::ID),
+                            CSSToken::Whitespace,
+                        ])
+                        .with_block(block("blue"))
+                ),
+            ],
         );
     }
 
@@ -229,4 +266,140 @@ mod tests {
             )]
         );
     }
+
+    #[test]
+    fn test_tokens_consumed() {
+        let mut parser = test_the_str!("#foo { color: red; }");
+        assert_eq!(parser.tokens_consumed(), 0);
+
+        // NOTE(phisyx): le jeton `#foo` est re-consommé par l'algorithme
+        // de consommation d'une règle qualifiée, sans être compté deux
+        // fois.
+        parser.stylesheet();
+        assert_eq!(parser.tokens_consumed(), 11);
+
+        // Le jeton EOF n'est pas compté.
+        parser.stylesheet();
+        assert_eq!(parser.tokens_consumed(), 11);
+    }
+
+    #[test]
+    fn test_source_order() {
+        let mut parser = test_the_str!(
+            "
+            #foo-1 { color: red; }
+            #foo-2 { color: blue; }
+            #foo-3 { color: green; }
+            "
+        );
+
+        let orders: Vec<_> = parser
+            .stylesheet()
+            .iter()
+            .map(CSSRule::source_order)
+            .collect();
+        assert_eq!(orders, [0, 1, 2]);
+
+        // Les règles d'un bloc `@media` suivent la règle `@media` dans
+        // l'ordre de la feuille de style.
+        let mut parser = test_the_str!(
+            "
+            #foo-1 { color: red; }
+            @media screen {
+                #foo-2 { color: blue; }
+                @media (min-width: 0) { #foo-3 { color: green; } }
+            }
+            #foo-4 { color: black; }
+            "
+        );
+
+        let stylesheet = parser.stylesheet();
+        let orders: Vec<_> =
+            stylesheet.iter().map(CSSRule::source_order).collect();
+        assert_eq!(orders, [0, 1, 5]);
+
+        let media = match &stylesheet[1] {
+            | CSSRule::AtRule(rule) => rule.rules().unwrap(),
+            | rule => panic!("at-rule attendue, obtenu {rule:?}"),
+        };
+        let orders: Vec<_> =
+            media.iter().map(CSSRule::source_order).collect();
+        assert_eq!(orders, [2, 3]);
+
+        let nested = match &media[1] {
+            | CSSRule::AtRule(rule) => rule.rules().unwrap(),
+            | rule => panic!("at-rule attendue, obtenu {rule:?}"),
+        };
+        assert_eq!(nested[0].source_order(), 4);
+    }
+
+    #[test]
+    fn test_deeply_nested_source_order() {
+        // Le bloc de chaque règle `@media` n'est analysé qu'une seule
+        // fois, quelle que soit la profondeur d'imbrication.
+        let depth = 64;
+        let input = "@media screen {".repeat(depth)
+            + "#foo { color: red; }"
+            + &"}".repeat(depth);
+        let stylesheet = CSSParser::from_str(&input).stylesheet();
+
+        let mut rule = &stylesheet[0];
+        for source_order in 0..depth {
+            assert_eq!(rule.source_order(), source_order);
+            rule = match rule {
+                | CSSRule::AtRule(rule) => &rule.rules().unwrap()[0],
+                | rule => panic!("at-rule attendue, obtenu {rule:?}"),
+            };
+        }
+        assert!(matches!(rule, CSSRule::QualifiedRule(_)));
+        assert_eq!(rule.source_order(), depth);
+    }
+
+    #[test]
+    fn test_qualified_rule_without_block() {
+        // Une règle qualifiée interrompue par la fin de l'entrée n'est
+        // pas retournée.
+        let mut parser = test_the_str!("#foo-1 { color: red; } #foo-2");
+        assert_eq!(parser.stylesheet().len(), 1);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_metrics() {
+        let mut parser =
+            test_the_str!("#foo { color: red; } @media screen { }");
+        assert_eq!(parser.metrics(), &parser::ParserMetrics::default());
+
+        parser.stylesheet();
+        let metrics = parser.metrics().clone();
+        assert_eq!(metrics.tokens, parser.tokens_consumed());
+        // Une règle qualifiée et une règle at-rule.
+        assert_eq!(metrics.nodes, 2);
+
+        parser.reset_metrics();
+        assert_eq!(parser.metrics(), &parser::ParserMetrics::default());
+
+        let mut parser = test_the_str!("color: red; width: 0");
+        parser.list_of_declarations();
+        assert_eq!(parser.metrics().nodes, 2);
+    }
+
+    #[test]
+    fn test_parser_from_str() {
+        let rule = || {
+            CSSRule::QualifiedRule(
+                CSSQualifiedRule::default()
+                    .with_prelude([CSSToken::Ident("a".into())])
+                    .with_block(CSSSimpleBlock::new(
+                        CSSToken::LeftCurlyBracket,
+                    )),
+            )
+        };
+
+        let mut parser = CSSParser::from_str("a{}");
+        assert_eq!(parser.stylesheet(), [rule()]);
+
+        let mut parser: CSSParser = "a{}".parse().unwrap();
+        assert_eq!(parser.stylesheet(), [rule()]);
+    }
 }
@@ -3,6 +3,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 mod codepoint;
+mod error;
 
 /// 4. Tokenization
 mod tokenization;
@@ -20,21 +21,33 @@ mod simple_block;
 mod grammars;
 mod style_blocks_content;
 
+/// Selectors Level 4 - 7. Linguistic Pseudo-classes
+mod pseudo_class;
+
+use std::{convert::Infallible, str};
+
 use infra::primitive::codepoint::CodePointIterator;
 use parser::{StreamIterator, StreamTokenIterator};
 
-use self::{
-    at_rule::CSSAtRule,
-    component_value::CSSComponentValue,
+pub use self::{
+    at_rule::{CSSAtRule, CSSKeyframe, CSSKeyframesRule},
+    component_value::{
+        trim_whitespace, CSSComponentValue, CSSComponentValuesList,
+    },
     declaration::{CSSDeclaration, CSSDeclarationList},
-    function::CSSFunction,
-    grammars::{CSSRule, CSSRuleList},
+    error::CSSParserError,
+    function::{CSSFunction, CSSMathValue, RepeatCount},
+    grammars::{CSSRule, CSSRuleList, CSSStyleSheet},
+    pseudo_class::{CSSDirectionality, CSSLinguisticPseudoClass},
     qualified_rule::CSSQualifiedRule,
+    style_blocks_content::CSSStyleBlock,
+    tokenization::CSSToken,
+};
+use self::{
     simple_block::CSSSimpleBlock,
-    style_blocks_content::{CSSStyleBlock, CSSStyleBlocksContents},
+    style_blocks_content::CSSStyleBlocksContents,
     tokenization::{CSSTokenStream, CSSTokenVariant, CSSTokenizer},
 };
-use crate::tokenization::CSSToken;
 
 // --------- //
 // Structure //
@@ -44,6 +57,15 @@ use crate::tokenization::CSSToken;
 pub struct CSSParser {
     tokens: CSSTokenStream,
     toplevel_flag: bool,
+    parse_errors: Vec<CSSParserError>,
+    next_source_order: usize,
+    /// Compteurs de profilage (voir [CSSParser::metrics]).
+    #[cfg(feature = "metrics")]
+    metrics: parser::ParserMetrics,
+    /// Nombre de jetons consommés lors de la dernière remise à zéro des
+    /// compteurs de profilage.
+    #[cfg(feature = "metrics")]
+    tokens_consumed_at_reset: usize,
 }
 
 // -------------- //
@@ -60,9 +82,26 @@ impl CSSParser {
         Self {
             tokens,
             toplevel_flag: Default::default(),
+            parse_errors: Default::default(),
+            next_source_order: Default::default(),
+            #[cfg(feature = "metrics")]
+            metrics: Default::default(),
+            #[cfg(feature = "metrics")]
+            tokens_consumed_at_reset: Default::default(),
         }
     }
 
+    /// Crée un analyseur à partir d'une chaîne de caractères. C'est le
+    /// point d'entrée le plus courant : il équivaut à
+    /// `CSSParser::new(input.chars())`.
+    ///
+    /// Voir aussi l'implémentation de [str::FromStr], qui permet
+    /// d'écrire `input.parse::<CSSParser>()`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &str) -> Self {
+        Self::new(input.chars())
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn from_iter<Iter>(input: Iter) -> Self
     where
@@ -72,10 +111,28 @@ impl CSSParser {
         Self {
             tokens,
             toplevel_flag: Default::default(),
+            parse_errors: Default::default(),
+            next_source_order: Default::default(),
+            #[cfg(feature = "metrics")]
+            metrics: Default::default(),
+            #[cfg(feature = "metrics")]
+            tokens_consumed_at_reset: Default::default(),
         }
     }
 }
 
+impl CSSParser {
+    /// Retire et retourne les erreurs d'analyse rencontrées jusqu'à
+    /// présent.
+    pub fn take_parse_errors(&mut self) -> Vec<CSSParserError> {
+        std::mem::take(&mut self.parse_errors)
+    }
+
+    fn parse_error(&mut self, error: CSSParserError) {
+        self.parse_errors.push(error);
+    }
+}
+
 impl CSSParser {
     pub fn consume_next_input_token(&mut self) -> CSSTokenVariant {
         self.tokens
@@ -94,6 +151,55 @@ impl CSSParser {
             .current_token()
             .expect("Il y a une c*ui**e dans le pâté?")
     }
+
+    /// Nombre de jetons d'entrée consommés jusqu'à présent (diagnostic de
+    /// performance).
+    pub fn tokens_consumed(&self) -> usize {
+        self.tokens.tokens_consumed()
+    }
+
+    /// Compteurs de profilage de l'instance : nombre de jetons
+    /// consommés, nombre de nœuds (règles et déclarations) créés et temps
+    /// passé dans les points d'entrée de l'analyseur.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&mut self) -> &parser::ParserMetrics {
+        self.metrics.tokens =
+            self.tokens_consumed() - self.tokens_consumed_at_reset;
+        &self.metrics
+    }
+
+    /// Remet les compteurs de profilage à zéro.
+    #[cfg(feature = "metrics")]
+    pub fn reset_metrics(&mut self) {
+        self.tokens_consumed_at_reset = self.tokens_consumed();
+        self.metrics.reset();
+    }
+
+    /// Mesure le temps passé dans un point d'entrée de l'analyseur.
+    fn measure<T>(
+        &mut self,
+        entry_point: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let output = entry_point(self);
+
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.elapsed += start.elapsed();
+        }
+
+        output
+    }
+
+    /// Compte un nœud créé par l'analyseur.
+    fn count_created_node(&mut self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.nodes += 1;
+        }
+    }
 }
 
 impl CSSParser {
@@ -131,6 +237,24 @@ impl CSSParser {
                     break;
                 }
 
+                // simple block with an associated token of <{-token>
+                //
+                // Assigner le bloc au bloc de la règle. Retourner la
+                // règle.
+                | variant
+                    if variant.is_simple_block_with(
+                        CSSToken::LeftCurlyBracket,
+                    ) =>
+                {
+                    at_rule.set_block(
+                        variant
+                            .component_value_unchecked()
+                            .simple_block_unchecked()
+                            .to_owned(),
+                    );
+                    break;
+                }
+
                 // Anything else
                 //
                 // Re-consommer le jeton d'entrée actuel. Consommer une
@@ -147,6 +271,30 @@ impl CSSParser {
             }
         }
 
+        // NOTE(phisyx): le bloc d'une règle de groupe conditionnelle
+        // est analysé une seule fois, ici. Ses règles sont numérotées
+        // à la suite de la règle, qui recevra l'index d'apparition
+        // actuel (voir [CSSParser::push_rule]).
+        if at_rule.is_conditional_group_rule() {
+            if let Some(block) = at_rule.block() {
+                let first_source_order = self.next_source_order + 1;
+                let mut parser = Self::from_iter(
+                    block
+                        .values()
+                        .iter()
+                        .cloned()
+                        .map(CSSTokenVariant::from),
+                );
+                parser.next_source_order = first_source_order;
+                let rules = parser.list_of_rules();
+                at_rule.set_rules(
+                    rules,
+                    parser.next_source_order - first_source_order,
+                );
+            }
+        }
+
+        self.count_created_node();
         at_rule
     }
 
@@ -186,8 +334,8 @@ impl CSSParser {
         let current_variant = self.current_input_token();
         let current_token = current_variant.token_unchecked();
 
-        let mut declaration =
-            CSSDeclaration::default().with_name(current_token.name());
+        let mut declaration = CSSDeclaration::default()
+            .with_property_name(current_token.name());
 
         self.tokens
             .advance_as_long_as_possible(|token| token.is_whitespace());
@@ -232,6 +380,15 @@ impl CSSParser {
             declaration.remove_last_n_values(1);
         }
 
+        // Une déclaration dont la valeur est vide (après suppression des
+        // espaces blancs et des commentaires) est invalide : il s'agit
+        // d'une erreur d'analyse. Ne rien retourner.
+        if declaration.has_empty_value() {
+            self.parse_error(CSSParserError::EmptyDeclarationValue);
+            return None;
+        }
+
+        self.count_created_node();
         Some(declaration)
     }
 
@@ -334,8 +491,10 @@ impl CSSParser {
                         CSSParser::from_iter(temporary_list.into_iter());
                     if let Some(declaration) = stream.consume_declaration()
                     {
+                        self.count_created_node();
                         list_of_declarations.push(declaration.into());
                     }
+                    self.parse_errors.extend(stream.take_parse_errors());
                 }
 
                 // Anything else
@@ -405,7 +564,7 @@ impl CSSParser {
                     if let Some(qualified_rule) =
                         self.consume_qualified_rule()
                     {
-                        rules.push(qualified_rule.into());
+                        self.push_rule(&mut rules, qualified_rule);
                     }
                 }
 
@@ -416,7 +575,7 @@ impl CSSParser {
                 | variant if variant.is_at_keyword() => {
                     self.tokens.reconsume_current_token();
                     let at_rule = self.consume_at_rule();
-                    rules.push(at_rule.into());
+                    self.push_rule(&mut rules, at_rule);
                 }
 
                 // Anything else
@@ -429,7 +588,7 @@ impl CSSParser {
                     if let Some(qualified_rule) =
                         self.consume_qualified_rule()
                     {
-                        rules.push(qualified_rule.into());
+                        self.push_rule(&mut rules, qualified_rule);
                     }
                 }
             };
@@ -438,6 +597,21 @@ impl CSSParser {
         rules
     }
 
+    /// Ajoute la règle à la liste des règles, en lui attribuant son index
+    /// d'apparition.
+    fn push_rule(
+        &mut self,
+        rules: &mut CSSRuleList,
+        rule: impl Into<CSSRule>,
+    ) {
+        let mut rule = rule.into();
+        rule.set_source_order(self.next_source_order);
+        // NOTE(phisyx): les règles imbriquées sont numérotées à la
+        // suite de la règle qui les contient.
+        self.next_source_order += 1 + rule.nested_rules_count();
+        rules.push(rule);
+    }
+
     fn consume_qualified_rule(&mut self) -> Option<CSSQualifiedRule> {
         let mut qualified_rule = CSSQualifiedRule::default();
 
@@ -447,7 +621,7 @@ impl CSSParser {
                 //
                 // Il s'agit d'une erreur d'analyse. Ne rien retourner.
                 // TODO(css): gérer les erreurs.
-                | variant if variant.is_eof() => {}
+                | variant if variant.is_eof() => return None,
 
                 // <{-token>
                 //
@@ -474,6 +648,7 @@ impl CSSParser {
                             .simple_block_unchecked()
                             .to_owned(),
                     );
+                    break;
                 }
 
                 // Anything else
@@ -492,6 +667,7 @@ impl CSSParser {
             }
         }
 
+        self.count_created_node();
         qualified_rule.into()
     }
 
@@ -606,6 +782,7 @@ impl CSSParser {
                     if let Some(decl) = stream.consume_declaration() {
                         decls.push(decl.into());
                     }
+                    self.parse_errors.extend(stream.take_parse_errors());
                 }
 
                 // <delim-token> with a value of "&" (U+0026 AMPERSAND)
@@ -645,3 +822,15 @@ impl CSSParser {
         decls
     }
 }
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl str::FromStr for CSSParser {
+    type Err = Infallible;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_str(input))
+    }
+}
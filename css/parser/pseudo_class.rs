@@ -0,0 +1,205 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::{
+    component_value::CSSComponentValue, function::CSSFunction,
+    preserved_tokens::CSSPreservedToken, tokenization::CSSToken,
+};
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Pseudo-classes fonctionnelles linguistiques.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum CSSLinguisticPseudoClass {
+    /// La pseudo-classe `:lang()` représente un élément qui est dans
+    /// l'une des langues listées dans son argument. Elle accepte une
+    /// liste de plages de langues, séparées par des virgules.
+    Lang(Vec<String>),
+
+    /// La pseudo-classe `:dir()` permet à l'auteur d'écrire des
+    /// sélecteurs qui représentent un élément en fonction de sa
+    /// directionnalité, telle que déterminée par le langage du document.
+    Dir(CSSDirectionality),
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum CSSDirectionality {
+    /// De gauche à droite.
+    Ltr,
+    /// De droite à gauche.
+    Rtl,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl CSSLinguisticPseudoClass {
+    /// Convertit une fonction `lang()` ou `dir()` en pseudo-classe.
+    ///
+    /// Retourne [None] lorsque la fonction n'est pas une pseudo-classe
+    /// linguistique ou que ses arguments ne sont pas valides.
+    pub(super) fn from_function(function: &CSSFunction) -> Option<Self> {
+        let arguments = function
+            .values()
+            .split(|value| value.is_comma())
+            .map(|argument| {
+                let mut argument =
+                    argument.iter().filter(|value| !value.is_whitespace());
+                match (argument.next(), argument.next()) {
+                    | (Some(value), None) => value.ident_or_string(),
+                    | _ => None,
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let name = function.name();
+        if name.eq_ignore_ascii_case("lang") {
+            Some(Self::Lang(arguments))
+        } else if name.eq_ignore_ascii_case("dir") {
+            match arguments.as_slice() {
+                | [dir] => dir.parse().ok().map(Self::Dir),
+                | _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Évaluation basique de la pseudo-classe : la valeur passée en
+    /// argument est celle de l'attribut `lang` (resp. `dir`) hérité de
+    /// l'élément.
+    ///
+    /// Pour `:lang()`, une plage de langues correspond à une langue
+    /// lorsqu'elle lui est égale ou en est un préfixe suivi d'un `-`, sans
+    /// tenir compte de la casse : `:lang(en)` correspond à `lang="en-US"`,
+    /// mais `:lang(en-US)` ne correspond pas à `lang="en"`.
+    pub fn matches(&self, inherited_value: &str) -> bool {
+        match self {
+            | Self::Lang(ranges) => ranges.iter().any(|range| {
+                language_range_matches(range, inherited_value)
+            }),
+            | Self::Dir(dir) => inherited_value
+                .parse::<CSSDirectionality>()
+                .map(|inherited_dir| inherited_dir == *dir)
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl CSSComponentValue {
+    fn ident_or_string(&self) -> Option<String> {
+        match self {
+            | Self::Preserved(CSSPreservedToken(
+                CSSToken::Ident(value) | CSSToken::String(value),
+            )) => Some(value.to_owned()),
+            | _ => None,
+        }
+    }
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl std::str::FromStr for CSSDirectionality {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            | "ltr" => Self::Ltr,
+            | "rtl" => Self::Rtl,
+            | _ => return Err("Directionnalité invalide"),
+        })
+    }
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Une plage de langues `*` correspond à n'importe quelle langue non
+/// vide.
+fn language_range_matches(range: &str, language: &str) -> bool {
+    if language.is_empty() {
+        return false;
+    }
+
+    if range == "*" {
+        return true;
+    }
+
+    let range = range.to_ascii_lowercase();
+    let language = language.to_ascii_lowercase();
+
+    language == range
+        || language
+            .strip_prefix(&range)
+            .filter(|rest| rest.starts_with('-'))
+            .is_some()
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grammars::CSSRule, test_the_str};
+
+    fn pseudo_classes(
+        mut parser: crate::CSSParser,
+    ) -> Vec<CSSLinguisticPseudoClass> {
+        match parser.stylesheet().pop() {
+            | Some(CSSRule::QualifiedRule(rule)) => {
+                rule.linguistic_pseudo_classes()
+            }
+            | _ => panic!("Règle qualifiée attendue."),
+        }
+    }
+
+    #[test]
+    fn test_lang() {
+        let langs =
+            pseudo_classes(test_the_str!(":lang(fr) { color: red; }"));
+        assert_eq!(
+            langs,
+            [CSSLinguisticPseudoClass::Lang(vec!["fr".into()])]
+        );
+
+        let lang = &langs[0];
+        assert!(lang.matches("fr"));
+        assert!(lang.matches("fr-CA"));
+        assert!(!lang.matches("fry"));
+        assert!(!lang.matches("en"));
+
+        let langs =
+            pseudo_classes(test_the_str!(":lang(en-US, \"de\") {}"));
+        let lang = &langs[0];
+        assert!(lang.matches("en-us"));
+        assert!(lang.matches("de"));
+        assert!(!lang.matches("en"));
+    }
+
+    #[test]
+    fn test_dir() {
+        let dirs = pseudo_classes(test_the_str!("p:dir(rtl) {}"));
+        assert_eq!(
+            dirs,
+            [CSSLinguisticPseudoClass::Dir(CSSDirectionality::Rtl)]
+        );
+        assert!(dirs[0].matches("RTL"));
+        assert!(!dirs[0].matches("ltr"));
+        assert!(!dirs[0].matches("auto"));
+
+        assert!(pseudo_classes(test_the_str!(":dir(up) {}")).is_empty());
+    }
+}
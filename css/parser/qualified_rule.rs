@@ -4,8 +4,11 @@
 
 use crate::{
     component_value::{CSSComponentValue, CSSComponentValuesList},
+    declaration::CSSDeclarationList,
+    pseudo_class::CSSLinguisticPseudoClass,
     simple_block::CSSSimpleBlock,
-    tokenization::CSSToken,
+    tokenization::{CSSToken, CSSTokenVariant},
+    CSSParser,
 };
 
 // --------- //
@@ -15,10 +18,10 @@ use crate::{
 /// Une règle qualifiée possède un prélude constitué d'une liste de
 /// valeurs de composants, et un bloc constitué d'un simple bloc {}.
 #[derive(Debug)]
-#[derive(PartialEq, Eq)]
 pub struct CSSQualifiedRule {
     prelude: CSSComponentValuesList,
     block: CSSSimpleBlock,
+    source_order: usize,
 }
 
 // -------------- //
@@ -43,6 +46,54 @@ impl CSSQualifiedRule {
     }
 }
 
+impl CSSQualifiedRule {
+    /// Le prélude de la règle : pour une règle de style, la liste de
+    /// sélecteurs.
+    pub fn prelude(&self) -> &CSSComponentValuesList {
+        &self.prelude
+    }
+
+    pub(super) fn block(&self) -> &CSSSimpleBlock {
+        &self.block
+    }
+
+    /// Les déclarations du bloc de la règle, analysé comme une liste de
+    /// déclarations.
+    pub fn declarations(&self) -> CSSDeclarationList {
+        CSSParser::from_iter(
+            self.block
+                .values()
+                .iter()
+                .cloned()
+                .map(CSSTokenVariant::from),
+        )
+        .list_of_declarations()
+    }
+
+    /// Index d'apparition de la règle dans la feuille de style.
+    pub fn source_order(&self) -> usize {
+        self.source_order
+    }
+
+    /// Les pseudo-classes linguistiques (`:lang()`, `:dir()`) du
+    /// prélude de la règle.
+    pub fn linguistic_pseudo_classes(
+        &self,
+    ) -> Vec<CSSLinguisticPseudoClass> {
+        self.prelude
+            .windows(2)
+            .filter_map(|values| match values {
+                | [colon, CSSComponentValue::Function(function)]
+                    if colon.is_colon() =>
+                {
+                    CSSLinguisticPseudoClass::from_function(function)
+                }
+                | _ => None,
+            })
+            .collect()
+    }
+}
+
 impl CSSQualifiedRule {
     pub(super) fn append(&mut self, value: CSSComponentValue) {
         self.prelude.push(value);
@@ -51,13 +102,32 @@ impl CSSQualifiedRule {
     pub(super) fn set_block(&mut self, block: CSSSimpleBlock) {
         self.block = block;
     }
+
+    pub(super) fn set_source_order(&mut self, source_order: usize) {
+        self.source_order = source_order;
+    }
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+// NOTE(phisyx): l'index d'apparition n'intervient pas dans la
+// comparaison de deux règles.
+impl PartialEq for CSSQualifiedRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.prelude == other.prelude && self.block == other.block
+    }
 }
 
+impl Eq for CSSQualifiedRule {}
+
 impl Default for CSSQualifiedRule {
     fn default() -> Self {
         Self {
             prelude: Default::default(),
             block: CSSSimpleBlock::new(CSSToken::LeftCurlyBracket),
+            source_order: Default::default(),
         }
     }
 }
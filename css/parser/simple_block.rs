@@ -2,6 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use core::fmt;
+
 use crate::{
     component_value::{CSSComponentValue, CSSComponentValuesList},
     tokenization::CSSToken,
@@ -51,6 +53,12 @@ impl CSSSimpleBlock {
     }
 }
 
+impl CSSSimpleBlock {
+    pub(super) fn values(&self) -> &CSSComponentValuesList {
+        &self.value
+    }
+}
+
 impl CSSSimpleBlock {
     pub(super) fn append(
         &mut self,
@@ -68,6 +76,16 @@ impl CSSSimpleBlock {
 // Implémentation // -> Interface
 // -------------- //
 
+impl fmt::Display for CSSSimpleBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.token)?;
+        self.value
+            .iter()
+            .try_for_each(|value| write!(f, "{value}"))?;
+        write!(f, "{}", self.token.mirror())
+    }
+}
+
 impl From<CSSToken> for CSSSimpleBlock {
     fn from(token: CSSToken) -> Self {
         match token {
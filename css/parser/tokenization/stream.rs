@@ -153,17 +153,12 @@ impl CSSTokenVariant {
         )
     }
 
+    /// Vérifie que le jeton est le jeton fermant attendu d'un bloc
+    /// (`}`, `]` ou `)`).
     pub(crate) fn is_mirror(&self, cmp_ending_token: &CSSToken) -> bool {
-        if !(self.is_left_curly_bracket()
-            || self.is_left_square_bracket()
-            || self.is_left_parenthesis())
-        {
-            return false;
-        }
-
-        if self.is_right_curly_bracket()
+        if !(self.is_right_curly_bracket()
             || self.is_right_square_bracket()
-            || self.is_right_parenthesis()
+            || self.is_right_parenthesis())
         {
             return false;
         }
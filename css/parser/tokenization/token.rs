@@ -6,6 +6,8 @@
 // Structure //
 // --------- //
 
+use core::fmt;
+
 use infra::primitive::codepoint::CodePoint;
 use parser::StreamInput;
 
@@ -181,6 +183,46 @@ impl CSSToken {
 //               énumération.
 impl Eq for CSSToken {}
 
+/// Sérialisation d'un jeton : la suite de points de code qui, une fois
+/// tokenisée, produit à nouveau ce jeton.
+impl fmt::Display for CSSToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            | Self::Ident(name) => write!(f, "{name}"),
+            | Self::Function(name) => write!(f, "{name}("),
+            | Self::AtKeyword(name) => write!(f, "@{name}"),
+            | Self::Hash(name, _) => write!(f, "#{name}"),
+            | Self::String(string) => write!(
+                f,
+                "\"{}\"",
+                string.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+            | Self::BadString => write!(f, "\"\n"),
+            | Self::Url(url) => write!(f, "url({url})"),
+            | Self::BadUrl => write!(f, "url()"),
+            | Self::Delim(ch) => write!(f, "{ch}"),
+            | Self::Number(number, _) => write!(f, "{number}"),
+            | Self::Percentage(number) => write!(f, "{number}%"),
+            | Self::Dimension(number, _, unit) => {
+                write!(f, "{number}{}", unit.0)
+            }
+            | Self::Whitespace => write!(f, " "),
+            | Self::CDO => write!(f, "<!--"),
+            | Self::CDC => write!(f, "-->"),
+            | Self::Colon => write!(f, ":"),
+            | Self::Semicolon => write!(f, ";"),
+            | Self::Comma => write!(f, ","),
+            | Self::LeftSquareBracket => write!(f, "["),
+            | Self::RightSquareBracket => write!(f, "]"),
+            | Self::LeftParenthesis => write!(f, "("),
+            | Self::RightParenthesis => write!(f, ")"),
+            | Self::LeftCurlyBracket => write!(f, "{{"),
+            | Self::RightCurlyBracket => write!(f, "}}"),
+            | Self::EOF => Ok(()),
+        }
+    }
+}
+
 impl StreamInput for CSSToken {
     fn eof() -> Self {
         Self::EOF
@@ -173,7 +173,7 @@ where
         repr.extend(&digits);
 
         if let Some(v) = self.input.peek_until::<Vec<_>>(2) {
-            if v[0] == '.' && v[1].is_css_digit() {
+            if v.len() == 2 && v[0] == '.' && v[1].is_css_digit() {
                 self.input.advance(2);
                 repr.extend(&v);
                 flag = NumberFlag::Number;
@@ -184,10 +184,12 @@ where
         }
 
         if let Some(v) = self.input.peek_until::<Vec<_>>(3) {
-            let is_minus_or_plus = v[1] == '-' || v[1] == '+';
-            if v[0].to_ascii_lowercase() == 'e'
-                && (is_minus_or_plus && v[2].is_css_digit())
-                || v[1].is_css_digit()
+            let is_minus_or_plus =
+                v.len() == 3 && (v[1] == '-' || v[1] == '+');
+            if v.len() >= 2
+                && v[0].to_ascii_lowercase() == 'e'
+                && (is_minus_or_plus && v[2].is_css_digit()
+                    || v[1].is_css_digit())
             {
                 let offset = if is_minus_or_plus { 3 } else { 2 };
                 self.input.advance(offset);
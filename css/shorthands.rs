@@ -0,0 +1,752 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Propriétés raccourcies (shorthands).
+//!
+//! Une propriété raccourcie permet de définir plusieurs propriétés
+//! longues (longhands) en une seule déclaration. Les fonctions de ce
+//! module décomposent une déclaration raccourcie en une liste de paires
+//! `(nom, valeur)` de propriétés longues. Une liste vide signifie que la
+//! déclaration est invalide.
+//!
+//! Voir <https://drafts.csswg.org/css-cascade/#shorthand-property>
+
+use parser::{CSSComponentValue, CSSDeclaration, CSSToken};
+
+use crate::values::Length;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Mots-clés CSS globaux : acceptés seuls par toutes les propriétés, ils
+/// s'appliquent à toutes les propriétés longues d'un raccourci.
+const CSS_WIDE_KEYWORDS: [&str; 5] =
+    ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// Mots-clés `<absolute-size>` et `<relative-size>` de `font-size`.
+const FONT_SIZE_KEYWORDS: [&str; 10] = [
+    "xx-small",
+    "x-small",
+    "small",
+    "medium",
+    "large",
+    "x-large",
+    "xx-large",
+    "xxx-large",
+    "larger",
+    "smaller",
+];
+
+/// Mots-clés `<line-width>` de `border-width`.
+const LINE_WIDTH_KEYWORDS: [&str; 3] = ["thin", "medium", "thick"];
+
+/// Mots-clés `<line-style>` de `border-style`.
+const LINE_STYLE_KEYWORDS: [&str; 10] = [
+    "none", "hidden", "dotted", "dashed", "solid", "double", "groove",
+    "ridge", "inset", "outset",
+];
+
+/// Couleurs nommées de `<named-color>`.
+///
+/// Voir <https://drafts.csswg.org/css-color/#named-colors>
+const NAMED_COLORS: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+/// Côtés d'une boîte, dans l'ordre des valeurs de `margin` et `padding`.
+const BOX_SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+
+/// Propriétés longues de `border`, dans l'ordre de la liste retournée.
+const BORDER_LONGHANDS: [&str; 3] =
+    ["border-width", "border-style", "border-color"];
+
+/// Propriétés longues de `font`, dans l'ordre de la liste retournée.
+const FONT_LONGHANDS: [&str; 5] = [
+    "font-style",
+    "font-weight",
+    "font-size",
+    "line-height",
+    "font-family",
+];
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Décompose la propriété raccourcie `font` en `font-style`,
+/// `font-weight`, `font-size`, `line-height` et `font-family`.
+///
+/// ```text
+/// [ <font-style> || <font-weight> ]? <font-size> [ / <line-height> ]?
+/// <font-family>
+/// ```
+///
+/// Les parties omises prennent leur valeur initiale (`normal`). Les
+/// mots-clés sont retournés en minuscules, les noms de familles gardent
+/// leur casse.
+///
+/// Retourne une liste vide si la déclaration n'est pas `font` ou si sa
+/// valeur est invalide.
+///
+/// NOTE: les sous-propriétés `font-variant` et `font-stretch` ne sont pas
+/// encore prises en charge : une valeur qui les utilise est invalide.
+///
+/// Voir <https://drafts.csswg.org/css-fonts/#font-prop>
+pub fn expand_font_shorthand(
+    decl: &CSSDeclaration,
+) -> Vec<(String, String)> {
+    if decl.name() != "font" {
+        return vec![];
+    }
+
+    preserved_tokens(decl.values())
+        .and_then(|tokens| expand_font(&tokens))
+        .unwrap_or_default()
+}
+
+/// Décompose les propriétés raccourcies de boîte :
+///
+///   - `margin` et `padding` en leurs quatre côtés (`-top`, `-right`,
+///     `-bottom`, `-left`). Une valeur s'applique aux quatre côtés ; deux
+///     valeurs à haut/bas puis droite/gauche ; trois valeurs à haut,
+///     droite/gauche puis bas ; quatre valeurs à haut, droite, bas puis
+///     gauche.
+///   - `border` en `border-width`, `border-style` et `border-color`, dans
+///     n'importe quel ordre. Les parties omises prennent leur valeur
+///     initiale (`medium`, `none`, `currentcolor`).
+///
+/// Retourne une liste vide si la propriété n'est pas l'une de celles-ci
+/// ou si la valeur est invalide.
+///
+/// NOTE: seules les couleurs sous forme de mot-clé ou hexadécimale sont
+/// prises en charge pour `border` : les fonctions (`rgb()`, ...) rendent
+/// la valeur invalide.
+///
+/// Voir <https://drafts.csswg.org/css-box/#margin-shorthand>
+/// Voir <https://drafts.csswg.org/css-backgrounds/#propdef-border>
+pub fn expand_box_shorthand(
+    name: &str,
+    values: &[CSSComponentValue],
+) -> Vec<(String, String)> {
+    let name = name.to_ascii_lowercase();
+    let expand = |tokens: Vec<&CSSToken>| match name.as_str() {
+        | "margin" => expand_box_sides(&name, &tokens, is_margin),
+        | "padding" => expand_box_sides(&name, &tokens, is_padding),
+        | "border" => expand_border(&tokens),
+        | _ => None,
+    };
+
+    preserved_tokens(values)
+        .and_then(expand)
+        .unwrap_or_default()
+}
+
+fn expand_font(tokens: &[&CSSToken]) -> Option<Vec<(String, String)>> {
+    if let [token] = tokens {
+        if let Some(keyword) = css_wide_keyword(token) {
+            return Some(longhands(FONT_LONGHANDS, [keyword; 5]));
+        }
+    }
+
+    let mut tokens = tokens.iter().copied().peekable();
+
+    // [ <font-style> || <font-weight> ]? <font-size>
+    let mut style = None;
+    let mut weight = None;
+    let mut normals = 0;
+    let size = loop {
+        let token = tokens.next()?;
+        let keyword = keyword(token);
+
+        match keyword.as_deref() {
+            | Some("normal") => normals += 1,
+            | Some("italic" | "oblique") if style.is_none() => {
+                style = keyword;
+            }
+            | Some("bold" | "bolder" | "lighter") if weight.is_none() => {
+                weight = keyword;
+            }
+            | _ if weight.is_none() && is_font_weight_number(token) => {
+                weight = Some(token.to_string());
+            }
+            | _ if is_font_size(token) => {
+                break keyword.unwrap_or_else(|| token.to_string());
+            }
+            | _ => return None,
+        }
+
+        // font-style, font-variant, font-weight et font-stretch : au plus
+        // quatre valeurs avant font-size.
+        let prefix_size = normals
+            + usize::from(style.is_some())
+            + usize::from(weight.is_some());
+        if prefix_size > 4 {
+            return None;
+        }
+    };
+
+    // [ / <line-height> ]?
+    let mut line_height = None;
+    if tokens.next_if_eq(&&CSSToken::Delim('/')).is_some() {
+        let token = tokens.next()?;
+        if !is_line_height(token) {
+            return None;
+        }
+        line_height =
+            Some(keyword(token).unwrap_or_else(|| token.to_string()));
+    }
+
+    // <font-family>
+    let family = font_family(&tokens.collect::<Vec<_>>())?;
+
+    let normal = || "normal".to_owned();
+    Some(longhands(
+        FONT_LONGHANDS,
+        [
+            style.unwrap_or_else(normal),
+            weight.unwrap_or_else(normal),
+            size,
+            line_height.unwrap_or_else(normal),
+            family,
+        ],
+    ))
+}
+
+fn expand_box_sides(
+    name: &str,
+    tokens: &[&CSSToken],
+    is_valid: fn(&CSSToken) -> bool,
+) -> Option<Vec<(String, String)>> {
+    let names = BOX_SIDES.map(|side| format!("{name}-{side}"));
+
+    if let [token] = tokens {
+        if let Some(keyword) = css_wide_keyword(token) {
+            return Some(longhands(names, [keyword; 4]));
+        }
+    }
+
+    let values: Vec<String> = tokens
+        .iter()
+        .map(|token| {
+            is_valid(token).then(|| {
+                keyword(token).unwrap_or_else(|| token.to_string())
+            })
+        })
+        .collect::<Option<_>>()?;
+
+    let sides = match values.as_slice() {
+        | [all] => [all, all, all, all],
+        | [vertical, horizontal] => {
+            [vertical, horizontal, vertical, horizontal]
+        }
+        | [top, horizontal, bottom] => {
+            [top, horizontal, bottom, horizontal]
+        }
+        | [top, right, bottom, left] => [top, right, bottom, left],
+        | _ => return None,
+    };
+
+    Some(longhands(names, sides))
+}
+
+/// `<line-width> || <line-style> || <color>`
+fn expand_border(tokens: &[&CSSToken]) -> Option<Vec<(String, String)>> {
+    if let [token] = tokens {
+        if let Some(keyword) = css_wide_keyword(token) {
+            return Some(longhands(BORDER_LONGHANDS, [keyword; 3]));
+        }
+    }
+
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let mut width = None;
+    let mut style = None;
+    let mut color = None;
+    for token in tokens {
+        let value = keyword(token).unwrap_or_else(|| token.to_string());
+        let slot = if is_line_width(token) {
+            &mut width
+        } else if is_line_style(token) {
+            &mut style
+        } else if is_color(token) {
+            &mut color
+        } else {
+            return None;
+        };
+
+        if slot.replace(value).is_some() {
+            return None;
+        }
+    }
+
+    Some(longhands(
+        BORDER_LONGHANDS,
+        [
+            width.unwrap_or_else(|| "medium".to_owned()),
+            style.unwrap_or_else(|| "none".to_owned()),
+            color.unwrap_or_else(|| "currentcolor".to_owned()),
+        ],
+    ))
+}
+
+/// `[ <family-name> | <generic-family> ]#` : une famille est soit une
+/// chaîne de caractères, soit une suite d'identifiants.
+fn font_family(tokens: &[&CSSToken]) -> Option<String> {
+    let families: Option<Vec<String>> = tokens
+        .split(|token| **token == CSSToken::Comma)
+        .map(|family| match family {
+            | [CSSToken::String(_)] => Some(family[0].to_string()),
+            | [] => None,
+            | names => names
+                .iter()
+                .map(|name| match name {
+                    | CSSToken::Ident(name) => Some(name.as_str()),
+                    | _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(|names| names.join(" ")),
+        })
+        .collect();
+
+    families.map(|families| families.join(", "))
+}
+
+fn is_font_weight_number(token: &CSSToken) -> bool {
+    matches!(token, CSSToken::Number(n, _) if (1.0..=1000.0).contains(n))
+}
+
+fn is_font_size(token: &CSSToken) -> bool {
+    is_one_of_keywords(token, FONT_SIZE_KEYWORDS)
+        || length_percentage(token).is_some_and(|n| n >= 0.0)
+}
+
+fn is_line_height(token: &CSSToken) -> bool {
+    match token {
+        | CSSToken::Ident(name) => name.eq_ignore_ascii_case("normal"),
+        | CSSToken::Number(n, _) => *n >= 0.0,
+        | _ => length_percentage(token).is_some_and(|n| n >= 0.0),
+    }
+}
+
+/// `<length-percentage> | auto`
+fn is_margin(token: &CSSToken) -> bool {
+    is_one_of_keywords(token, ["auto"])
+        || length_percentage(token).is_some()
+}
+
+/// `<length-percentage [0,∞]>`
+fn is_padding(token: &CSSToken) -> bool {
+    length_percentage(token).is_some_and(|n| n >= 0.0)
+}
+
+/// `<line-width>` : un mot-clé ou une longueur positive, les
+/// pourcentages ne sont pas autorisés.
+fn is_line_width(token: &CSSToken) -> bool {
+    is_one_of_keywords(token, LINE_WIDTH_KEYWORDS)
+        || !matches!(token, CSSToken::Percentage(_))
+            && length_percentage(token).is_some_and(|n| n >= 0.0)
+}
+
+fn is_line_style(token: &CSSToken) -> bool {
+    is_one_of_keywords(token, LINE_STYLE_KEYWORDS)
+}
+
+/// `<color>` : une couleur hexadécimale, une couleur nommée,
+/// `currentcolor` ou `transparent`.
+fn is_color(token: &CSSToken) -> bool {
+    match token {
+        | CSSToken::Hash(hex, _) => {
+            matches!(hex.len(), 3 | 4 | 6 | 8)
+                && hex.chars().all(|ch| ch.is_ascii_hexdigit())
+        }
+        | CSSToken::Ident(_) => {
+            is_one_of_keywords(token, NAMED_COLORS)
+                || is_one_of_keywords(
+                    token,
+                    ["currentcolor", "transparent"],
+                )
+        }
+        | _ => false,
+    }
+}
+
+/// Valeur numérique d'un jeton `<length-percentage>`, zéro sans unité
+/// compris.
+fn length_percentage(token: &CSSToken) -> Option<f64> {
+    match token {
+        | CSSToken::Percentage(n) => Some(*n),
+        | CSSToken::Dimension(n, _, unit)
+            if Length::from_dimension(*n as f32, &unit.0).is_some() =>
+        {
+            Some(*n)
+        }
+        | CSSToken::Number(n, _) if *n == 0.0 => Some(*n),
+        | _ => None,
+    }
+}
+
+fn is_one_of_keywords<const N: usize>(
+    token: &CSSToken,
+    keywords: [&str; N],
+) -> bool {
+    match token {
+        | CSSToken::Ident(name) => keywords
+            .iter()
+            .any(|keyword| name.eq_ignore_ascii_case(keyword)),
+        | _ => false,
+    }
+}
+
+/// Jetons conservés des valeurs de composants, sans les espaces blancs.
+/// Retourne `None` si une valeur est une fonction ou un bloc simple.
+fn preserved_tokens(
+    values: &[CSSComponentValue],
+) -> Option<Vec<&CSSToken>> {
+    values
+        .iter()
+        .filter(|value| !value.is_whitespace())
+        .map(CSSComponentValue::token)
+        .collect()
+}
+
+/// Mot-clé en minuscules, si le jeton est un identifiant.
+fn keyword(token: &CSSToken) -> Option<String> {
+    match token {
+        | CSSToken::Ident(name) => Some(name.to_ascii_lowercase()),
+        | _ => None,
+    }
+}
+
+fn css_wide_keyword(token: &CSSToken) -> Option<&'static str> {
+    let keyword = keyword(token)?;
+    CSS_WIDE_KEYWORDS
+        .into_iter()
+        .find(|css_wide_keyword| *css_wide_keyword == keyword)
+}
+
+fn longhands<const N: usize>(
+    names: [impl ToString; N],
+    values: [impl ToString; N],
+) -> Vec<(String, String)> {
+    names
+        .into_iter()
+        .zip(values)
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use parser::CSSParser;
+
+    use super::*;
+
+    fn expand(value: &str) -> Vec<(String, String)> {
+        let mut parser = CSSParser::from_str(value);
+        expand_font_shorthand(&parser.declaration().unwrap())
+    }
+
+    fn font(
+        style: &str,
+        weight: &str,
+        size: &str,
+        line_height: &str,
+        family: &str,
+    ) -> Vec<(String, String)> {
+        longhands(
+            FONT_LONGHANDS,
+            [style, weight, size, line_height, family],
+        )
+    }
+
+    #[test]
+    fn test_expand_font_shorthand() {
+        assert_eq!(
+            expand("font: italic bold 12px/1.5 sans-serif"),
+            font("italic", "bold", "12px", "1.5", "sans-serif")
+        );
+
+        // Les parties omises prennent leur valeur initiale.
+        assert_eq!(
+            expand("font: 12px serif"),
+            font("normal", "normal", "12px", "normal", "serif")
+        );
+        assert_eq!(
+            expand("font: 700 Italic 120%/normal serif"),
+            font("italic", "700", "120%", "normal", "serif")
+        );
+        assert_eq!(
+            expand("font: normal large/2em \"Roboto\", Times New Roman"),
+            font(
+                "normal",
+                "normal",
+                "large",
+                "2em",
+                "\"Roboto\", Times New Roman"
+            )
+        );
+
+        // Un mot-clé global s'applique à toutes les propriétés longues.
+        assert_eq!(
+            expand("font: INHERIT"),
+            font("inherit", "inherit", "inherit", "inherit", "inherit")
+        );
+    }
+
+    #[test]
+    fn test_expand_font_shorthand_invalid() {
+        // font-size et font-family sont obligatoires.
+        assert!(expand("font: bold serif").is_empty());
+        assert!(expand("font: 12px").is_empty());
+        // Valeurs invalides.
+        assert!(expand("font: bold bold 12px serif").is_empty());
+        assert!(expand("font: 12px/ serif").is_empty());
+        assert!(expand("font: -1px serif").is_empty());
+        assert!(expand("font: 12deg serif").is_empty());
+        assert!(expand("font: 12px serif,").is_empty());
+        assert!(expand("font: 12px 'a' b").is_empty());
+        // Ce n'est pas la propriété font.
+        assert!(expand("margin: 12px serif").is_empty());
+    }
+
+    fn expand_box(value: &str) -> Vec<(String, String)> {
+        let mut parser = CSSParser::from_str(value);
+        let decl = parser.declaration().unwrap();
+        expand_box_shorthand(decl.name(), decl.values())
+    }
+
+    fn sides(
+        name: &str,
+        [top, right, bottom, left]: [&str; 4],
+    ) -> Vec<(String, String)> {
+        longhands(
+            BOX_SIDES.map(|side| format!("{name}-{side}")),
+            [top, right, bottom, left],
+        )
+    }
+
+    #[test]
+    fn test_expand_box_shorthand_sides() {
+        // 1 valeur
+        assert_eq!(
+            expand_box("margin: 1px"),
+            sides("margin", ["1px", "1px", "1px", "1px"])
+        );
+        // 2 valeurs
+        assert_eq!(
+            expand_box("margin: 1px AUTO"),
+            sides("margin", ["1px", "auto", "1px", "auto"])
+        );
+        // 3 valeurs
+        assert_eq!(
+            expand_box("padding: 1px 2% 3em"),
+            sides("padding", ["1px", "2%", "3em", "2%"])
+        );
+        // 4 valeurs
+        assert_eq!(
+            expand_box("margin: 1px 2px 3px -4px"),
+            sides("margin", ["1px", "2px", "3px", "-4px"])
+        );
+        assert_eq!(
+            expand_box("padding: 0 1px 2px 3px"),
+            sides("padding", ["0", "1px", "2px", "3px"])
+        );
+        assert_eq!(
+            expand_box("padding: inherit"),
+            sides("padding", ["inherit"; 4])
+        );
+
+        assert!(expand_box("margin: 1px 2px 3px 4px 5px").is_empty());
+        assert!(expand_box("padding: auto").is_empty());
+        assert!(expand_box("padding: -1px").is_empty());
+        assert!(expand_box("margin: 1px red").is_empty());
+        assert!(expand_box("margin: 1").is_empty());
+    }
+
+    #[test]
+    fn test_expand_box_shorthand_border() {
+        let border = |width: &str, style: &str, color: &str| {
+            longhands(BORDER_LONGHANDS, [width, style, color])
+        };
+
+        assert_eq!(
+            expand_box("border: 1px solid red"),
+            border("1px", "solid", "red")
+        );
+        assert_eq!(
+            expand_box("border: #FF0000 DASHED thick"),
+            border("thick", "dashed", "#FF0000")
+        );
+        assert_eq!(
+            expand_box("border: thin dotted Transparent"),
+            border("thin", "dotted", "transparent")
+        );
+        assert_eq!(
+            expand_box("border: solid"),
+            border("medium", "solid", "currentcolor")
+        );
+
+        assert!(expand_box("border: 1px 2px solid").is_empty());
+        assert!(expand_box("border: 10% solid").is_empty());
+        assert!(expand_box("border: solid red blue").is_empty());
+        assert!(expand_box("border: 1px solid #ggg").is_empty());
+        assert!(expand_box("border: 1px solid banana").is_empty());
+        assert!(expand_box("border: 1px solid auto").is_empty());
+        assert!(expand_box("outline: 1px solid red").is_empty());
+    }
+}
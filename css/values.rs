@@ -0,0 +1,222 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! CSS Values and Units Module.
+//!
+//! Voir <https://drafts.csswg.org/css-values/#lengths>
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Le contexte nécessaire à la résolution des longueurs relatives.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq)]
+pub struct LengthContext {
+    /// La valeur calculée de la propriété `font-size` de l'élément, en
+    /// pixels. Référence des unités `em`.
+    pub font_size: f32,
+    /// La valeur calculée de la propriété `font-size` de l'élément
+    /// racine, en pixels. Référence des unités `rem`.
+    pub root_font_size: f32,
+    /// La longueur, en pixels, à laquelle les pourcentages se réfèrent
+    /// (par exemple la largeur du bloc conteneur).
+    pub percentage_basis: f32,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Une longueur CSS : une dimension dont l'unité est une unité de
+/// longueur absolue, relative à la police, ou un pourcentage.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq)]
+pub enum Length {
+    /// Pixels : 1px = 1/96 de pouce.
+    Px(f32),
+    /// Taille de police de l'élément.
+    Em(f32),
+    /// Taille de police de l'élément racine.
+    Rem(f32),
+    /// Pourcentage de la longueur de référence.
+    Percent(f32),
+    /// Points : 1pt = 1/72 de pouce.
+    Pt(f32),
+    /// Picas : 1pc = 1/6 de pouce.
+    Pc(f32),
+    /// Pouces : 1in = 96px.
+    In(f32),
+    /// Centimètres : 1cm = 96px/2.54.
+    Cm(f32),
+    /// Millimètres : 1mm = 1/10 de centimètre.
+    Mm(f32),
+    /// Quarts de millimètre : 1Q = 1/40 de centimètre.
+    Q(f32),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LengthContext {
+    /// La taille de police par défaut des navigateurs.
+    pub const DEFAULT_FONT_SIZE: f32 = 16.0;
+}
+
+impl Length {
+    /// Crée une longueur à partir d'une valeur et d'une unité. L'unité
+    /// est insensible à la casse ASCII. Retourne `None` si l'unité n'est
+    /// pas une unité de longueur prise en charge.
+    pub fn from_dimension(value: f32, unit: &str) -> Option<Self> {
+        let length = match unit.to_ascii_lowercase().as_str() {
+            | "px" => Self::Px(value),
+            | "em" => Self::Em(value),
+            | "rem" => Self::Rem(value),
+            | "%" => Self::Percent(value),
+            | "pt" => Self::Pt(value),
+            | "pc" => Self::Pc(value),
+            | "in" => Self::In(value),
+            | "cm" => Self::Cm(value),
+            | "mm" => Self::Mm(value),
+            | "q" => Self::Q(value),
+            | _ => return None,
+        };
+        Some(length)
+    }
+
+    /// La longueur est-elle absolue, c'est-à-dire convertible en pixels
+    /// sans contexte ?
+    pub fn is_absolute(&self) -> bool {
+        self.to_absolute_px().is_some()
+    }
+
+    /// Convertit la longueur en pixels. Les unités `em`, `rem` et `%`
+    /// sont résolues à partir du contexte.
+    pub fn to_px(&self, context: LengthContext) -> f32 {
+        match *self {
+            | Self::Em(n) => n * context.font_size,
+            | Self::Rem(n) => n * context.root_font_size,
+            | Self::Percent(n) => n * context.percentage_basis / 100.0,
+            | _ => self.to_absolute_px().unwrap_or_default(),
+        }
+    }
+
+    /// Convertit une longueur absolue en pixels. Retourne `None` pour les
+    /// longueurs relatives.
+    pub fn to_absolute_px(&self) -> Option<f32> {
+        const PX_PER_IN: f32 = 96.0;
+        const PX_PER_CM: f32 = PX_PER_IN / 2.54;
+
+        let px = match *self {
+            | Self::Px(n) => n,
+            | Self::Pt(n) => n * PX_PER_IN / 72.0,
+            | Self::Pc(n) => n * PX_PER_IN / 6.0,
+            | Self::In(n) => n * PX_PER_IN,
+            | Self::Cm(n) => n * PX_PER_CM,
+            | Self::Mm(n) => n * PX_PER_CM / 10.0,
+            | Self::Q(n) => n * PX_PER_CM / 40.0,
+            | Self::Em(_) | Self::Rem(_) | Self::Percent(_) => {
+                return None
+            }
+        };
+        Some(px)
+    }
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LengthContext {
+    fn default() -> Self {
+        Self {
+            font_size: Self::DEFAULT_FONT_SIZE,
+            root_font_size: Self::DEFAULT_FONT_SIZE,
+            percentage_basis: 0.0,
+        }
+    }
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_px(length: Length, context: LengthContext, expected: f32) {
+        let px = length.to_px(context);
+        assert!(
+            (px - expected).abs() < 1e-3,
+            "{length:?} -> {px}px, attendu {expected}px"
+        );
+    }
+
+    #[test]
+    fn test_absolute_lengths() {
+        let context = LengthContext::default();
+        assert_px(Length::Px(12.0), context, 12.0);
+        assert_px(Length::In(1.0), context, 96.0);
+        assert_px(Length::Pt(72.0), context, 96.0);
+        assert_px(Length::Pc(6.0), context, 96.0);
+        assert_px(Length::Cm(2.54), context, 96.0);
+        assert_px(Length::Mm(25.4), context, 96.0);
+        assert_px(Length::Q(101.6), context, 96.0);
+
+        // Les longueurs absolues ne dépendent pas du contexte.
+        let other = LengthContext {
+            font_size: 40.0,
+            root_font_size: 8.0,
+            percentage_basis: 1000.0,
+        };
+        assert_px(Length::Px(12.0), other, 12.0);
+        assert_px(Length::Pt(12.0), other, 16.0);
+        assert!(Length::Px(1.0).is_absolute());
+    }
+
+    #[test]
+    fn test_relative_lengths() {
+        let context = LengthContext {
+            font_size: 20.0,
+            root_font_size: 10.0,
+            percentage_basis: 300.0,
+        };
+        assert_px(Length::Em(1.5), context, 30.0);
+        assert_px(Length::Rem(1.5), context, 15.0);
+        assert_px(Length::Percent(50.0), context, 150.0);
+
+        let context = LengthContext {
+            percentage_basis: 800.0,
+            ..Default::default()
+        };
+        assert_px(Length::Em(2.0), context, 32.0);
+        assert_px(Length::Rem(0.5), context, 8.0);
+        assert_px(Length::Percent(25.0), context, 200.0);
+
+        assert!(!Length::Em(1.0).is_absolute());
+        assert_eq!(Length::Percent(10.0).to_absolute_px(), None);
+    }
+
+    #[test]
+    fn test_from_dimension() {
+        assert_eq!(
+            Length::from_dimension(3.0, "PX"),
+            Some(Length::Px(3.0))
+        );
+        assert_eq!(
+            Length::from_dimension(1.0, "rem"),
+            Some(Length::Rem(1.0))
+        );
+        assert_eq!(
+            Length::from_dimension(50.0, "%"),
+            Some(Length::Percent(50.0))
+        );
+        assert_eq!(Length::from_dimension(4.0, "Q"), Some(Length::Q(4.0)));
+        assert_eq!(Length::from_dimension(1.0, "deg"), None);
+    }
+}
@@ -68,7 +68,8 @@ impl fmt::Display for DOMException {
             "{}",
             match self {
                 #[allow(deprecated)]
-                | DOMException::IndexSizeError => "",
+                | DOMException::IndexSizeError =>
+                    "The index is not in the allowed range.",
                 | DOMException::HierarchyRequestError =>
                     "The operation would yield an incorrect node tree.",
                 | DOMException::WrongDocumentError =>
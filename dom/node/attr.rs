@@ -2,15 +2,128 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use infra::primitive::string::DOMString;
+use std::cell::RefCell;
+
+use html_elements::ElementAttribute;
+use infra::{
+    namespace::Namespace,
+    primitive::string::DOMString,
+    structure::tree::{TreeNode, TreeNodeWeak},
+};
+
+use super::Node;
 
 // --------- //
 // Structure //
 // --------- //
 
+/// Les nœuds Attr ont un espace de noms (nul ou une chaîne non vide), un
+/// préfixe d'espace de noms (nul ou une chaîne non vide), un nom local
+/// (une chaîne non vide) et une valeur (une chaîne).
+///
+/// Un nœud Attr a également un élément (nul ou un élément), son élément
+/// propriétaire.
 #[derive(Debug)]
-#[derive(PartialEq, Eq)]
 pub struct Attr {
-    name: DOMString,
+    namespace_uri: Option<Namespace>,
+    prefix: Option<DOMString>,
+    local_name: DOMString,
     value: DOMString,
+    owner_element: Option<TreeNodeWeak<Node>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Attr {
+    pub fn new(
+        namespace_uri: Option<Namespace>,
+        prefix: Option<&str>,
+        local_name: &str,
+        value: &str,
+    ) -> Self {
+        Self {
+            namespace_uri,
+            prefix: prefix
+                .filter(|prefix| !prefix.is_empty())
+                .map(|prefix| RefCell::new(prefix.to_owned())),
+            local_name: RefCell::new(local_name.to_owned()),
+            value: RefCell::new(value.to_owned()),
+            owner_element: Default::default(),
+        }
+    }
+
+    /// Définit l'élément propriétaire de l'attribut.
+    pub fn with_owner_element(mut self, element: &TreeNode<Node>) -> Self {
+        self.owner_element.replace(TreeNodeWeak::from(element));
+        self
+    }
 }
+
+impl Attr {
+    pub fn namespace_uri(&self) -> Option<Namespace> {
+        self.namespace_uri
+    }
+
+    pub fn prefix(&self) -> Option<String> {
+        self.prefix
+            .as_ref()
+            .map(|prefix| prefix.borrow().to_owned())
+    }
+
+    pub fn local_name(&self) -> String {
+        self.local_name.borrow().to_owned()
+    }
+
+    /// Le nom qualifié d'un attribut est son nom local si son préfixe
+    /// d'espace de noms est nul, et sinon son préfixe d'espace de noms,
+    /// suivi de ":", suivi de son nom local.
+    pub fn name(&self) -> String {
+        match self.prefix() {
+            | Some(prefix) => format!("{prefix}:{}", self.local_name()),
+            | None => self.local_name(),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        self.value.borrow().to_owned()
+    }
+
+    /// L'élément propriétaire de l'attribut, s'il en a un et qu'il existe
+    /// toujours.
+    pub fn owner_element(&self) -> Option<TreeNode<Node>> {
+        self.owner_element
+            .as_deref()
+            .and_then(|node_weak| node_weak.upgrade())
+            .map(TreeNode::from)
+    }
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<&ElementAttribute> for Attr {
+    fn from(attr: &ElementAttribute) -> Self {
+        Self::new(
+            attr.namespace,
+            attr.prefix.as_deref(),
+            &attr.local_name,
+            &attr.value,
+        )
+    }
+}
+
+/// Un nœud Attr A est égal à un nœud Attr B si leur espace de noms, leur
+/// nom local et leur valeur sont identiques. L'élément propriétaire n'est
+/// pas comparé.
+impl PartialEq for Attr {
+    fn eq(&self, other: &Self) -> bool {
+        self.namespace_uri == other.namespace_uri
+            && self.local_name == other.local_name
+            && self.value == other.value
+    }
+}
+
+impl Eq for Attr {}
@@ -5,6 +5,7 @@
 use infra::primitive::string::DOMString;
 
 use super::{Comment, Text};
+use crate::exception::DOMException;
 
 // --------- //
 // Structure //
@@ -30,13 +31,175 @@ pub enum CharacterDataInner {
 
 impl CharacterData {
     pub(crate) fn new(inner: CharacterDataInner) -> Self {
+        let data = match &inner {
+            | CharacterDataInner::Text(text) => text.data(),
+            | CharacterDataInner::Comment(comment) => comment.data(),
+        };
         Self {
+            data: DOMString::new(data.to_owned()),
             inner,
-            data: Default::default(),
         }
     }
 
+    pub fn data(&self) -> String {
+        self.data.borrow().to_owned()
+    }
+
     pub(crate) fn set_data(&self, data: &str) {
         *self.data.borrow_mut() = data.to_owned();
     }
 }
+
+impl CharacterData {
+    /// La longueur des données, en unités de code UTF-16.
+    pub fn length(&self) -> usize {
+        self.data.borrow().encode_utf16().count()
+    }
+
+    /// Retourne `count` unités de code des données à partir de `offset`.
+    ///
+    /// 1. Soit length la longueur du nœud.
+    /// 2. Si offset est supérieur à length, une DOMException
+    ///    "IndexSizeError" est levée.
+    /// 3. Si offset plus count est supérieur à length, retourner une
+    ///    chaîne dont la valeur est celle des unités de code à partir de
+    ///    l'unité offset jusqu'à la fin des données du nœud.
+    /// 4. Retourner une chaîne dont la valeur est celle des unités de code
+    ///    à partir de l'unité offset jusqu'à l'unité offset+count.
+    pub fn substring_data(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Result<String, DOMException> {
+        let code_units: Vec<u16> =
+            self.data.borrow().encode_utf16().collect();
+        let length = code_units.len();
+        if offset > length {
+            #[allow(deprecated)]
+            return Err(DOMException::IndexSizeError);
+        }
+        let end = length.min(offset.saturating_add(count));
+        Ok(String::from_utf16_lossy(&code_units[offset..end]))
+    }
+
+    /// Ajoute `data` à la fin des données.
+    ///
+    /// NOTE: équivaut à remplacer 0 unité de code à la fin des
+    /// données, sans avoir à les convertir en UTF-16.
+    pub fn append_data(&self, data: &str) {
+        self.data.borrow_mut().push_str(data);
+    }
+
+    /// Insère `data` à la position `offset`.
+    pub fn insert_data(
+        &self,
+        offset: usize,
+        data: &str,
+    ) -> Result<(), DOMException> {
+        self.replace_data(offset, 0, data)
+    }
+
+    /// Supprime `count` unités de code à partir de `offset`.
+    pub fn delete_data(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Result<(), DOMException> {
+        self.replace_data(offset, count, "")
+    }
+
+    /// Remplace `count` unités de code à partir de `offset` par `data`.
+    ///
+    /// 1. Soit length la longueur du nœud.
+    /// 2. Si offset est supérieur à length, une DOMException
+    ///    "IndexSizeError" est levée.
+    /// 3. Si offset plus count est supérieur à length, count vaut length
+    ///    moins offset.
+    /// 4. Insérer data dans les données du nœud après offset unités de
+    ///    code.
+    /// 5. Soit delete offset la valeur de offset plus la longueur de data.
+    /// 6. À partir de delete offset, supprimer count unités de code des
+    ///    données du nœud.
+    ///
+    /// NOTE: une paire de substitution coupée par l'opération est
+    /// remplacée par U+FFFD REPLACEMENT CHARACTER.
+    pub fn replace_data(
+        &self,
+        offset: usize,
+        count: usize,
+        data: &str,
+    ) -> Result<(), DOMException> {
+        let mut code_units: Vec<u16> =
+            self.data.borrow().encode_utf16().collect();
+        let length = code_units.len();
+        if offset > length {
+            #[allow(deprecated)]
+            return Err(DOMException::IndexSizeError);
+        }
+        let count = count.min(length - offset);
+        code_units.splice(offset..offset + count, data.encode_utf16());
+        *self.data.borrow_mut() = String::from_utf16_lossy(&code_units);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{exception::DOMException, node::DocumentNode};
+
+    #[test]
+    fn test_character_data_operations() {
+        let document = DocumentNode::new();
+        let text = document.create_text_node("Hello");
+        let data = text.character_data_ref();
+        assert_eq!(data.length(), 5);
+
+        data.append_data(" World");
+        assert_eq!(data.data(), "Hello World");
+
+        assert!(data.insert_data(5, ",").is_ok());
+        assert_eq!(data.data(), "Hello, World");
+        assert!(data.insert_data(12, "!").is_ok());
+        assert_eq!(data.data(), "Hello, World!");
+
+        assert!(data.delete_data(5, 1).is_ok());
+        assert_eq!(data.data(), "Hello World!");
+        // count est tronqué à la fin des données.
+        assert!(data.delete_data(11, 100).is_ok());
+        assert_eq!(data.data(), "Hello World");
+
+        assert!(data.replace_data(6, 5, "DOM").is_ok());
+        assert_eq!(data.data(), "Hello DOM");
+        assert!(data.replace_data(0, 0, ">").is_ok());
+        assert_eq!(data.data(), ">Hello DOM");
+
+        assert_eq!(
+            data.substring_data(1, 5).ok().as_deref(),
+            Some("Hello")
+        );
+        assert_eq!(
+            data.substring_data(7, 100).ok().as_deref(),
+            Some("DOM")
+        );
+        assert_eq!(data.substring_data(10, 1).ok().as_deref(), Some(""));
+
+        // Un offset supérieur à la longueur lève une exception
+        // "IndexSizeError", et les données ne sont pas modifiées.
+        #[allow(deprecated)]
+        let is_index_size_error =
+            |err| matches!(err, Err(DOMException::IndexSizeError));
+        assert!(is_index_size_error(data.insert_data(11, "x")));
+        assert!(is_index_size_error(data.delete_data(11, 0)));
+        assert!(is_index_size_error(data.replace_data(11, 1, "x")));
+        assert!(is_index_size_error(data.substring_data(11, 1).map(drop)));
+        assert_eq!(data.data(), ">Hello DOM");
+
+        // Les offsets sont exprimés en unités de code UTF-16.
+        let comment = document.create_comment("a😀b");
+        let data = comment.character_data_ref();
+        assert_eq!(data.length(), 4);
+        assert_eq!(data.substring_data(1, 2).ok().as_deref(), Some("😀"));
+        assert!(data.delete_data(1, 2).is_ok());
+        assert_eq!(data.data(), "ab");
+    }
+}
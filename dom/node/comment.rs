@@ -57,6 +57,10 @@ impl Comment {
     fn new(data: String) -> Self {
         Self { data }
     }
+
+    pub(super) fn data(&self) -> &str {
+        &self.data
+    }
 }
 
 // -------------- //
@@ -8,13 +8,15 @@ use std::{
     cell::RefCell,
 };
 
-use html_elements::{tag_names, HTMLElementVariant};
+use html_elements::{
+    interface::IsOneOfTagsInterface, tag_names, HTMLElementVariant,
+};
 use infra::{
     namespace::Namespace, primitive::string::DOMString,
     structure::tree::TreeNode,
 };
 
-use super::comment::CommentNode;
+use super::{comment::CommentNode, text::TextNode};
 use crate::{
     exception::DOMException,
     node::{DocumentType, Node, NodeData, NodeType},
@@ -44,12 +46,20 @@ pub struct Document {
 // Énumération //
 // ----------- //
 
+/// Mode d'un document, déterminé par son doctype lors de l'analyse.
+///
+/// Le mode "quirks" reproduit les comportements historiques des
+/// navigateurs, à la fois pour l'analyse HTML (par exemple, un élément
+/// `<table>` ne ferme pas un élément `<p>` ouvert) et pour le rendu CSS.
 #[derive(Debug)]
-#[derive(Clone)]
+#[derive(Copy, Clone)]
 #[derive(PartialEq, Eq)]
 pub enum QuirksMode {
+    /// Mode "no-quirks" : le mode par défaut d'un document.
     No,
+    /// Mode "quirks".
     Yes,
+    /// Mode "limited-quirks".
     Limited,
 }
 
@@ -67,7 +77,7 @@ impl Document {
     pub fn new() -> Self {
         Self {
             doctype: Default::default(),
-            quirks_mode: RefCell::new(QuirksMode::Yes),
+            quirks_mode: RefCell::new(QuirksMode::No),
         }
     }
 
@@ -99,25 +109,68 @@ impl Document {
         // 2) S'il s'agit d'un document HTML, définir localName en
         // minuscules ASCII.
 
-        let element = html_elements::Element::new(
-            DOMString::new(local_name.as_ref().to_owned()),
+        // 6) Renvoie le résultat de la création d'un élément avec this,
+        // localName, namespace, null, is, et avec l'indicateur d'éléments
+        // personnalisés synchrones activé.
+        Ok(Self::create_an_element(
+            local_name.as_ref(),
+            namespace.unwrap_or(Namespace::HTML),
+            None,
             is,
+        ))
+    }
+
+    /// Crée un élément à partir d'un espace de noms et d'un nom qualifié
+    /// (`prefix:localName`).
+    ///
+    /// NOTE(phisyx): un élément n'a pas (encore) d'espace de noms nul :
+    /// l'espace de noms HTML est utilisé à la place.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#dom-document-createelementns>
+    pub fn create_element_ns(
+        namespace: Option<Namespace>,
+        qualified_name: impl AsRef<str>,
+    ) -> Result<TreeNode<Node>, DOMException> {
+        // 1) Laisser namespace, prefix et localName être le résultat de
+        // la validation et de l'extraction de namespace et
+        // qualifiedName.
+        let (prefix, local_name) =
+            validate_and_extract(namespace, qualified_name.as_ref())?;
+
+        // 4) Renvoie le résultat de la création d'un élément avec this,
+        // localName, namespace, prefix, is, et avec l'indicateur
+        // d'éléments personnalisés synchrones activé.
+        Ok(Self::create_an_element(
+            &local_name,
             namespace.unwrap_or(Namespace::HTML),
+            prefix,
+            None,
+        ))
+    }
+
+    /// Voir <https://dom.spec.whatwg.org/#concept-create-element>
+    fn create_an_element(
+        local_name: &str,
+        namespace: Namespace,
+        prefix: Option<String>,
+        is: Option<String>,
+    ) -> TreeNode<Node> {
+        let element = html_elements::Element::new(
+            DOMString::new(local_name.to_owned()),
+            is,
+            namespace,
         );
+        element.prefix.replace(prefix.map(DOMString::new));
         let html_element = html_elements::HTMLElement::new(element);
 
-        // 6) Renvoie le résultat de la création d'un élément avec this,
-        // localName, namespace, null, is, et avec l'indicateur d'éléments
-        // personnalisés synchrones activé.
-
-        Ok(TreeNode::new(
+        TreeNode::new(
             Node::builder()
                 .set_data(NodeData::Element(HTMLElementVariant::from(
                     html_element,
                 )))
                 .set_type(NodeType::ELEMENT_NODE)
                 .build(),
-        ))
+        )
     }
 }
 
@@ -126,6 +179,12 @@ impl Document {
         self.doctype.borrow().clone()
     }
 
+    /// Le mode du document. Sauf indication contraire, le mode d'un
+    /// document est "no-quirks".
+    pub fn quirks_mode(&self) -> QuirksMode {
+        *self.quirks_mode.borrow()
+    }
+
     pub fn isin_quirks_mode(&self) -> bool {
         matches!(*self.quirks_mode.borrow(), QuirksMode::Yes)
     }
@@ -149,6 +208,10 @@ impl DocumentNode {
         Self::default()
     }
 
+    pub(super) fn from_tree(tree: TreeNode<Node>) -> Self {
+        Self { tree }
+    }
+
     pub fn get(&self) -> &Document {
         self.document_ref()
     }
@@ -157,10 +220,186 @@ impl DocumentNode {
         self.document_ref().borrow_mut()
     }
 
+    /// Crée un nouveau nœud [Text](super::Text) dont les données sont
+    /// `data` et dont le document de nœud est ce document.
+    pub fn create_text_node(
+        &self,
+        data: impl AsRef<str>,
+    ) -> TreeNode<Node> {
+        TextNode::new(self, data.as_ref().to_owned()).to_owned()
+    }
+
+    /// Crée un nouveau nœud [Comment](super::Comment) dont les données
+    /// sont `data` et dont le document de nœud est ce document.
+    pub fn create_comment(&self, data: impl AsRef<str>) -> TreeNode<Node> {
+        CommentNode::new(self, data.as_ref().to_owned()).to_owned()
+    }
+
+    /// L'élément du document est son premier enfant de type élément,
+    /// le cas échéant (`<html>` pour un document HTML).
+    pub fn document_element(&self) -> Option<TreeNode<Node>> {
+        first_child_element(self, |_| true)
+    }
+
+    /// L'élément head est le premier enfant `<head>` de l'élément
+    /// `<html>` du document, le cas échéant.
+    pub fn head(&self) -> Option<TreeNode<Node>> {
+        let html = self.html_element()?;
+        first_child_element(&html, |child| {
+            child.isin_html_namespace()
+                && tag_names::head == child.element_ref().local_name()
+        })
+    }
+
+    /// L'élément body est le premier enfant `<body>` ou `<frameset>` de
+    /// l'élément `<html>` du document, le cas échéant.
+    #[allow(deprecated)]
+    pub fn body(&self) -> Option<TreeNode<Node>> {
+        let html = self.html_element()?;
+        first_child_element(&html, |child| {
+            child.isin_html_namespace()
+                && [tag_names::body, tag_names::frameset]
+                    .into_iter()
+                    .any(|name| name == child.element_ref().local_name())
+        })
+    }
+
+    /// L'élément du document, s'il s'agit d'un élément `<html>` de
+    /// l'espace de noms HTML.
+    fn html_element(&self) -> Option<TreeNode<Node>> {
+        self.document_element().filter(|element| {
+            element.isin_html_namespace()
+                && tag_names::html == element.element_ref().local_name()
+        })
+    }
+
     pub fn insert_comment(&self, text: String) {
         let comment_node = CommentNode::new(self, text).to_owned();
         self.append_child(comment_node);
     }
+
+    /// Extrait le texte visible du document (approximation de
+    /// `innerText`) : le contenu des éléments non rendus (head, script,
+    /// style, ...) est ignoré, et les éléments de bloc (p, div, li, br)
+    /// sont séparés par des sauts de ligne.
+    pub fn visible_text(&self) -> String {
+        let mut text = String::new();
+        collect_visible_text(self, &mut text);
+        text.trim_matches('\n').to_owned()
+    }
+}
+
+fn first_child_element(
+    node: &TreeNode<Node>,
+    predicate: impl Fn(&TreeNode<Node>) -> bool,
+) -> Option<TreeNode<Node>> {
+    let mut maybe_child = node.get_first_child();
+    while let Some(child) = maybe_child {
+        if child.is_element() && predicate(&child) {
+            return Some(child);
+        }
+        maybe_child = child.next_sibling();
+    }
+    None
+}
+
+/// Valide le nom qualifié et en extrait le préfixe et le nom local.
+///
+/// Voir <https://dom.spec.whatwg.org/#validate-and-extract>
+fn validate_and_extract(
+    namespace: Option<Namespace>,
+    qualified_name: &str,
+) -> Result<(Option<String>, String), DOMException> {
+    // 2) Valider qualifiedName : le préfixe et le nom local doivent
+    // correspondre à la production de Name, sans caractère ":".
+    let is_ncname =
+        |name: &str| !name.contains(':') && tag_names::is_valid_name(name);
+
+    // 3) Si qualifiedName contient un U+003A (:), diviser la chaîne sur
+    // ce caractère et définir prefix à la partie avant et localName à la
+    // partie après.
+    let (prefix, local_name) = match qualified_name.split_once(':') {
+        | Some((prefix, local_name)) => (Some(prefix), local_name),
+        | None => (None, qualified_name),
+    };
+
+    if prefix.is_some_and(|prefix| !is_ncname(prefix))
+        || !is_ncname(local_name)
+    {
+        return Err(DOMException::InvalidCharacterError);
+    }
+
+    // 4) Si prefix n'est pas nul et que namespace est nul, lancer une
+    // DOMException "NamespaceError".
+    if prefix.is_some() && namespace.is_none() {
+        return Err(DOMException::NamespaceError);
+    }
+
+    // 5) Si prefix est "xml" et que namespace n'est pas l'espace de noms
+    // XML, lancer une DOMException "NamespaceError".
+    if prefix == Some("xml") && namespace != Some(Namespace::XML) {
+        return Err(DOMException::NamespaceError);
+    }
+
+    // 6) Si qualifiedName ou prefix est "xmlns" et que namespace n'est
+    // pas l'espace de noms XMLNS, lancer une DOMException
+    // "NamespaceError".
+    let is_xmlns = qualified_name == "xmlns" || prefix == Some("xmlns");
+    if is_xmlns && namespace != Some(Namespace::XMLNS) {
+        return Err(DOMException::NamespaceError);
+    }
+
+    // 7) Si namespace est l'espace de noms XMLNS et que ni qualifiedName
+    // ni prefix n'est "xmlns", lancer une DOMException "NamespaceError".
+    if !is_xmlns && namespace == Some(Namespace::XMLNS) {
+        return Err(DOMException::NamespaceError);
+    }
+
+    // 8) Renvoyer namespace, prefix et localName.
+    Ok((prefix.map(ToOwned::to_owned), local_name.to_owned()))
+}
+
+fn collect_visible_text(node: &TreeNode<Node>, text: &mut String) {
+    let push_line_break = |text: &mut String| {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+    };
+
+    let mut maybe_child = node.get_first_child();
+    while let Some(child) = maybe_child {
+        if child.is_text() {
+            text.push_str(&child.character_data_ref().data());
+        } else if child.is_element() {
+            let maybe_tag_name =
+                child.element_ref().local_name().parse::<tag_names>();
+
+            match maybe_tag_name {
+                | Ok(tag_name)
+                    if tag_name.is_one_of([
+                        tag_names::head,
+                        tag_names::script,
+                        tag_names::style,
+                        tag_names::template,
+                    ]) => {}
+                | Ok(tag_names::br) => text.push('\n'),
+                | Ok(tag_name)
+                    if tag_name.is_one_of([
+                        tag_names::p,
+                        tag_names::div,
+                        tag_names::li,
+                    ]) =>
+                {
+                    push_line_break(text);
+                    collect_visible_text(&child, text);
+                    push_line_break(text);
+                }
+                | _ => collect_visible_text(&child, text),
+            }
+        }
+
+        maybe_child = child.next_sibling();
+    }
 }
 
 // -------------- //
@@ -187,3 +426,72 @@ impl ops::Deref for DocumentNode {
         self.tree.borrow()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_element_ns() {
+        let circle =
+            Document::create_element_ns(Some(Namespace::SVG), "s:circle")
+                .unwrap();
+        let element = circle.element_ref();
+        assert_eq!(element.namespace(), Some(Namespace::SVG));
+        assert_eq!(element.prefix().as_deref(), Some("s"));
+        assert_eq!(element.local_name(), "circle");
+        assert_eq!(element.qualified_name(), "s:circle");
+
+        let math =
+            Document::create_element_ns(Some(Namespace::MathML), "math")
+                .unwrap();
+        assert_eq!(
+            math.element_ref().namespace(),
+            Some(Namespace::MathML)
+        );
+        assert_eq!(math.element_ref().prefix(), None);
+        assert_eq!(math.element_ref().qualified_name(), "math");
+
+        for (namespace, qualified_name) in [
+            (Some(Namespace::XML), "xml:lang"),
+            (Some(Namespace::XMLNS), "xmlns"),
+            (Some(Namespace::XMLNS), "xmlns:svg"),
+            (None, "div"),
+        ] {
+            assert!(
+                Document::create_element_ns(namespace, qualified_name)
+                    .is_ok(),
+                "{qualified_name}"
+            );
+        }
+
+        for qualified_name in ["", "1a", ":a", "a:", "a:b:c", "a b"] {
+            assert!(
+                matches!(
+                    Document::create_element_ns(
+                        Some(Namespace::SVG),
+                        qualified_name
+                    ),
+                    Err(DOMException::InvalidCharacterError)
+                ),
+                "{qualified_name}"
+            );
+        }
+
+        for (namespace, qualified_name) in [
+            (None, "s:circle"),
+            (Some(Namespace::SVG), "xml:lang"),
+            (Some(Namespace::SVG), "xmlns"),
+            (Some(Namespace::SVG), "xmlns:svg"),
+            (Some(Namespace::XMLNS), "svg"),
+        ] {
+            assert!(
+                matches!(
+                    Document::create_element_ns(namespace, qualified_name),
+                    Err(DOMException::NamespaceError)
+                ),
+                "{qualified_name}"
+            );
+        }
+    }
+}
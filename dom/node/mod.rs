@@ -17,6 +17,9 @@ mod shadow_root;
 /// 4.9. Interface Element
 mod element;
 
+/// 4.9.1. Interface NamedNodeMap
+mod named_node_map;
+
 /// 4.9.2. Interface Attr
 mod attr;
 
@@ -29,12 +32,16 @@ mod text;
 /// 4.14. Interface Comment
 mod comment;
 
-use std::cell::RefCell;
+use std::{cell::RefCell, rc::Rc};
 
 use html_elements::{
-    Element, HTMLElement, HTMLElementVariant, HTMLScriptElement,
+    Element, HTMLElement, HTMLElementVariant, HTMLImageElement,
+    HTMLLinkElement, HTMLMetaElement, HTMLScriptElement, HTMLStyleElement,
+};
+use infra::{
+    namespace::Namespace,
+    structure::tree::{TreeElementInterface, TreeNode, TreeNodeWeak},
 };
-use infra::structure::tree::{TreeNode, TreeNodeWeak};
 
 pub use self::{
     attr::Attr,
@@ -43,9 +50,11 @@ pub use self::{
     document::{CreateElementOptions, Document, DocumentNode, QuirksMode},
     document_fragment::{DocumentFragment, DocumentFragmentNode},
     document_type::DocumentType,
+    named_node_map::NamedNodeMap,
     shadow_root::ShadowRoot,
     text::{Text, TextNode},
 };
+use crate::exception::DOMException;
 
 // --------- //
 // Structure //
@@ -65,6 +74,97 @@ pub(super) struct NodeBuilder {
     node_type: NodeType,
 }
 
+// --------- //
+// Interface //
+// --------- //
+
+/// Propriétés d'un noeud qui dépendent de sa position dans l'arbre des
+/// noeuds.
+pub trait NodeTreeInterface {
+    /// Un noeud est connecté si sa racine est un document.
+    ///
+    /// NOTE(dom): la spécification utilise la racine incluant les
+    /// racines fantômes, les arbres fantômes ne sont pas encore pris en
+    /// charge.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#connected>
+    fn is_connected(&self) -> bool;
+}
+
+/// Accès aux attributs d'un élément sous forme de nœuds [Attr], dont
+/// l'élément propriétaire est l'élément.
+pub trait ElementAttributesInterface {
+    /// La liste des attributs de l'élément.
+    ///
+    /// NOTE(phisyx): correspond à l'attribut `attributes` de l'interface
+    /// Element, [Node::attributes] retourne quant à lui des attributs
+    /// sans élément propriétaire.
+    fn attributes_map(&self) -> NamedNodeMap;
+
+    /// L'attribut de l'élément dont le nom qualifié est `name`.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#dom-element-getattributenode>
+    fn get_attribute_node(&self, name: &str) -> Option<Attr>;
+
+    /// L'attribut de l'élément dont l'espace de noms est `namespace` et
+    /// le nom local est `local_name`.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#dom-element-getattributenodens>
+    fn get_attribute_node_ns(
+        &self,
+        namespace: Option<Namespace>,
+        local_name: &str,
+    ) -> Option<Attr>;
+}
+
+/// Contenu textuel des noeuds, notamment des éléments dont le contenu est
+/// analysé comme du texte brut (`<style>`, `<script>`).
+pub trait NodeTextContentInterface {
+    /// Le contenu textuel enfant du noeud : la concaténation des données
+    /// des noeuds Text enfants, dans l'ordre de l'arbre.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#concept-child-text-content>
+    fn child_text_content(&self) -> String;
+
+    /// Le contenu textuel de l'élément style, c'est-à-dire la feuille de
+    /// style qu'il définit. [None] s'il ne s'agit pas d'un élément style.
+    fn style_text_content(&self) -> Option<String>;
+
+    /// Le texte de l'élément script (`HTMLScriptElement.text`). [None]
+    /// s'il ne s'agit pas d'un élément script.
+    ///
+    /// Voir <https://html.spec.whatwg.org/multipage/scripting.html#dom-script-text>
+    fn script_text(&self) -> Option<String>;
+}
+
+/// Algorithmes de mutation de l'arbre des noeuds. Contrairement aux
+/// opérations de [TreeNode], qui paniquent lorsque l'arbre obtenu serait
+/// incorrect, ces opérations retournent une [DOMException].
+///
+/// Voir <https://dom.spec.whatwg.org/#mutation-algorithms>
+pub trait NodeMutationInterface {
+    /// Insère le noeud avant l'enfant (ou à la fin lorsque l'enfant est
+    /// null). Le noeud est d'abord retiré de son ancien parent.
+    fn try_insert_before(
+        &self,
+        node: TreeNode<Node>,
+        child: Option<&TreeNode<Node>>,
+    ) -> Result<(), DOMException>;
+
+    /// Ajoute le noeud à la fin des enfants du parent. Le noeud est
+    /// d'abord retiré de son ancien parent.
+    fn try_append_child(
+        &self,
+        node: TreeNode<Node>,
+    ) -> Result<(), DOMException>;
+
+    /// Retire l'enfant du parent et le retourne.
+    fn remove_child(
+        &self,
+        child: &TreeNode<Node>,
+    ) -> Result<TreeNode<Node>, DOMException>;
+}
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -142,6 +242,17 @@ impl Node {
         self.node_type == NodeType::DOCUMENT_NODE
     }
 
+    /// Le noeud courant est un document de type
+    /// [NodeType::DOCUMENT_FRAGMENT_NODE].
+    pub fn is_document_fragment(&self) -> bool {
+        self.node_type == NodeType::DOCUMENT_FRAGMENT_NODE
+    }
+
+    /// Le noeud courant est un document de type [NodeType::ELEMENT_NODE].
+    pub fn is_element(&self) -> bool {
+        self.node_type == NodeType::ELEMENT_NODE
+    }
+
     /// Le noeud courant est un document de type [NodeType::TEXT_NODE].
     pub fn is_text(&self) -> bool {
         self.node_type == NodeType::TEXT_NODE
@@ -179,6 +290,37 @@ impl Node {
         }
     }
 
+    /// Les attributs de l'élément courant, dans leur ordre d'ajout.
+    pub fn attributes(&self) -> Vec<Attr> {
+        self.element_ref()
+            .attributes
+            .borrow()
+            .iter()
+            .map(Attr::from)
+            .collect()
+    }
+
+    /// Retourne la donnée du noeud, qui est une donnée de caractères
+    /// (texte ou commentaire).
+    // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
+    // erreurs.
+    pub fn character_data_ref(&self) -> &CharacterData {
+        match self.node_data.as_ref() {
+            | Some(NodeData::CharacterData(cd)) => cd,
+            | _ => panic!("Donnée de caractères attendue."),
+        }
+    }
+
+    /// Retourne la donnée du noeud, qui est le doctype courant.
+    // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
+    // erreurs.
+    pub fn doctype_ref(&self) -> &DocumentType {
+        match self.node_data.as_ref() {
+            | Some(NodeData::DocumentType(ref doctype)) => doctype,
+            | _ => panic!("Doctype attendu."),
+        }
+    }
+
     /// Retourne la donnée du noeud, qui est le document courant.
     // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
     // erreurs.
@@ -189,6 +331,46 @@ impl Node {
         }
     }
 
+    /// Retourne la donnée du noeud, qui est l'élément img.
+    // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
+    // erreurs.
+    pub fn img_ref(&self) -> &HTMLImageElement {
+        match self.node_data.as_ref() {
+            | Some(NodeData::Element(element)) => element.img(),
+            | _ => panic!("Élément img attendu."),
+        }
+    }
+
+    /// Retourne la donnée du noeud, qui est l'élément link.
+    // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
+    // erreurs.
+    pub fn link_ref(&self) -> &HTMLLinkElement {
+        match self.node_data.as_ref() {
+            | Some(NodeData::Element(element)) => element.link(),
+            | _ => panic!("Élément link attendu."),
+        }
+    }
+
+    /// Retourne la donnée du noeud, qui est l'élément meta.
+    // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
+    // erreurs.
+    pub fn meta_ref(&self) -> &HTMLMetaElement {
+        match self.node_data.as_ref() {
+            | Some(NodeData::Element(element)) => element.meta(),
+            | _ => panic!("Élément meta attendu."),
+        }
+    }
+
+    /// Retourne la donnée du noeud, qui est l'élément style.
+    // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
+    // erreurs.
+    pub fn style_ref(&self) -> &HTMLStyleElement {
+        match self.node_data.as_ref() {
+            | Some(NodeData::Element(element)) => element.style(),
+            | _ => panic!("Élément style attendu."),
+        }
+    }
+
     /// Retourne la donnée du noeud, qui est l'élément script.
     // NOTE(phisyx): au lieux de panic comme un demeuré: mieux gérer les
     // erreurs.
@@ -210,6 +392,19 @@ impl Node {
         };
     }
 
+    /// Le document de nœud du nœud, s'il en a un et qu'il existe
+    /// toujours.
+    pub fn owner_document(&self) -> Option<DocumentNode> {
+        self.owner_document
+            .borrow()
+            .as_deref()
+            .and_then(|node_weak| {
+                node_weak.upgrade().map(|node_ref| {
+                    DocumentNode::from_tree(node_ref.into())
+                })
+            })
+    }
+
     pub fn set_document(&self, document: &TreeNode<Node>) {
         let document_weak: TreeNodeWeak<Node> =
             TreeNodeWeak::from(document);
@@ -248,10 +443,279 @@ impl NodeBuilder {
     }
 }
 
+// -------- //
+// Fonction //
+// -------- //
+
+/// Voir <https://dom.spec.whatwg.org/#concept-node-ensure-pre-insertion-validity>
+fn ensure_pre_insertion_validity(
+    parent: &TreeNode<Node>,
+    node: &TreeNode<Node>,
+    child: Option<&TreeNode<Node>>,
+) -> Result<(), DOMException> {
+    // 1. Si le parent n'est pas un noeud Document, DocumentFragment ou
+    // Element, lancer une DOMException "HierarchyRequestError".
+    if !(parent.is_document()
+        || parent.is_document_fragment()
+        || parent.is_element())
+    {
+        return Err(DOMException::HierarchyRequestError);
+    }
+
+    // 2. Si le noeud est un ancêtre inclusif du parent, lancer une
+    // DOMException "HierarchyRequestError".
+    if node.is_inclusive_ancestor_of(parent) {
+        return Err(DOMException::HierarchyRequestError);
+    }
+
+    // 3. Si l'enfant n'est pas null et que son parent n'est pas le
+    // parent, lancer une DOMException "NotFoundError".
+    if let Some(child) = child {
+        match child.parent_node() {
+            | Some(child_parent) if Rc::ptr_eq(parent, &child_parent) => {}
+            | _ => return Err(DOMException::NotFoundError),
+        }
+    }
+
+    // 4. Si le noeud n'est pas un noeud DocumentFragment, DocumentType,
+    // Element ou CharacterData, lancer une DOMException
+    // "HierarchyRequestError".
+    // 5. Si le noeud est un noeud Text et que le parent est un document,
+    // ou si le noeud est un doctype et que le parent n'est pas un
+    // document, lancer une DOMException "HierarchyRequestError".
+    if node.is_document()
+        || node.is_text() && parent.is_document()
+        || node.is_doctype() && !parent.is_document()
+    {
+        return Err(DOMException::HierarchyRequestError);
+    }
+
+    // 6. Si le parent est un document, et que l'une des conditions
+    // suivantes, selon le noeud, est vraie, lancer une DOMException
+    // "HierarchyRequestError".
+    if parent.is_document() {
+        let parent_has_element_child = has_child(parent, Node::is_element);
+        let child_is_doctype = child.is_some_and(|c| c.is_doctype());
+        let doctype_is_following_child = child.is_some_and(|child| {
+            let mut maybe_sibling = child.next_sibling();
+            while let Some(sibling) = maybe_sibling {
+                if sibling.is_doctype() {
+                    return true;
+                }
+                maybe_sibling = sibling.next_sibling();
+            }
+            false
+        });
+
+        let is_hierarchy_error = if node.is_document_fragment() {
+            // DocumentFragment : si le noeud a plus d'un enfant élément
+            // ou a un enfant Text ; sinon, si le noeud a un enfant
+            // élément et que le parent a un enfant élément, que l'enfant
+            // est un doctype, ou que l'enfant n'est pas null et qu'un
+            // doctype le suit.
+            let mut element_children = 0;
+            let mut has_text_child = false;
+            node.foreach_child(|child| {
+                element_children += usize::from(child.is_element());
+                has_text_child |= child.is_text();
+            });
+
+            element_children > 1
+                || has_text_child
+                || element_children == 1
+                    && (parent_has_element_child
+                        || child_is_doctype
+                        || doctype_is_following_child)
+        } else if node.is_element() {
+            // Element : le parent a un enfant élément, l'enfant est un
+            // doctype, ou l'enfant n'est pas null et un doctype le suit.
+            parent_has_element_child
+                || child_is_doctype
+                || doctype_is_following_child
+        } else if node.is_doctype() {
+            // DocumentType : le parent a un enfant doctype, un élément
+            // précède l'enfant, ou l'enfant est null et le parent a un
+            // enfant élément.
+            has_child(parent, Node::is_doctype)
+                || match child {
+                    | Some(child) => {
+                        let mut maybe_sibling = child.prev_sibling();
+                        let mut element_is_preceding_child = false;
+                        while let Some(sibling) = maybe_sibling {
+                            element_is_preceding_child |=
+                                sibling.is_element();
+                            maybe_sibling = sibling.prev_sibling();
+                        }
+                        element_is_preceding_child
+                    }
+                    | None => parent_has_element_child,
+                }
+        } else {
+            false
+        };
+
+        if is_hierarchy_error {
+            return Err(DOMException::HierarchyRequestError);
+        }
+    }
+
+    Ok(())
+}
+
+/// Le parent a-t-il un enfant qui satisfait le prédicat ?
+fn has_child(
+    parent: &TreeNode<Node>,
+    predicate: impl Fn(&Node) -> bool,
+) -> bool {
+    let mut maybe_child = parent.get_first_child();
+    while let Some(child) = maybe_child {
+        if predicate(&child) {
+            return true;
+        }
+        maybe_child = child.next_sibling();
+    }
+    false
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl NodeTreeInterface for TreeNode<Node> {
+    fn is_connected(&self) -> bool {
+        self.get_root().is_document()
+    }
+}
+
+impl ElementAttributesInterface for TreeNode<Node> {
+    fn attributes_map(&self) -> NamedNodeMap {
+        NamedNodeMap::new(self)
+    }
+
+    fn get_attribute_node(&self, name: &str) -> Option<Attr> {
+        self.attributes_map().get_named_item(name)
+    }
+
+    fn get_attribute_node_ns(
+        &self,
+        namespace: Option<Namespace>,
+        local_name: &str,
+    ) -> Option<Attr> {
+        self.attributes_map()
+            .get_named_item_ns(namespace, local_name)
+    }
+}
+
+impl NodeTextContentInterface for TreeNode<Node> {
+    fn child_text_content(&self) -> String {
+        let mut text = String::new();
+        let mut maybe_child = self.get_first_child();
+        while let Some(child) = maybe_child {
+            if child.is_text() {
+                text.push_str(&child.character_data_ref().data());
+            }
+            maybe_child = child.next_sibling();
+        }
+        text
+    }
+
+    fn style_text_content(&self) -> Option<String> {
+        match self.node_data.as_ref() {
+            | Some(NodeData::Element(
+                HTMLElementVariant::MetadataStyle(_),
+            )) => Some(self.child_text_content()),
+            | _ => None,
+        }
+    }
+
+    fn script_text(&self) -> Option<String> {
+        match self.node_data.as_ref() {
+            | Some(NodeData::Element(
+                HTMLElementVariant::ScriptingScript(_),
+            )) => Some(self.child_text_content()),
+            | _ => None,
+        }
+    }
+}
+
+impl NodeMutationInterface for TreeNode<Node> {
+    fn try_insert_before(
+        &self,
+        node: TreeNode<Node>,
+        child: Option<&TreeNode<Node>>,
+    ) -> Result<(), DOMException> {
+        // 1. Assurer la validité de la pré-insertion du noeud dans le
+        // parent avant l'enfant.
+        ensure_pre_insertion_validity(self, &node, child)?;
+
+        // 2. Soit referenceChild l'enfant.
+        // 3. Si referenceChild est le noeud, alors définir
+        // referenceChild sur le prochain frère du noeud.
+        let reference_child = match child {
+            | Some(child) if Rc::ptr_eq(child, &node) => {
+                node.next_sibling()
+            }
+            | child => child.cloned(),
+        };
+
+        // 4. Insérer le noeud dans le parent avant referenceChild : le
+        // noeud est adopté, c'est-à-dire retiré de son ancien parent.
+        //
+        // Si le noeud est un noeud DocumentFragment, ce sont ses enfants
+        // qui sont insérés, dans l'ordre de l'arbre ; le fragment est
+        // alors vidé.
+        if node.is_document_fragment() {
+            while let Some(fragment_child) = node.get_first_child() {
+                fragment_child.detach_node();
+                self.insert_before(
+                    fragment_child,
+                    reference_child.as_ref(),
+                );
+            }
+            return Ok(());
+        }
+
+        if node.parent_node().is_some() {
+            node.detach_node();
+        }
+        self.insert_before(node, reference_child.as_ref());
+        Ok(())
+    }
+
+    fn try_append_child(
+        &self,
+        node: TreeNode<Node>,
+    ) -> Result<(), DOMException> {
+        // Ajouter un noeud à un parent signifie pré-insérer le noeud dans
+        // le parent avant null.
+        self.try_insert_before(node, None)
+    }
+
+    fn remove_child(
+        &self,
+        child: &TreeNode<Node>,
+    ) -> Result<TreeNode<Node>, DOMException> {
+        // 1. Si le parent de l'enfant n'est pas le parent, lancer une
+        // DOMException "NotFoundError".
+        match child.parent_node() {
+            | Some(parent) if Rc::ptr_eq(self, &parent) => {}
+            | _ => return Err(DOMException::NotFoundError),
+        }
+
+        // 2. Retirer l'enfant.
+        child.detach_node();
+
+        // 3. Retourner l'enfant.
+        Ok(child.to_owned())
+    }
+}
+
+impl TreeElementInterface for Node {
+    fn is_element(&self) -> bool {
+        Node::is_element(self)
+    }
+}
+
 impl PartialEq for Node {
     fn eq(&self, other: &Self) -> bool {
         self.node_type == other.node_type
@@ -268,3 +732,175 @@ impl Eq for Node {}
 //         &self.0
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_element(local_name: &str) -> TreeNode<Node> {
+        Document::create_element(local_name, None).unwrap()
+    }
+
+    fn create_doctype() -> TreeNode<Node> {
+        TreeNode::new(
+            Node::builder()
+                .set_data(NodeData::DocumentType(DocumentType::new(
+                    "html",
+                )))
+                .set_type(NodeType::DOCUMENT_TYPE_NODE)
+                .build(),
+        )
+    }
+
+    #[test]
+    fn test_insert_document_fragment() {
+        let fragment = DocumentFragmentNode::default();
+        let a = create_element("a");
+        let b = create_element("b");
+        fragment.append_child(a.clone());
+        fragment.append_child(b.clone());
+
+        let div = create_element("div");
+        let hr = create_element("hr");
+        div.append_child(hr.clone());
+
+        // Les enfants du fragment sont insérés, le fragment lui-même ne
+        // l'est pas.
+        assert!(div
+            .try_insert_before(fragment.clone(), Some(&hr))
+            .is_ok());
+        assert!(fragment.get_first_child().is_none());
+        assert!(fragment.parent_node().is_none());
+        assert!(Rc::ptr_eq(&div.get_first_child().unwrap(), &a));
+        assert!(Rc::ptr_eq(&a.next_sibling().unwrap(), &b));
+        assert!(Rc::ptr_eq(&b.next_sibling().unwrap(), &hr));
+        assert_eq!(a.parent_node().as_ref(), Some(&div));
+
+        // Un fragment avec plusieurs éléments ne peut pas être un enfant
+        // du document.
+        let document = DocumentNode::new();
+        fragment.append_child(create_element("c"));
+        fragment.append_child(create_element("d"));
+        assert!(matches!(
+            document.try_append_child(fragment.clone()),
+            Err(DOMException::HierarchyRequestError)
+        ));
+        assert!(fragment.get_first_child().is_some());
+    }
+
+    #[test]
+    fn test_document_children_validity() {
+        let document = DocumentNode::new();
+        let html = create_element("html");
+        assert!(document.try_append_child(html.clone()).is_ok());
+
+        // Un document ne peut avoir qu'un seul enfant élément.
+        assert!(matches!(
+            document.try_append_child(create_element("body")),
+            Err(DOMException::HierarchyRequestError)
+        ));
+
+        // Un doctype ne peut pas suivre l'élément du document.
+        assert!(matches!(
+            document.try_append_child(create_doctype()),
+            Err(DOMException::HierarchyRequestError)
+        ));
+
+        let doctype = create_doctype();
+        assert!(document
+            .try_insert_before(doctype.clone(), Some(&html))
+            .is_ok());
+        assert!(Rc::ptr_eq(
+            &document.get_first_child().unwrap(),
+            &doctype
+        ));
+
+        // Un élément ne peut pas précéder le doctype, et le document ne
+        // peut avoir qu'un seul doctype.
+        html.detach_node();
+        assert!(matches!(
+            document.try_insert_before(html.clone(), Some(&doctype)),
+            Err(DOMException::HierarchyRequestError)
+        ));
+        assert!(matches!(
+            document.try_append_child(create_doctype()),
+            Err(DOMException::HierarchyRequestError)
+        ));
+        assert!(document.try_append_child(html).is_ok());
+    }
+
+    #[test]
+    fn test_is_connected() {
+        let document = DocumentNode::new();
+        let html = create_element("html");
+        let body = create_element("body");
+        let p = create_element("p");
+        let text = document.create_text_node("a");
+        document.append_child(html.clone());
+        html.append_child(body.clone());
+        body.append_child(p.clone());
+        p.append_child(text.clone());
+
+        assert!(document.is_connected());
+        assert!(p.is_connected());
+        assert!(text.is_connected());
+
+        // Un noeud créé n'est connecté qu'une fois ajouté au document.
+        let comment = document.create_comment("commentaire");
+        assert!(!comment.is_connected());
+        body.append_child(comment.clone());
+        assert!(comment.is_connected());
+
+        // Un noeud détaché, et ses descendants, ne le sont plus.
+        p.detach_node();
+        assert!(!p.is_connected());
+        assert!(!text.is_connected());
+        assert!(comment.is_connected());
+    }
+
+    #[test]
+    fn test_try_append_child() {
+        let document = DocumentNode::new();
+        let body = create_element("body");
+        let div = create_element("div");
+        let span = create_element("span");
+        let p = create_element("p");
+        let hr = create_element("hr");
+        let text = document.create_text_node("a");
+        body.append_child(div.clone());
+        body.append_child(span.clone());
+        div.append_child(p.clone());
+        div.append_child(hr.clone());
+        p.append_child(text.clone());
+
+        // Le noeud est détaché de son ancien parent avant d'être ajouté.
+        assert!(span.try_append_child(p.clone()).is_ok());
+        assert_eq!(p.parent_node().as_ref(), Some(&span));
+        assert!(Rc::ptr_eq(&div.get_first_child().unwrap(), &hr));
+        assert!(hr.prev_sibling().is_none());
+
+        // Ré-ajouter un noeud déjà attaché au même parent.
+        assert!(span.try_append_child(hr.clone()).is_ok());
+        assert!(span.try_append_child(p.clone()).is_ok());
+        assert!(div.get_first_child().is_none());
+        assert!(Rc::ptr_eq(&span.get_first_child().unwrap(), &hr));
+        assert!(Rc::ptr_eq(&span.get_last_child().unwrap(), &p));
+        assert!(p.next_sibling().is_none());
+
+        // Un ancêtre ne peut pas être ajouté à l'un de ses descendants.
+        assert!(matches!(
+            p.try_append_child(body.clone()),
+            Err(DOMException::HierarchyRequestError)
+        ));
+        assert!(matches!(
+            p.try_append_child(p.clone()),
+            Err(DOMException::HierarchyRequestError)
+        ));
+
+        // Un noeud Text ne peut pas être un enfant du document.
+        assert!(matches!(
+            document.try_append_child(text),
+            Err(DOMException::HierarchyRequestError)
+        ));
+    }
+}
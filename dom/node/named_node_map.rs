@@ -0,0 +1,140 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use infra::{namespace::Namespace, structure::tree::TreeNode};
+
+use super::{Attr, Node};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un objet NamedNodeMap a un élément (un élément) et une liste
+/// d'attributs, qui est la liste d'attributs de son élément.
+#[derive(Debug)]
+pub struct NamedNodeMap {
+    element: TreeNode<Node>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl NamedNodeMap {
+    pub(super) fn new(element: &TreeNode<Node>) -> Self {
+        Self {
+            element: TreeNode::clone(element),
+        }
+    }
+}
+
+impl NamedNodeMap {
+    /// Le nombre d'attributs de la liste d'attributs.
+    pub fn length(&self) -> usize {
+        self.element.element_ref().attributes.borrow().len()
+    }
+
+    /// L'attribut à l'index `index` de la liste d'attributs, s'il existe.
+    pub fn item(&self, index: usize) -> Option<Attr> {
+        self.element
+            .element_ref()
+            .attributes
+            .borrow()
+            .get(index)
+            .map(|attr| Attr::from(attr).with_owner_element(&self.element))
+    }
+
+    /// Le premier attribut dont le nom qualifié est `qualified_name`.
+    /// Si l'élément est dans l'espace de noms HTML, `qualified_name` est
+    /// d'abord converti en minuscules ASCII.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#concept-element-attributes-get-by-name>
+    pub fn get_named_item(&self, qualified_name: &str) -> Option<Attr> {
+        let element = self.element.element_ref();
+        let qualified_name = if element.isin_html_namespace() {
+            qualified_name.to_ascii_lowercase()
+        } else {
+            qualified_name.to_owned()
+        };
+
+        element
+            .attributes
+            .borrow()
+            .iter()
+            .find(|attr| attr.qualified_name() == qualified_name)
+            .map(|attr| Attr::from(attr).with_owner_element(&self.element))
+    }
+
+    /// L'attribut dont l'espace de noms est `namespace` et le nom local
+    /// est `local_name`.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#concept-element-attributes-get-by-namespace>
+    pub fn get_named_item_ns(
+        &self,
+        namespace: Option<Namespace>,
+        local_name: &str,
+    ) -> Option<Attr> {
+        self.element
+            .element_ref()
+            .attributes
+            .borrow()
+            .iter()
+            .find(|attr| {
+                attr.namespace == namespace
+                    && attr.local_name == local_name
+            })
+            .map(|attr| Attr::from(attr).with_owner_element(&self.element))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::node::{Document, ElementAttributesInterface};
+
+    #[test]
+    fn test_get_attribute_node() {
+        let div = Document::create_element("div", None).unwrap();
+        div.element_ref().set_attribute("id", "x");
+        div.element_ref().set_attribute("class", "c");
+
+        let attributes = div.attributes_map();
+        assert_eq!(attributes.length(), 2);
+        assert_eq!(attributes.item(1).unwrap().name(), "class");
+        assert!(attributes.item(2).is_none());
+
+        // Le nom est converti en minuscules pour les éléments HTML.
+        let id = div.get_attribute_node("Id").unwrap();
+        assert_eq!(id.name(), "id");
+        assert_eq!(id.value(), "x");
+        assert_eq!(id.namespace_uri(), None);
+        assert!(Rc::ptr_eq(&id.owner_element().unwrap(), &div));
+        assert!(div.get_attribute_node("title").is_none());
+
+        let use_el =
+            Document::create_element_ns(Some(Namespace::SVG), "use")
+                .unwrap();
+        use_el.element_ref().set_attribute_ns(
+            Some(Namespace::XLink),
+            Some("xlink"),
+            "href",
+            "#a",
+        );
+        let href = use_el
+            .get_attribute_node_ns(Some(Namespace::XLink), "href")
+            .unwrap();
+        assert_eq!(href.name(), "xlink:href");
+        assert_eq!(href.value(), "#a");
+        assert!(Rc::ptr_eq(&href.owner_element().unwrap(), &use_el));
+        assert_eq!(use_el.get_attribute_node("xlink:href"), Some(href));
+
+        // Un attribut créé hors d'un élément n'a pas d'élément
+        // propriétaire.
+        let attr = Attr::new(None, None, "id", "x");
+        assert!(attr.owner_element().is_none());
+        assert_eq!(attr, id);
+    }
+}
@@ -10,6 +10,7 @@ use super::{
     character_data::CharacterDataInner, CharacterData, DocumentNode, Node,
     NodeData, NodeType,
 };
+use crate::exception::DOMException;
 
 // --------- //
 // Structure //
@@ -43,12 +44,70 @@ impl TextNode {
         tree.set_document(document);
         Self { tree }
     }
+
+    /// Le noeud de l'arbre, s'il s'agit d'un noeud texte.
+    pub fn from_tree(tree: TreeNode<Node>) -> Option<Self> {
+        tree.is_text().then_some(Self { tree })
+    }
+
+    /// Coupe le noeud texte à la position `offset` : le noeud garde les
+    /// données qui précèdent `offset`, un nouveau noeud texte contenant
+    /// le reste est inséré après lui et retourné.
+    ///
+    /// 1. Soit length la longueur du nœud.
+    /// 2. Si offset est supérieur à length, une DOMException
+    ///    "IndexSizeError" est levée.
+    /// 3. Soit count la valeur de length moins offset.
+    /// 4. Soit new data le résultat de substring data avec node, offset et
+    ///    count.
+    /// 5. Soit new node un nouveau noeud Text, avec le même document de
+    ///    noeud que node. Définir les données de new node à new data.
+    /// 6. Soit parent le parent de node.
+    /// 7. Si parent n'est pas null, insérer new node dans parent avant le
+    ///    frère suivant de node.
+    /// 8. Remplacer les données avec node, offset, count et la chaîne
+    ///    vide.
+    /// 9. Retourner new node.
+    ///
+    /// Voir <https://dom.spec.whatwg.org/#concept-text-split>
+    pub fn split_text(
+        &self,
+        offset: usize,
+    ) -> Result<TreeNode<Node>, DOMException> {
+        let data = self.character_data_ref();
+        let length = data.length();
+        if offset > length {
+            #[allow(deprecated)]
+            return Err(DOMException::IndexSizeError);
+        }
+
+        let count = length - offset;
+        let new_data = data.substring_data(offset, count)?;
+
+        let document = self.owner_document().unwrap_or_default();
+        let new_node = Self::new(&document, new_data).tree;
+
+        if let Some(parent) = self.parent_node() {
+            parent.insert_before(
+                new_node.to_owned(),
+                self.next_sibling().as_ref(),
+            );
+        }
+
+        data.replace_data(offset, count, "")?;
+
+        Ok(new_node)
+    }
 }
 
 impl Text {
     pub fn new(data: String) -> Self {
         Self { data }
     }
+
+    pub(super) fn data(&self) -> &str {
+        &self.data
+    }
 }
 
 // -------------- //
@@ -62,3 +121,39 @@ impl ops::Deref for TextNode {
         self.tree.borrow()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::Document;
+
+    #[test]
+    fn test_split_text() {
+        let document = DocumentNode::new();
+        let p = Document::create_element("p", None).unwrap();
+        let b = Document::create_element("b", None).unwrap();
+        let text = TextNode::new(&document, "hello".to_owned());
+        p.append_child(text.to_owned());
+        p.append_child(b.clone());
+
+        let new_node = text.split_text(2).unwrap();
+        assert_eq!(text.character_data_ref().data(), "he");
+        assert_eq!(new_node.character_data_ref().data(), "llo");
+
+        // Le nouveau noeud est inséré juste après le noeud coupé.
+        assert_eq!(text.next_sibling().as_ref(), Some(&new_node));
+        assert_eq!(new_node.next_sibling().as_ref(), Some(&b));
+        assert_eq!(new_node.parent_node().as_ref(), Some(&p));
+
+        // Un noeud sans parent n'insère pas le nouveau noeud.
+        let orphan = document.create_text_node("abc");
+        let orphan = TextNode::from_tree(orphan).unwrap();
+        let new_node = orphan.split_text(3).unwrap();
+        assert_eq!(orphan.character_data_ref().data(), "abc");
+        assert_eq!(new_node.character_data_ref().data(), "");
+        assert!(new_node.parent_node().is_none());
+
+        assert!(orphan.split_text(4).is_err());
+        assert!(TextNode::from_tree(p).is_none());
+    }
+}
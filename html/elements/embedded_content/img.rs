@@ -7,3 +7,342 @@ use crate::html_element;
 html_element! {
     struct HTMLImageElement(img) {}
 }
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Une source d'image candidate de l'attribut `srcset` : une URL suivie
+/// d'un descripteur optionnel.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct SrcsetCandidate {
+    pub url: String,
+    pub descriptor: SrcsetDescriptor,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq)]
+pub enum SrcsetDescriptor {
+    /// Descripteur de largeur : un entier positif suivi du caractère
+    /// "w" (`400w`).
+    Width(u32),
+    /// Descripteur de densité de pixels : un nombre à virgule flottante
+    /// positif suivi du caractère "x" (`2x`). En l'absence de
+    /// descripteur, la densité vaut 1.
+    Density(f64),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLImageElement {
+    /// L'attribut `alt` fournit le contenu de remplacement de l'image.
+    pub fn alt(&self) -> Option<String> {
+        self.get_attribute("alt")
+    }
+
+    /// L'attribut `height` donne la hauteur de l'image, en pixels CSS.
+    pub fn height(&self) -> Option<u32> {
+        self.get_attribute("height")
+            .and_then(|height| parse_non_negative_integer(&height))
+    }
+
+    /// L'attribut `src` donne l'URL de l'image.
+    pub fn src(&self) -> Option<String> {
+        self.get_attribute("src")
+    }
+
+    /// L'attribut `srcset` donne une liste de sources d'image candidates,
+    /// séparées par des virgules. Les candidates malformées sont
+    /// ignorées.
+    ///
+    /// Voir <https://html.spec.whatwg.org/multipage/images.html#parsing-a-srcset-attribute>
+    pub fn srcset(&self) -> Vec<SrcsetCandidate> {
+        self.get_attribute("srcset")
+            .map(|srcset| parse_srcset_attribute(&srcset))
+            .unwrap_or_default()
+    }
+
+    /// L'attribut `width` donne la largeur de l'image, en pixels CSS.
+    pub fn width(&self) -> Option<u32> {
+        self.get_attribute("width")
+            .and_then(|width| parse_non_negative_integer(&width))
+    }
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Algorithme d'analyse d'un attribut srcset.
+fn parse_srcset_attribute(input: &str) -> Vec<SrcsetCandidate> {
+    let is_whitespace = |ch: char| ch.is_ascii_whitespace();
+
+    // 2. Soit candidates une liste initialement vide de sources
+    // d'image.
+    let mut candidates = Vec::new();
+    let mut input = input;
+
+    loop {
+        // 3. Sauter les espaces blancs et les virgules.
+        input = input
+            .trim_start_matches(|ch: char| is_whitespace(ch) || ch == ',');
+
+        // 4. Si la position est après la fin de l'entrée, retourner les
+        // candidates.
+        if input.is_empty() {
+            return candidates;
+        }
+
+        // 5. Collecter une séquence de points de code qui ne sont pas des
+        // espaces blancs : c'est l'URL.
+        let url_end = input.find(is_whitespace).unwrap_or(input.len());
+        let (url, rest) = input.split_at(url_end);
+        input = rest;
+
+        // 7. Si l'URL se termine par une virgule, retirer toutes les
+        // virgules finales : il n'y a pas de descripteur.
+        let descriptors = if url.ends_with(',') {
+            Vec::new()
+        } else {
+            let (descriptors, rest) = tokenize_descriptors(input);
+            input = rest;
+            descriptors
+        };
+        let url = url.trim_end_matches(',');
+
+        // 13. Analyser les descripteurs : une candidate dont les
+        // descripteurs sont malformés est ignorée.
+        if let Some(descriptor) = parse_descriptors(&descriptors) {
+            candidates.push(SrcsetCandidate {
+                url: url.to_owned(),
+                descriptor,
+            });
+        }
+    }
+}
+
+/// Découpe les descripteurs d'une candidate, jusqu'à la prochaine virgule
+/// en dehors de parenthèses. Retourne les descripteurs et le reste de
+/// l'entrée.
+fn tokenize_descriptors(input: &str) -> (Vec<&str>, &str) {
+    let mut descriptors = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut in_parens = false;
+
+    for (idx, ch) in input.char_indices() {
+        match ch {
+            | ',' if !in_parens => {
+                if let Some(start) = start {
+                    descriptors.push(&input[start..idx]);
+                }
+                return (descriptors, &input[idx + 1..]);
+            }
+            | ch if ch.is_ascii_whitespace() && !in_parens => {
+                if let Some(start) = start.take() {
+                    descriptors.push(&input[start..idx]);
+                }
+            }
+            | _ => {
+                in_parens = match ch {
+                    | '(' => true,
+                    | ')' => false,
+                    | _ => in_parens,
+                };
+                start.get_or_insert(idx);
+            }
+        }
+    }
+
+    if let Some(start) = start {
+        descriptors.push(&input[start..]);
+    }
+
+    (descriptors, "")
+}
+
+fn parse_descriptors(descriptors: &[&str]) -> Option<SrcsetDescriptor> {
+    let mut found: Option<SrcsetDescriptor> = None;
+
+    for descriptor in descriptors {
+        let parsed = if let Some(value) = descriptor.strip_suffix('w') {
+            value
+                .parse::<u32>()
+                .ok()
+                .filter(|width| *width > 0 && value.len() == digits(value))
+                .map(SrcsetDescriptor::Width)
+        } else if let Some(value) = descriptor.strip_suffix('x') {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|density| density.is_finite() && *density >= 0.0)
+                .map(SrcsetDescriptor::Density)
+        } else {
+            // NOTE(html): le descripteur "h" (hauteur future) n'est pas
+            // pris en charge.
+            None
+        };
+
+        // Un descripteur inconnu ou dupliqué est une erreur.
+        if parsed.is_none() || found.is_some() {
+            return None;
+        }
+        found = parsed;
+    }
+
+    Some(found.unwrap_or(SrcsetDescriptor::Density(1.0)))
+}
+
+/// Règles d'analyse des entiers non négatifs : les espaces blancs de
+/// début sont ignorés, puis les chiffres ASCII sont interprétés en base
+/// 10.
+fn parse_non_negative_integer(input: &str) -> Option<u32> {
+    let input =
+        input.trim_start_matches(|ch: char| ch.is_ascii_whitespace());
+    let input = input.strip_prefix('+').unwrap_or(input);
+    input[..digits(input)].parse().ok()
+}
+
+fn digits(input: &str) -> usize {
+    input
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(input.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use infra::namespace::Namespace;
+
+    use super::*;
+    use crate::{Element, HTMLElement};
+
+    fn img(attributes: &[(&str, &str)]) -> HTMLImageElement {
+        let element = Element::new(
+            HTMLImageElement::NAME.to_owned().into(),
+            None,
+            Namespace::HTML,
+        );
+        attributes
+            .iter()
+            .for_each(|(name, value)| element.set_attribute(name, value));
+        HTMLImageElement::new(HTMLElement::new(element))
+    }
+
+    fn candidate(
+        url: &str,
+        descriptor: SrcsetDescriptor,
+    ) -> SrcsetCandidate {
+        SrcsetCandidate {
+            url: url.to_owned(),
+            descriptor,
+        }
+    }
+
+    #[test]
+    fn test_img_element_attributes() {
+        let el = img(&[
+            ("src", "a.jpg"),
+            ("alt", "A"),
+            ("width", "100"),
+            ("height", "x"),
+            ("srcset", "a.jpg 1x, b.jpg 2x"),
+        ]);
+        assert_eq!(el.src().as_deref(), Some("a.jpg"));
+        assert_eq!(el.alt().as_deref(), Some("A"));
+        assert_eq!(el.width(), Some(100));
+        assert_eq!(el.height(), None);
+        assert_eq!(
+            el.srcset(),
+            [
+                candidate("a.jpg", SrcsetDescriptor::Density(1.0)),
+                candidate("b.jpg", SrcsetDescriptor::Density(2.0)),
+            ]
+        );
+
+        // Les candidates malformées (descripteur inconnu ou dupliqué)
+        // sont ignorées ; une URL sans descripteur a une densité de 1.
+        let el = img(&[(
+            "srcset",
+            "c.jpg 400w, d.jpg,, e.jpg 2y, f.jpg 1x 2x",
+        )]);
+        assert_eq!(el.src(), None);
+        assert_eq!(
+            el.srcset(),
+            [
+                candidate("c.jpg", SrcsetDescriptor::Width(400)),
+                candidate("d.jpg", SrcsetDescriptor::Density(1.0)),
+            ]
+        );
+        assert!(img(&[]).srcset().is_empty());
+    }
+
+    #[test]
+    fn test_parse_srcset_malformed_descriptors() {
+        for srcset in [
+            "a.jpg 2y",
+            "a.jpg 0w",
+            "a.jpg 1.5w",
+            "a.jpg +2w",
+            "a.jpg -1x",
+            "a.jpg x",
+            "a.jpg 100h",
+            "a.jpg 1x(2)",
+        ] {
+            assert!(parse_srcset_attribute(srcset).is_empty(), "{srcset}");
+        }
+
+        // Seule la candidate malformée est ignorée.
+        assert_eq!(
+            parse_srcset_attribute("a.jpg 2y, b.jpg 0.5x"),
+            [candidate("b.jpg", SrcsetDescriptor::Density(0.5))]
+        );
+    }
+
+    #[test]
+    fn test_parse_srcset_duplicate_descriptors() {
+        for srcset in ["a.jpg 1x 2x", "a.jpg 100w 200w", "a.jpg 100w 1x"] {
+            assert!(parse_srcset_attribute(srcset).is_empty(), "{srcset}");
+        }
+
+        assert_eq!(
+            parse_srcset_attribute("a.jpg 1x 1x, b.jpg 100w"),
+            [candidate("b.jpg", SrcsetDescriptor::Width(100))]
+        );
+    }
+
+    #[test]
+    fn test_parse_srcset_url_ending_with_commas() {
+        // Les virgules finales d'une URL sont retirées, et la candidate
+        // n'a alors pas de descripteur : ce qui suit est une nouvelle
+        // candidate.
+        assert_eq!(
+            parse_srcset_attribute("a.jpg,,, 2x"),
+            [
+                candidate("a.jpg", SrcsetDescriptor::Density(1.0)),
+                candidate("2x", SrcsetDescriptor::Density(1.0)),
+            ]
+        );
+        assert_eq!(
+            parse_srcset_attribute("a.jpg, b.jpg,"),
+            [
+                candidate("a.jpg", SrcsetDescriptor::Density(1.0)),
+                candidate("b.jpg", SrcsetDescriptor::Density(1.0)),
+            ]
+        );
+
+        // Une virgule au milieu d'une URL en fait partie.
+        assert_eq!(
+            parse_srcset_attribute("a.jpg,b.jpg 2x"),
+            [candidate("a.jpg,b.jpg", SrcsetDescriptor::Density(2.0))]
+        );
+        assert!(parse_srcset_attribute(" , ,, ").is_empty());
+    }
+}
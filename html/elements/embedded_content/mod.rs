@@ -4,4 +4,4 @@
 
 mod img;
 
-pub use self::img::HTMLImageElement;
+pub use self::img::{HTMLImageElement, SrcsetCandidate, SrcsetDescriptor};
@@ -122,10 +122,24 @@ pub struct Element {
     name: DOMString,
 
     // TODO(phisyx): changer le type de cet attribut en NamedNodeMap (cf. https://dom.spec.whatwg.org/#namednodemap)
-    pub attributes: RefCell<HashMap<String, String>>,
+    pub attributes: RefCell<Vec<ElementAttribute>>,
     pub id: RefCell<Option<DOMString>>,
     pub is: RefCell<Option<DOMString>>,
     pub namespace_uri: RefCell<Namespace>,
+    pub prefix: RefCell<Option<DOMString>>,
+}
+
+/// Attribut d'un élément. Les attributs étrangers ajustés lors de
+/// l'analyse (`xlink:href`, `xml:lang`, ...) ont un préfixe et un espace
+/// de noms.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct ElementAttribute {
+    pub namespace: Option<Namespace>,
+    pub prefix: Option<String>,
+    pub local_name: String,
+    pub value: String,
 }
 
 #[derive(Debug)]
@@ -161,10 +175,18 @@ pub enum HTMLElementVariant<Document, Fragment> {
         /// 4.2.2 The title element
         HTMLTitleElement,
     ),
+    MetadataLink(
+        /// 4.2.4 The link element
+        HTMLLinkElement,
+    ),
     MetadataMeta(
         /// 4.2.5 The meta element
         HTMLMetaElement,
     ),
+    MetadataStyle(
+        /// 4.2.6 The style element
+        HTMLStyleElement,
+    ),
 
     // 4.3 Sections
     SectionBody(
@@ -243,6 +265,15 @@ pub enum HTMLElementVariant<Document, Fragment> {
     Unknown(HTMLUnknownElement),
 }
 
+/// Erreur de l'API dataset.
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum DatasetError {
+    /// Le nom contient un caractère U+002D HYPHEN-MINUS (-) suivi d'une
+    /// lettre ASCII minuscule.
+    SyntaxError,
+}
+
 // -------------- //
 // Implementation //
 // -------------- //
@@ -260,6 +291,7 @@ impl Element {
             id: Default::default(),
             is: RefCell::new(is.map(DOMString::from)),
             namespace_uri: RefCell::new(namespace_uri),
+            prefix: Default::default(),
         }
     }
 }
@@ -289,11 +321,13 @@ impl Element {
     }
 
     pub fn is_html_text_integration_point(&self) -> bool {
-        if self.tag_name() == tag_names::annotationXml {
-            let attrs = self.attributes.borrow().clone();
-            let encoding_str = "encoding";
-            let maybe_encoding = attrs.get(&encoding_str.to_owned());
-            if let Some(encoding) = maybe_encoding {
+        // NOTE(phisyx): un élément annotation-xml n'est un point
+        // d'intégration HTML que dans l'espace de noms MathML.
+        if self.namespace() == Some(Namespace::MathML)
+            && self.tag_name() == tag_names::annotationXml
+        {
+            let maybe_encoding = self.get_attribute("encoding");
+            if let Some(encoding) = maybe_encoding.as_deref() {
                 let encoding_str = "text/html";
                 if encoding_str.eq_ignore_ascii_case(encoding) {
                     return true;
@@ -318,6 +352,37 @@ impl Element {
         self.name.borrow().to_owned()
     }
 
+    /// Le préfixe d'espace de noms de l'élément.
+    pub fn prefix(&self) -> Option<String> {
+        self.prefix
+            .borrow()
+            .as_ref()
+            .map(|prefix| prefix.borrow().to_owned())
+    }
+
+    /// Le nom qualifié d'un élément est son nom local si son préfixe
+    /// d'espace de noms est nul, et sinon son préfixe, suivi de ":",
+    /// suivi de son nom local.
+    pub fn qualified_name(&self) -> String {
+        match self.prefix() {
+            | Some(prefix) => format!("{prefix}:{}", self.local_name()),
+            | None => self.local_name(),
+        }
+    }
+
+    /// Le nom de la balise de l'élément, tel qu'il est sérialisé : son
+    /// nom local s'il est dans l'espace de noms HTML, MathML ou SVG, son
+    /// nom qualifié sinon. Contrairement à [Element::tag_name], ne
+    /// panique pas pour les éléments inconnus.
+    pub fn tag_name_string(&self) -> String {
+        match self.namespace() {
+            | Some(
+                Namespace::HTML | Namespace::MathML | Namespace::SVG,
+            ) => self.local_name(),
+            | _ => self.qualified_name(),
+        }
+    }
+
     pub fn tag_name(&self) -> tag_names {
         self.name
             .borrow()
@@ -326,20 +391,155 @@ impl Element {
     }
 
     pub fn has_attribute(&self, name: &str) -> bool {
-        (*self.attributes.borrow()).contains_key(&name.to_owned())
+        self.attributes
+            .borrow()
+            .iter()
+            .any(|attr| attr.qualified_name() == name)
+    }
+
+    pub fn get_attribute(&self, name: &str) -> Option<String> {
+        self.attributes
+            .borrow()
+            .iter()
+            .find(|attr| attr.qualified_name() == name)
+            .map(|attr| attr.value.to_owned())
+    }
+
+    /// Les attributs `data-*` personnalisés de l'élément : les noms sont
+    /// ceux des attributs sans espace de noms, dont le nom local commence
+    /// par "data-" et ne contient pas de lettre ASCII majuscule, sans ce
+    /// préfixe, convertis en camelCase (`data-foo-bar` → `fooBar`).
+    ///
+    /// Voir <https://html.spec.whatwg.org/multipage/dom.html#dom-dataset>
+    pub fn dataset(&self) -> HashMap<String, String> {
+        self.attributes
+            .borrow()
+            .iter()
+            .filter(|attr| attr.namespace.is_none())
+            .filter(|attr| {
+                !attr
+                    .local_name
+                    .contains(|ch: char| ch.is_ascii_uppercase())
+            })
+            .filter_map(|attr| {
+                let name = attr.local_name.strip_prefix("data-")?;
+
+                // Pour chaque caractère U+002D HYPHEN-MINUS (-) du nom
+                // qui est suivi d'une lettre ASCII minuscule, retirer le
+                // caractère U+002D HYPHEN-MINUS (-) et remplacer le
+                // caractère qui le suivait par la même lettre en
+                // majuscule.
+                let mut key = String::with_capacity(name.len());
+                let mut chars = name.chars().peekable();
+                while let Some(ch) = chars.next() {
+                    match chars.peek() {
+                        | Some(next)
+                            if ch == '-' && next.is_ascii_lowercase() =>
+                        {
+                            key.push(next.to_ascii_uppercase());
+                            chars.next();
+                        }
+                        | _ => key.push(ch),
+                    }
+                }
+
+                Some((key, attr.value.to_owned()))
+            })
+            .collect()
+    }
+
+    /// Retourne la valeur de l'attribut `data-*` correspondant au nom
+    /// (camelCase) de l'API dataset.
+    pub fn get_data(&self, key: &str) -> Option<String> {
+        self.dataset().remove(key)
     }
 }
 
 // &mut Self
 impl Element {
+    /// Défini l'attribut `data-*` correspondant au nom (camelCase) de
+    /// l'API dataset : chaque lettre ASCII majuscule est précédée d'un
+    /// caractère U+002D HYPHEN-MINUS (-) et convertie en minuscule, puis
+    /// le nom est préfixé par "data-".
+    ///
+    /// Voir <https://html.spec.whatwg.org/multipage/dom.html#dom-domstringmap-setitem>
+    pub fn set_data_attr(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<(), DatasetError> {
+        // 1. Si le nom contient un caractère U+002D HYPHEN-MINUS (-)
+        // suivi d'une lettre ASCII minuscule, lancer une DOMException
+        // "SyntaxError".
+        let has_invalid_hyphen = key
+            .as_bytes()
+            .windows(2)
+            .any(|pair| pair[0] == b'-' && pair[1].is_ascii_lowercase());
+        if has_invalid_hyphen {
+            return Err(DatasetError::SyntaxError);
+        }
+
+        // 2. à 4. Convertir le nom et le préfixer par "data-".
+        let mut name = String::from("data-");
+        key.chars().for_each(|ch| {
+            if ch.is_ascii_uppercase() {
+                name.push('-');
+            }
+            name.push(ch.to_ascii_lowercase());
+        });
+
+        // 5. Définir la valeur de l'attribut.
+        self.set_attribute(&name, value);
+        Ok(())
+    }
+
     pub fn set_attribute(&self, name: &str, value: &str) {
-        if name == "id" {
+        self.set_attribute_ns(None, None, name, value);
+    }
+
+    /// Défini un attribut avec un espace de noms. L'attribut est
+    /// identifié par son espace de noms et son nom local : s'il existe
+    /// déjà, sa valeur est remplacée, sinon il est ajouté à la fin de la
+    /// liste des attributs.
+    pub fn set_attribute_ns(
+        &self,
+        namespace: Option<Namespace>,
+        prefix: Option<&str>,
+        local_name: &str,
+        value: &str,
+    ) {
+        if namespace.is_none() && local_name == "id" {
             self.id.borrow_mut().replace(value.to_owned().into());
+        }
+
+        let mut attributes = self.attributes.borrow_mut();
+        if let Some(attr) = attributes.iter_mut().find(|attr| {
+            attr.namespace == namespace && attr.local_name == local_name
+        }) {
+            attr.value = value.to_owned();
             return;
         }
 
-        (self.attributes.borrow_mut())
-            .insert(name.to_owned(), value.to_owned());
+        attributes.push(ElementAttribute {
+            namespace,
+            prefix: prefix.map(ToOwned::to_owned),
+            local_name: local_name.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+}
+
+impl ElementAttribute {
+    /// Le nom qualifié d'un attribut est son nom local si son préfixe
+    /// est nul, et sinon son préfixe, suivi de ":", suivi de son nom
+    /// local.
+    pub fn qualified_name(&self) -> String {
+        match self.prefix.as_deref() {
+            | Some(prefix) if !prefix.is_empty() => {
+                format!("{prefix}:{}", self.local_name)
+            }
+            | _ => self.local_name.to_owned(),
+        }
     }
 }
 
@@ -353,9 +553,9 @@ impl HTMLElement {
 // &Self
 impl HTMLElement {}
 
-impl HTMLUnknownElement {
+impl HTMLElementInterface for HTMLUnknownElement {
     fn tag_name(&self) -> String {
-        unsafe { &*self.name.as_ptr() }.to_owned()
+        self.name.borrow().to_owned()
     }
 }
 
@@ -377,7 +577,9 @@ impl<D, F> HTMLElementVariant<D, F> {
             | Self::DocumentHtml(el) => el,
             | Self::MetadataHead(el) => el,
             | Self::MetadataTitle(el) => el,
+            | Self::MetadataLink(el) => el,
             | Self::MetadataMeta(el) => el,
+            | Self::MetadataStyle(el) => el,
             | Self::SectionBody(el) => el,
             | Self::SectionHeading(el) => el,
             | Self::GroupingContentHr(el) => el,
@@ -398,6 +600,34 @@ impl<D, F> HTMLElementVariant<D, F> {
         }
     }
 
+    pub fn img(&self) -> &HTMLImageElement {
+        match self {
+            | Self::EmbeddedContentImg(img) => img,
+            | _ => panic!("N'est pas un élément HTMLImageElement."),
+        }
+    }
+
+    pub fn link(&self) -> &HTMLLinkElement {
+        match self {
+            | Self::MetadataLink(link) => link,
+            | _ => panic!("N'est pas un élément HTMLLinkElement."),
+        }
+    }
+
+    pub fn meta(&self) -> &HTMLMetaElement {
+        match self {
+            | Self::MetadataMeta(meta) => meta,
+            | _ => panic!("N'est pas un élément HTMLMetaElement."),
+        }
+    }
+
+    pub fn style(&self) -> &HTMLStyleElement {
+        match self {
+            | Self::MetadataStyle(style) => style,
+            | _ => panic!("N'est pas un élément HTMLStyleElement."),
+        }
+    }
+
     pub fn script(&self) -> &HTMLScriptElement<D> {
         match self {
             | Self::ScriptingScript(script) => script,
@@ -436,9 +666,15 @@ where
             | tag_names::title => {
                 Self::MetadataTitle(HTMLTitleElement::new(el))
             }
+            | tag_names::link => {
+                Self::MetadataLink(HTMLLinkElement::new(el))
+            }
             | tag_names::meta => {
                 Self::MetadataMeta(HTMLMetaElement::new(el))
             }
+            | tag_names::style => {
+                Self::MetadataStyle(HTMLStyleElement::new(el))
+            }
 
             | tag_names::template => Self::ScriptingTemplate(
                 HTMLTemplateElement::new(el, Default::default()),
@@ -530,7 +766,9 @@ impl<D, F> fmt::Display for HTMLElementVariant<D, F> {
                 | Self::DocumentHtml(el) => el.tag_name(),
                 | Self::MetadataHead(el) => el.tag_name(),
                 | Self::MetadataTitle(el) => el.tag_name(),
+                | Self::MetadataLink(el) => el.tag_name(),
                 | Self::MetadataMeta(el) => el.tag_name(),
+                | Self::MetadataStyle(el) => el.tag_name(),
                 | Self::GroupingContentHr(el) => el.tag_name(),
                 | Self::GroupingContentPre(el) => el.tag_name(),
                 | Self::GroupingContentBlockquote(el) => el.tag_name(),
@@ -568,3 +806,75 @@ impl<D, F> ops::Deref for HTMLElementVariant<D, F> {
         self.html()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_dataset() {
+        let element = Element::new(
+            DOMString::from("div".to_owned()),
+            None,
+            Namespace::HTML,
+        );
+        element.set_attribute("data-user-id", "42");
+        element.set_attribute("data-x", "1");
+        element.set_attribute("data-a-1", "2");
+        element.set_attribute("title", "t");
+
+        let dataset = element.dataset();
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(dataset["userId"], "42");
+        assert_eq!(dataset["x"], "1");
+        // Un tiret suivi d'un autre caractère qu'une lettre minuscule est
+        // conservé.
+        assert_eq!(dataset["a-1"], "2");
+
+        assert_eq!(element.get_data("userId").as_deref(), Some("42"));
+        assert_eq!(element.get_data("user-id"), None);
+
+        assert_eq!(element.set_data_attr("fooBar", "baz"), Ok(()));
+        assert_eq!(
+            element.get_attribute("data-foo-bar").as_deref(),
+            Some("baz")
+        );
+        assert_eq!(element.get_data("fooBar").as_deref(), Some("baz"));
+
+        assert_eq!(
+            element.set_data_attr("foo-bar", "baz"),
+            Err(DatasetError::SyntaxError)
+        );
+    }
+
+    #[test]
+    fn test_unknown_element_tag_name() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let element = Element::new(
+            DOMString::from("foo-bar".to_owned()),
+            None,
+            Namespace::HTML,
+        );
+        let unknown = HTMLUnknownElement::new(HTMLElement::new(element));
+        assert_eq!(unknown.tag_name(), "foo-bar");
+
+        // Lire le nom de la balise pendant que le nom est emprunté
+        // mutablement panique (BorrowError) au lieu de lire une donnée en
+        // cours de modification.
+        let mut name = unknown.name.borrow_mut();
+        name.push_str("-baz");
+        let result =
+            panic::catch_unwind(AssertUnwindSafe(|| unknown.tag_name()));
+        let err = result.expect_err("BorrowError attendue");
+        let message = err
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| err.downcast_ref::<&str>().copied())
+            .unwrap_or_default();
+        assert!(message.contains("already mutably borrowed"), "{message}");
+        drop(name);
+
+        assert_eq!(unknown.tag_name(), "foo-bar-baz");
+    }
+}
@@ -0,0 +1,37 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::html_element;
+
+html_element! {
+    struct HTMLLinkElement(link) {}
+}
+
+impl HTMLLinkElement {
+    /// L'attribut `href` donne l'adresse de la ressource liée.
+    pub fn href(&self) -> Option<String> {
+        self.get_attribute("href")
+    }
+
+    /// L'attribut `media` indique les médias auxquels la ressource
+    /// s'applique.
+    pub fn media(&self) -> Option<String> {
+        self.get_attribute("media")
+    }
+
+    /// L'attribut `rel` donne les types de relation du lien, séparés par
+    /// des espaces ASCII.
+    pub fn rel(&self) -> Option<String> {
+        self.get_attribute("rel")
+    }
+
+    /// Le lien est-il une feuille de style externe ? Les types de
+    /// relation ne sont pas sensibles à la casse ASCII.
+    pub fn is_stylesheet(&self) -> bool {
+        self.rel().is_some_and(|rel| {
+            rel.split_ascii_whitespace()
+                .any(|keyword| keyword.eq_ignore_ascii_case("stylesheet"))
+        })
+    }
+}
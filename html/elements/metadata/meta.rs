@@ -7,3 +7,28 @@ use crate::html_element;
 html_element! {
     struct HTMLMetaElement(meta) {}
 }
+
+impl HTMLMetaElement {
+    /// L'attribut `charset` spécifie l'encodage des caractères utilisé
+    /// par le document.
+    pub fn charset(&self) -> Option<String> {
+        self.get_attribute("charset")
+    }
+
+    /// L'attribut `content` donne la valeur des métadonnées du document
+    /// ou de la directive pragma.
+    pub fn content(&self) -> Option<String> {
+        self.get_attribute("content")
+    }
+
+    /// L'attribut `http-equiv` est un attribut énuméré, qui transforme
+    /// l'élément meta en une directive pragma.
+    pub fn http_equiv(&self) -> Option<String> {
+        self.get_attribute("http-equiv")
+    }
+
+    /// L'attribut `name` donne le nom des métadonnées du document.
+    pub fn name(&self) -> Option<String> {
+        self.get_attribute("name")
+    }
+}
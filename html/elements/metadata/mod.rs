@@ -3,9 +3,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 mod head;
+mod link;
 mod meta;
+mod style;
 mod title;
 
 pub use self::{
-    head::HTMLHeadElement, meta::HTMLMetaElement, title::HTMLTitleElement,
+    head::HTMLHeadElement, link::HTMLLinkElement, meta::HTMLMetaElement,
+    style::HTMLStyleElement, title::HTMLTitleElement,
 };
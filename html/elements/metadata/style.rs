@@ -0,0 +1,22 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::html_element;
+
+html_element! {
+    struct HTMLStyleElement(style) {}
+}
+
+// NOTE(phisyx): le contenu textuel de l'élément (la feuille de style) est
+// fait des noeuds Text enfants, que cette structure ne connaît pas : il
+// se récupère depuis le noeud de l'arbre, avec
+// `NodeTextContentInterface::style_text_content` (crate dom).
+
+impl HTMLStyleElement {
+    /// L'attribut `media` indique les médias auxquels la feuille de
+    /// style s'applique.
+    pub fn media(&self) -> Option<String> {
+        self.get_attribute("media")
+    }
+}
@@ -14,6 +14,11 @@ html_element! {
     }
 }
 
+// NOTE(phisyx): le texte de l'élément (`HTMLScriptElement.text`) est fait
+// des noeuds Text enfants, que cette structure ne connaît pas : il se
+// récupère depuis le noeud de l'arbre, avec
+// `NodeTextContentInterface::script_text` (crate dom).
+
 impl<D> HTMLScriptElement<D> {
     pub fn set_already_started(&self, to: bool) -> &Self {
         *self.already_started.borrow_mut() = to;
@@ -25,12 +25,20 @@ macro_rules! enumerate_html_tag_attributes {
         ),*
         }
 
+        /// Noms des attributs, calculés à la compilation, pour pouvoir
+        /// être utilisés comme motifs.
+        #[allow(non_upper_case_globals)]
+        mod literals {
+            $(pub(super) const $name: &str =
+                super::tag_attributes::name_of(stringify!($name));)*
+        }
+
         impl str::FromStr for tag_attributes {
             type Err = &'static str;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 Ok(match s {
-                    $(| stringify!($name) => Self::$name),*,
+                    $(| literals::$name => Self::$name),*,
                     | _ => return Err("Attribut inconnu")
                 })
             }
@@ -39,13 +47,32 @@ macro_rules! enumerate_html_tag_attributes {
         impl fmt::Display for tag_attributes {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "{}", match self {
-                    $(Self::$name => stringify!($name)),*
+                    $(Self::$name => literals::$name),*
                 })
             }
         }
     };
 }
 
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl tag_attributes {
+    /// Nom de l'attribut à partir de l'identifiant de la variante.
+    // NOTE(phisyx): les identifiants bruts (`r#type`, `r#for`, ...) ne
+    // doivent pas garder leur préfixe.
+    const fn name_of(ident: &'static str) -> &'static str {
+        match ident.as_bytes() {
+            | [b'r', b'#', name @ ..] => match str::from_utf8(name) {
+                | Ok(name) => name,
+                | Err(_) => ident,
+            },
+            | _ => ident,
+        }
+    }
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -29,24 +29,39 @@ macro_rules! enumerate_html_tag_names {
         ),*
         }
 
+        /// Noms des balises, calculés à la compilation, pour pouvoir être
+        /// utilisés comme motifs.
+        #[allow(non_upper_case_globals)]
+        mod literals {
+            $(pub(super) const $name: &str =
+                super::tag_names::name_of(stringify!($name));)*
+        }
+
         impl str::FromStr for tag_names {
             type Err = &'static str;
 
             #[allow(deprecated)]
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 Ok(match s {
-                    $(| stringify!($name) => Self::$name),*,
+                    $(| literals::$name => Self::$name),*,
                     | _ => return Err("Élément inconnu")
                 })
             }
         }
 
-        impl fmt::Display for tag_names {
+        impl tag_names {
+            /// Nom de la balise, sans allocation.
             #[allow(deprecated)]
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$name => literals::$name),*
+                }
+            }
+        }
+
+        impl fmt::Display for tag_names {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", match self {
-                    $(Self::$name => stringify!($name)),*
-                })
+                f.write_str(self.as_str())
             }
         }
     };
@@ -57,6 +72,21 @@ macro_rules! enumerate_html_tag_names {
 // -------------- //
 
 impl tag_names {
+    /// Nom de la balise à partir de l'identifiant de la variante.
+    // NOTE(phisyx): les identifiants bruts (`r#use`, `r#type`, ...) ne
+    // doivent pas garder leur préfixe, et certains noms de balise ne sont
+    // pas des identifiants valides (`annotation-xml`).
+    const fn name_of(ident: &'static str) -> &'static str {
+        match ident.as_bytes() {
+            | b"annotationXml" => "annotation-xml",
+            | [b'r', b'#', name @ ..] => match str::from_utf8(name) {
+                | Ok(name) => name,
+                | Err(_) => ident,
+            },
+            | _ => ident,
+        }
+    }
+
     /*
     NameStartChar ::= ":" | [A-Z]     | "_" | [a-z]     | [#xC0-#xD6]
                     | [#xD8-#xF6]     | [#xF8-#x2FF]    | [#x370-#x37D]
@@ -90,6 +120,15 @@ impl tag_names {
             )
     }
 
+    /// Le nom de la balise est-il égal à `s`, sans tenir compte de la
+    /// casse ASCII ? Contrairement à une comparaison après
+    /// [str::to_lowercase], aucune chaîne n'est allouée et seules les
+    /// lettres ASCII sont converties : `"TİTLE"` n'est pas égal à
+    /// `title`.
+    pub fn eq_ascii(&self, s: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(s)
+    }
+
     pub fn is_valid_name(name: impl AsRef<str>) -> bool {
         let name = name.as_ref();
 
@@ -104,7 +143,7 @@ impl tag_names {
             return false;
         }
 
-        chars.any(Self::name_char)
+        chars.all(Self::name_char)
     }
 }
 
@@ -124,7 +163,8 @@ where
     S: Copy,
 {
     fn is_one_of(self, arr: impl IntoIterator<Item = tag_names>) -> bool {
-        arr.into_iter().any(|tag_name| tag_name == self)
+        let name = self.as_ref();
+        arr.into_iter().any(|tag_name| tag_name.as_str() == name)
     }
 }
 
@@ -133,7 +173,7 @@ where
     S: AsRef<str>,
 {
     fn eq(&self, other: &S) -> bool {
-        self.to_string().eq(other.as_ref())
+        self.as_str() == other.as_ref()
     }
 }
 
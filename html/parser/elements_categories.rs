@@ -0,0 +1,337 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Catégories d'éléments utilisées par les algorithmes de l'arbre de
+//! construction (portées, balises de fin implicites, algorithme de
+//! l'agence d'adoption).
+//!
+//! Voir <https://html.spec.whatwg.org/multipage/parsing.html#the-stack-of-open-elements>
+
+use html_elements::{interface::IsOneOfTagsInterface, tag_names};
+use infra::namespace::Namespace;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Éléments HTML de la catégorie spéciale.
+pub(crate) const SPECIAL_HTML_ELEMENTS: [tag_names; 82] = [
+    tag_names::address,
+    #[allow(deprecated)]
+    tag_names::applet,
+    tag_names::area,
+    tag_names::article,
+    tag_names::aside,
+    tag_names::base,
+    #[allow(deprecated)]
+    tag_names::basefont,
+    #[allow(deprecated)]
+    tag_names::bgsound,
+    tag_names::blockquote,
+    tag_names::body,
+    tag_names::br,
+    tag_names::button,
+    tag_names::caption,
+    #[allow(deprecated)]
+    tag_names::center,
+    tag_names::col,
+    tag_names::colgroup,
+    tag_names::dd,
+    tag_names::details,
+    #[allow(deprecated)]
+    tag_names::dir,
+    tag_names::div,
+    tag_names::dl,
+    tag_names::dt,
+    tag_names::embed,
+    tag_names::fieldset,
+    tag_names::figcaption,
+    tag_names::figure,
+    tag_names::footer,
+    tag_names::form,
+    #[allow(deprecated)]
+    tag_names::frame,
+    #[allow(deprecated)]
+    tag_names::frameset,
+    tag_names::h1,
+    tag_names::h2,
+    tag_names::h3,
+    tag_names::h4,
+    tag_names::h5,
+    tag_names::h6,
+    tag_names::head,
+    tag_names::header,
+    #[allow(deprecated)]
+    tag_names::hgroup,
+    tag_names::hr,
+    tag_names::html,
+    tag_names::iframe,
+    tag_names::img,
+    tag_names::input,
+    #[allow(deprecated)]
+    tag_names::keygen,
+    tag_names::li,
+    tag_names::link,
+    #[allow(deprecated)]
+    tag_names::listing,
+    tag_names::main,
+    #[allow(deprecated)]
+    tag_names::marquee,
+    tag_names::menu,
+    tag_names::meta,
+    tag_names::nav,
+    #[allow(deprecated)]
+    tag_names::noembed,
+    #[allow(deprecated)]
+    tag_names::noframes,
+    tag_names::noscript,
+    tag_names::object,
+    tag_names::ol,
+    tag_names::p,
+    #[allow(deprecated)]
+    tag_names::param,
+    #[allow(deprecated)]
+    tag_names::plaintext,
+    tag_names::pre,
+    tag_names::script,
+    tag_names::section,
+    tag_names::select,
+    tag_names::source,
+    tag_names::style,
+    tag_names::summary,
+    tag_names::table,
+    tag_names::tbody,
+    tag_names::td,
+    tag_names::template,
+    tag_names::textarea,
+    tag_names::tfoot,
+    tag_names::th,
+    tag_names::thead,
+    tag_names::title,
+    tag_names::tr,
+    tag_names::track,
+    tag_names::ul,
+    tag_names::wbr,
+    #[allow(deprecated)]
+    tag_names::xmp,
+];
+
+/// Éléments MathML de la catégorie spéciale.
+pub(crate) const SPECIAL_MATHML_ELEMENTS: [tag_names; 6] = [
+    tag_names::mi,
+    tag_names::mo,
+    tag_names::mn,
+    tag_names::ms,
+    tag_names::mtext,
+    tag_names::annotationXml,
+];
+
+/// Éléments SVG de la catégorie spéciale.
+pub(crate) const SPECIAL_SVG_ELEMENTS: [tag_names; 3] =
+    [tag_names::foreignObject, tag_names::desc, tag_names::title];
+
+/// Éléments de la catégorie de mise en forme.
+pub(crate) const FORMATTING_ELEMENTS: [tag_names; 14] = [
+    tag_names::a,
+    tag_names::b,
+    #[allow(deprecated)]
+    tag_names::big,
+    tag_names::code,
+    tag_names::em,
+    #[allow(deprecated)]
+    tag_names::font,
+    tag_names::i,
+    #[allow(deprecated)]
+    tag_names::nobr,
+    tag_names::s,
+    tag_names::small,
+    #[allow(deprecated)]
+    tag_names::strike,
+    tag_names::strong,
+    #[allow(deprecated)]
+    tag_names::tt,
+    tag_names::u,
+];
+
+/// Types d'éléments délimitant la portée (champ d'application) d'un
+/// élément dans la pile des éléments ouverts :
+///   - applet, caption, html, table, td, th, marquee, object, template
+///   - MathML mi, mo, mn, ms, mtext, annotation-xml
+///   - SVG foreignObject, desc, title
+pub(crate) const SCOPE_ELEMENTS: [tag_names; 18] = [
+    #[allow(deprecated)]
+    tag_names::applet,
+    tag_names::caption,
+    tag_names::html,
+    tag_names::table,
+    tag_names::td,
+    tag_names::th,
+    #[allow(deprecated)]
+    tag_names::marquee,
+    tag_names::object,
+    tag_names::template,
+    tag_names::mi,
+    tag_names::mo,
+    tag_names::mn,
+    tag_names::ms,
+    tag_names::mtext,
+    tag_names::annotationXml,
+    tag_names::foreignObject,
+    tag_names::desc,
+    tag_names::title,
+];
+
+/// Portée du bouton : [SCOPE_ELEMENTS] et l'élément button.
+pub(crate) const BUTTON_SCOPE_ELEMENTS: [tag_names; 19] =
+    scope_elements_with([tag_names::button]);
+
+/// Portée des éléments de liste : [SCOPE_ELEMENTS] et les éléments ol,
+/// ul.
+pub(crate) const LIST_ITEM_SCOPE_ELEMENTS: [tag_names; 20] =
+    scope_elements_with([tag_names::ol, tag_names::ul]);
+
+/// Portée de tableau : les éléments html, table et template.
+pub(crate) const TABLE_SCOPE_ELEMENTS: [tag_names; 3] =
+    [tag_names::html, tag_names::table, tag_names::template];
+
+/// Portée de select : tous les éléments, SAUF optgroup et option.
+pub(crate) const SELECT_SCOPE_ELEMENTS: [tag_names; 2] =
+    [tag_names::optgroup, tag_names::option];
+
+/// Éléments retirés de la pile lors de la génération des balises de fin
+/// implicites.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#generate-implied-end-tags>
+pub(crate) const IMPLIED_END_TAGS_ELEMENTS: [tag_names; 10] = [
+    tag_names::dd,
+    tag_names::dt,
+    tag_names::li,
+    tag_names::optgroup,
+    tag_names::option,
+    tag_names::p,
+    #[allow(deprecated)]
+    tag_names::rb,
+    tag_names::rp,
+    tag_names::rt,
+    #[allow(deprecated)]
+    tag_names::rtc,
+];
+
+/// Éléments retirés de la pile lors de la génération exhaustive des
+/// balises de fin implicites.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#generate-all-implied-end-tags-thoroughly>
+pub(crate) const THOROUGHLY_IMPLIED_END_TAGS_ELEMENTS: [tag_names; 18] = [
+    tag_names::caption,
+    tag_names::colgroup,
+    tag_names::dd,
+    tag_names::dt,
+    tag_names::li,
+    tag_names::optgroup,
+    tag_names::option,
+    tag_names::p,
+    #[allow(deprecated)]
+    tag_names::rb,
+    tag_names::rp,
+    tag_names::rt,
+    #[allow(deprecated)]
+    tag_names::rtc,
+    tag_names::tbody,
+    tag_names::td,
+    tag_names::tfoot,
+    tag_names::th,
+    tag_names::thead,
+    tag_names::tr,
+];
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// L'élément est-il dans la catégorie spéciale ? Le nom de la balise
+/// peut être un nom connu ([tag_names]) ou un nom local quelconque.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#special>
+pub(crate) fn is_special(
+    tag_name: impl IsOneOfTagsInterface,
+    namespace: Namespace,
+) -> bool {
+    match namespace {
+        | Namespace::HTML => tag_name.is_one_of(SPECIAL_HTML_ELEMENTS),
+        | Namespace::MathML => tag_name.is_one_of(SPECIAL_MATHML_ELEMENTS),
+        | Namespace::SVG => tag_name.is_one_of(SPECIAL_SVG_ELEMENTS),
+        | _ => false,
+    }
+}
+
+/// Concatène [SCOPE_ELEMENTS] et la liste donnée. La taille du tableau
+/// retourné doit être la somme des deux tailles, sinon l'évaluation
+/// constante échoue.
+const fn scope_elements_with<const N: usize, const M: usize>(
+    list: [tag_names; M],
+) -> [tag_names; N] {
+    assert!(N == SCOPE_ELEMENTS.len() + M);
+
+    let mut elements = [tag_names::var; N];
+
+    let mut idx = 0;
+    while idx < SCOPE_ELEMENTS.len() {
+        elements[idx] = SCOPE_ELEMENTS[idx];
+        idx += 1;
+    }
+
+    let mut jdx = 0;
+    while jdx < M {
+        elements[idx + jdx] = list[jdx];
+        jdx += 1;
+    }
+
+    elements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(SPECIAL_HTML_ELEMENTS.len(), 82);
+        assert_eq!(FORMATTING_ELEMENTS.len(), 14);
+        assert_eq!(SCOPE_ELEMENTS.len(), 18);
+        assert_eq!(BUTTON_SCOPE_ELEMENTS.len(), 19);
+        assert_eq!(LIST_ITEM_SCOPE_ELEMENTS.len(), 20);
+        assert_eq!(IMPLIED_END_TAGS_ELEMENTS.len(), 10);
+        assert_eq!(THOROUGHLY_IMPLIED_END_TAGS_ELEMENTS.len(), 18);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_membership() {
+        // Les portées étendues contiennent toute la portée de base.
+        assert!(SCOPE_ELEMENTS.into_iter().all(|tag_name| {
+            BUTTON_SCOPE_ELEMENTS.contains(&tag_name)
+                && LIST_ITEM_SCOPE_ELEMENTS.contains(&tag_name)
+        }));
+        assert!(tag_names::button.is_one_of(BUTTON_SCOPE_ELEMENTS));
+        assert!(!tag_names::var.is_one_of(BUTTON_SCOPE_ELEMENTS));
+        assert!(tag_names::ul.is_one_of(LIST_ITEM_SCOPE_ELEMENTS));
+
+        // Les balises de fin implicites sont un sous-ensemble de la
+        // génération exhaustive.
+        assert!(IMPLIED_END_TAGS_ELEMENTS
+            .into_iter()
+            .all(|t| THOROUGHLY_IMPLIED_END_TAGS_ELEMENTS.contains(&t)));
+
+        assert!(is_special(tag_names::div, Namespace::HTML));
+        assert!(is_special(tag_names::title, Namespace::SVG));
+        assert!(!is_special(tag_names::div, Namespace::SVG));
+        assert!(!is_special(tag_names::span, Namespace::HTML));
+        assert!(is_special(tag_names::mtext, Namespace::MathML));
+
+        // Un élément de mise en forme n'est jamais spécial.
+        assert!(FORMATTING_ELEMENTS
+            .into_iter()
+            .all(|t| !is_special(t, Namespace::HTML)));
+    }
+}
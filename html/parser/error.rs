@@ -44,7 +44,10 @@ macro_rules! define_errors {
 /// Certaines erreurs d'analyse ont des codes spécifiques décrits dans le
 /// tableau ci-dessous, qui doivent être utilisés par les vérificateurs de
 /// conformité dans les rapports.
-pub(super) enum HTMLParserError {
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum HTMLParserError {
     $( $(#[$attr])* $enum ),*
 }
 
@@ -127,7 +130,13 @@ define_errors! {
     /// l'analyseur syntaxique traite le début d'une balise de début
     /// (c.-à-d. <) ou d'une balise de fin (c.-à-d. </) comme du contenu
     /// textuel.
-    EofBeforeTagName = "cdata-in-html-content",
+    EofBeforeTagName = "eof-before-tag-name",
+
+    /// Cette erreur se produit si l'analyseur syntaxique rencontre la fin
+    /// du flux d'entrée dans une section CDATA. L'analyseur traite de
+    /// telles sections CDATA comme si elles étaient fermées
+    /// immédiatement avant la fin du flux d'entrée.
+    EofInCdata = "eof-in-cdata",
 
     /// Cette erreur se produit si l'analyseur syntaxique rencontre la fin
     /// du flux d'entrée dans un commentaire. L'analyseur traite de tels
@@ -311,6 +320,51 @@ define_errors! {
     /// code "-->" et tout ce qui suit sera traité comme du balisage.
     NestedComment = "nested-comment",
 
+    /// Cette erreur se produit si l'analyseur rencontre une balise de
+    /// début d'un élément qui n'est ni un élément vide, ni un élément
+    /// étranger, et dont la balise se termine par un point de code
+    /// U+002F (/) (par exemple, `<div/>`). Dans ce cas, l'analyseur se
+    /// comporte comme si le point de code U+002F (/) était absent : la
+    /// balise ouvre l'élément.
+    NonVoidHTMLElementStartTagWithTrailingSolidus = "non-void-html-element-start-tag-with-trailing-solidus",
+
+    /// Cette erreur se produit si la pile des éléments ouverts dépasse
+    /// le nombre maximal d'éléments autorisé par l'analyseur (voir
+    /// [HTMLParser::max_open_elements](crate::HTMLParser::max_open_elements)).
+    /// Il ne s'agit pas d'une erreur de la spécification : l'analyse est
+    /// interrompue pour ne pas épuiser la pile d'appels.
+    MaxOpenElementsExceeded = "max-open-elements-exceeded",
+
+    /// Les erreurs d'analyse de l'étape de construction de l'arbre n'ont
+    /// pas de code dans la spécification. Celle-ci se produit si un
+    /// DOCTYPE apparaît là où il n'est pas permis (par exemple,
+    /// `<html><!DOCTYPE html>`). Le jeton est ignoré.
+    UnexpectedDOCTYPE = "unexpected-doctype",
+
+    /// Cette erreur se produit si une balise de début n'est pas permise
+    /// dans le mode d'insertion actuel (par exemple, `<head>` dans le
+    /// `<body>`).
+    UnexpectedStartTag = "unexpected-start-tag",
+
+    /// Cette erreur se produit si une balise de fin ne correspond à
+    /// aucun élément ouvert, ou ferme des éléments mal imbriqués (par
+    /// exemple, `<p></div>` ou `</br>`).
+    UnexpectedEndTag = "unexpected-end-tag",
+
+    /// Cette erreur se produit si un commentaire n'est pas permis dans
+    /// le mode d'insertion actuel.
+    UnexpectedComment = "unexpected-comment",
+
+    /// Cette erreur se produit si un caractère n'est pas permis dans le
+    /// mode d'insertion actuel (par exemple, du texte dans un
+    /// `<frameset>`).
+    UnexpectedCharacter = "unexpected-character",
+
+    /// Cette erreur se produit si la fin de l'entrée est atteinte alors
+    /// que des éléments restent ouverts là où la spécification
+    /// l'interdit (par exemple, `<template>` non fermé).
+    UnexpectedEof = "unexpected-eof",
+
     /// Cette erreur se produit si l'analyseur syntaxique rencontre une
     /// référence de caractère numérique qui fait référence à un point
     /// de code U+0000 NULL. L'analyseur syntaxique résout de telles
@@ -907,8 +961,6 @@ mod tests {
             "crashtests/tag/unexpected_question_mark_instead_of_tag_name.html"
         ));
 
-        html_tok.consume_next_token();
-
         // |- #comment: ?xml-stylesheet type="text/css" href="style.css"?
         assert_eq!(
             html_tok.consume_next_token(),
@@ -0,0 +1,143 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use dom::node::{DocumentNode, Node};
+use html_elements::{tag_names, HTMLScriptElement};
+use infra::structure::tree::TreeNode;
+
+use crate::{serialization::serialize_html_fragment, HTMLParser};
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Accès au balisage HTML des enfants d'un élément.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/dynamic-markup-insertion.html#dom-element-innerhtml>
+pub trait InnerHTMLInterface {
+    /// Retourne le résultat de l'algorithme de sérialisation des
+    /// fragments HTML sur l'élément.
+    fn inner_html(&self) -> String;
+
+    /// Analyse le balisage avec l'élément comme élément de contexte, puis
+    /// remplace tous les enfants de l'élément par les noeuds obtenus.
+    fn set_inner_html(&self, html: &str);
+}
+
+/// Point d'intégration d'un moteur de script externe : appelé de manière
+/// synchrone par l'analyseur dès qu'un élément script sans attribut src
+/// est complété, avec le texte de l'élément, avant de traiter le jeton
+/// suivant.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#scriptEndTag>
+pub trait ScriptExecutionInterface {
+    fn on_script(
+        &mut self,
+        script: &HTMLScriptElement<DocumentNode>,
+        text: &str,
+    );
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<F> ScriptExecutionInterface for F
+where
+    F: FnMut(&HTMLScriptElement<DocumentNode>, &str),
+{
+    fn on_script(
+        &mut self,
+        script: &HTMLScriptElement<DocumentNode>,
+        text: &str,
+    ) {
+        self(script, text)
+    }
+}
+
+impl InnerHTMLInterface for TreeNode<Node> {
+    fn inner_html(&self) -> String {
+        serialize_html_fragment(self)
+    }
+
+    fn set_inner_html(&self, html: &str) {
+        assert!(self.is_element(), "Élément attendu.");
+
+        // 1. Soit le fragment le résultat de l'invocation de l'algorithme
+        // d'analyse des fragments, avec l'élément comme élément de
+        // contexte et le balisage.
+        let fragment = HTMLParser::parse_fragment(self, html.chars());
+
+        // 2. Si l'élément est un élément template, alors le contexte est
+        // le contenu de l'élément template. Sinon, le contexte est
+        // l'élément.
+        let element = self.element_ref();
+        let context = if element.isin_html_namespace()
+            && tag_names::template == element.local_name()
+        {
+            self.iref()
+                .content()
+                .map(|content| TreeNode::clone(&content))
+                .unwrap_or_else(|| TreeNode::clone(self))
+        } else {
+            TreeNode::clone(self)
+        };
+
+        // 3. Remplacer tous les enfants du contexte par le fragment : les
+        // enfants existants sont détachés avant l'insertion.
+        while let Some(child) = context.get_first_child() {
+            child.detach_node();
+        }
+
+        while let Some(child) = fragment.get_first_child() {
+            child.detach_node();
+            context.append_child(child);
+        }
+    }
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use dom::node::DocumentNode;
+
+    use super::*;
+
+    #[test]
+    fn test_inner_html() {
+        let mut parser = HTMLParser::new(
+            DocumentNode::new(),
+            "<!DOCTYPE html><html><head></head><body>\
+             <div><p>a</p><!--b--></div></body></html>"
+                .chars(),
+        );
+        parser.run();
+
+        let html = parser
+            .tree_construction()
+            .document
+            .get_first_child()
+            .unwrap();
+        let body = html.get_last_child().unwrap();
+        let div = body.get_first_child().unwrap();
+        assert_eq!(div.inner_html(), "<p>a</p><!--b-->");
+
+        let p = div.get_first_child().unwrap();
+        div.set_inner_html("<b>x</b>y");
+        assert!(p.parent_node().is_none());
+        assert!(p.next_sibling().is_none());
+        assert_eq!(div.inner_html(), "<b>x</b>y");
+
+        let b = div.get_first_child().unwrap();
+        assert_eq!(tag_names::b, b.element_ref().local_name());
+        assert_eq!(b.parent_node().as_ref(), Some(&div));
+
+        div.set_inner_html("");
+        assert!(div.get_first_child().is_none());
+        assert_eq!(body.inner_html(), "<div></div>");
+    }
+}
@@ -5,16 +5,30 @@
 #![feature(type_name_of_val, option_result_contains)]
 
 mod codepoint;
+mod elements_categories;
 mod error;
+mod interface;
+mod serialization;
 mod state;
+mod streaming;
 mod tokenization;
 mod tree_construction;
 
 use std::{borrow::BorrowMut, ops::ControlFlow};
 
-use dom::node::DocumentNode;
-use infra::primitive::codepoint::CodePointIterator;
+use dom::node::{
+    DocumentFragmentNode, DocumentNode, Node, NodeTextContentInterface,
+};
+use infra::{
+    primitive::codepoint::CodePointIterator, structure::tree::TreeNode,
+};
 
+pub use self::{
+    error::HTMLParserError,
+    interface::{InnerHTMLInterface, ScriptExecutionInterface},
+    serialization::serialize_html_fragment,
+    streaming::HTMLStreamingInput,
+};
 use self::{
     state::{FramesetOkFlag, InsertionMode},
     tokenization::{HTMLToken, HTMLTokenizer},
@@ -27,6 +41,28 @@ use self::{
 
 pub struct HTMLParser<C> {
     tokenizer: HTMLTokenizer<C>,
+    script_hook: Option<Box<dyn ScriptExecutionInterface>>,
+    /// Le prochain jeton est ignoré s'il s'agit d'un caractère U+000A
+    /// LINE FEED (LF) (après les balises de début pre, listing et
+    /// textarea).
+    ignore_next_line_feed: bool,
+    /// Nombre maximal d'éléments de la pile des éléments ouverts.
+    /// Au-delà, l'analyse est interrompue (voir
+    /// [HTMLParser::max_open_elements]).
+    max_open_elements: usize,
+    /// L'analyse a été interrompue : plus aucun jeton n'est traité.
+    aborted: bool,
+    /// Compteurs de profilage (voir [HTMLParser::metrics]).
+    #[cfg(feature = "metrics")]
+    metrics: parser::ParserMetrics,
+}
+
+/// Rapport de conformité d'une analyse : le document obtenu et la liste
+/// des erreurs d'analyse rencontrées, avec leur position (en points de
+/// code) dans l'entrée.
+pub struct ParseReport {
+    pub document: DocumentNode,
+    pub errors: Vec<(HTMLParserError, usize)>,
 }
 
 pub enum HTMLParserFlag {
@@ -48,9 +84,81 @@ pub enum HTMLParserState {
 // -------------- //
 
 impl<C> HTMLParser<C> {
+    /// Profondeur d'imbrication maximale par défaut, comme dans les
+    /// navigateurs.
+    pub const DEFAULT_MAX_OPEN_ELEMENTS: usize = 512;
+
     pub fn new(document: DocumentNode, input: C) -> Self {
         let tokenizer = HTMLTokenizer::new(document, input);
-        Self { tokenizer }
+        Self {
+            tokenizer,
+            script_hook: None,
+            ignore_next_line_feed: false,
+            max_open_elements: Self::DEFAULT_MAX_OPEN_ELEMENTS,
+            aborted: false,
+            #[cfg(feature = "metrics")]
+            metrics: Default::default(),
+        }
+    }
+
+    /// Définit le hook appelé à chaque fois qu'un élément script sans
+    /// attribut src est complété (voir [ScriptExecutionInterface]).
+    pub fn on_script(
+        &mut self,
+        hook: impl ScriptExecutionInterface + 'static,
+    ) -> &mut Self {
+        self.script_hook.replace(Box::new(hook));
+        self
+    }
+
+    /// Active ou désactive le drapeau de scripting de l'analyseur (voir
+    /// [HTMLTreeConstruction::set_scripting]).
+    pub fn scripting(&mut self, enabled: bool) -> &mut Self {
+        self.tokenizer.tree_construction.set_scripting(enabled);
+        self
+    }
+
+    /// Définit le nombre maximal d'éléments de la pile des éléments
+    /// ouverts ([HTMLParser::DEFAULT_MAX_OPEN_ELEMENTS] par défaut).
+    ///
+    /// Lorsque cette limite est dépassée, l'erreur
+    /// [HTMLParserError::MaxOpenElementsExceeded] est signalée,
+    /// l'élément qui la dépasse est retiré de l'arbre et l'analyse
+    /// s'arrête : le reste de l'entrée est ignoré. Cela
+    /// protège des documents dont l'imbrication démesurée épuiserait la
+    /// pile d'appels.
+    pub fn max_open_elements(&mut self, limit: usize) -> &mut Self {
+        self.max_open_elements = limit;
+        self
+    }
+
+    /// Active ou désactive le mode de récupération (désactivé par
+    /// défaut, pour ne pas masquer les bugs) : un mode d'insertion non
+    /// implémenté traite ses jetons selon les règles du mode
+    /// d'insertion "in body", avec un avertissement loggé, au lieu de
+    /// paniquer. Cela permet d'analyser partiellement des documents
+    /// complexes sans crash (voir
+    /// [HTMLTreeConstruction::set_best_effort]).
+    pub fn best_effort(&mut self, enabled: bool) -> &mut Self {
+        self.tokenizer.tree_construction.set_best_effort(enabled);
+        self
+    }
+
+    /// Compteurs de profilage de l'instance : nombre de jetons émis par
+    /// le tokenizer, nombre de nœuds créés par la construction de
+    /// l'arbre et temps passé à analyser.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&mut self) -> &parser::ParserMetrics {
+        self.metrics.nodes +=
+            self.tokenizer.tree_construction.take_nodes_created();
+        &self.metrics
+    }
+
+    /// Remet les compteurs de profilage à zéro.
+    #[cfg(feature = "metrics")]
+    pub fn reset_metrics(&mut self) {
+        self.tokenizer.tree_construction.take_nodes_created();
+        self.metrics.reset();
     }
 }
 
@@ -58,71 +166,310 @@ impl<C> HTMLParser<C>
 where
     C: CodePointIterator,
 {
+    /// Lance l'analyseur en mode permissif : les erreurs d'analyse sont
+    /// collectées (voir [HTMLParser::take_parse_errors]) sans
+    /// interrompre l'analyse.
     pub fn run(&mut self) {
+        // NOTE(phisyx): en mode permissif, aucune erreur n'est renvoyée.
+        _ = self.process(false);
+    }
+
+    /// Lance l'analyseur en mode strict : l'analyse s'arrête sur la
+    /// première erreur d'analyse rencontrée, qui est renvoyée avec sa
+    /// position dans l'entrée.
+    pub fn run_strict(&mut self) -> Result<(), (HTMLParserError, usize)> {
+        self.process(true)
+    }
+
+    fn process(
+        &mut self,
+        strict: bool,
+    ) -> Result<(), (HTMLParserError, usize)> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let result = self.process_tokens(strict);
+
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.elapsed += start.elapsed();
+        }
+
+        result
+    }
+
+    fn process_tokens(
+        &mut self,
+        strict: bool,
+    ) -> Result<(), (HTMLParserError, usize)> {
         loop {
+            self.execute_pending_script();
+
             let token = self.tokenizer.consume_next_token();
 
+            // NOTE(phisyx): en mode strict, le jeton ayant provoqué
+            // une erreur du tokenizer n'est pas traité. Une erreur de
+            // l'étape de construction de l'arbre est signalée après le
+            // traitement de son jeton : l'analyse s'arrête avant le
+            // jeton suivant.
+            if strict {
+                self.first_parse_error()?;
+            }
+
+            if self.process_token(token).is_break() {
+                break;
+            }
+        }
+
+        self.execute_pending_script();
+
+        if strict {
+            self.first_parse_error()?;
+        }
+
+        Ok(())
+    }
+
+    /// Exécute, via le hook d'exécution de script, l'élément script
+    /// complété en attente, s'il y en a un. Le texte du script est la
+    /// concaténation des données de ses noeuds Text enfants.
+    fn execute_pending_script(&mut self) {
+        let script = match self
+            .tokenizer
+            .tree_construction
+            .take_pending_parsing_blocking_script()
+        {
+            | Some(script) => script,
+            | None => return,
+        };
+
+        let hook = match self.script_hook.as_mut() {
+            | Some(hook) => hook,
+            | None => return,
+        };
+
+        let text = script.child_text_content();
+        hook.on_script(script.script_ref(), &text);
+    }
+
+    /// Traite un jeton émis par le tokenizer selon l'état retourné par la
+    /// construction de l'arbre.
+    fn process_token(
+        &mut self,
+        token: Option<HTMLToken>,
+    ) -> ControlFlow<()> {
+        if self.aborted {
+            return ControlFlow::Break(());
+        }
+
+        #[cfg(feature = "metrics")]
+        if token.is_some() {
+            self.metrics.tokens += 1;
+        }
+
+        if std::mem::take(&mut self.ignore_next_line_feed)
+            && matches!(token, Some(HTMLToken::Character('\n')))
+        {
+            return ControlFlow::Continue(());
+        }
+
+        let control_flow =
+            self.tokenizer.tree_construction.dispatcher(token);
+
+        let errors = self.tokenizer.tree_construction.take_parse_errors();
+        errors.into_iter().for_each(|error| {
+            self.tokenizer.report_parse_error(error);
+        });
+
+        if self
+            .tokenizer
+            .tree_construction
+            .take_unacknowledged_self_closing_flag()
+        {
+            self.tokenizer.report_parse_error(
+                HTMLParserError::NonVoidHTMLElementStartTagWithTrailingSolidus,
+            );
+        }
+
+        if self.tokenizer.tree_construction.open_elements_count()
+            > self.max_open_elements
+        {
+            self.tokenizer
+                .tree_construction
+                .truncate_open_elements(self.max_open_elements);
+            self.tokenizer.report_parse_error(
+                HTMLParserError::MaxOpenElementsExceeded,
+            );
+            self.aborted = true;
+            return ControlFlow::Break(());
+        }
+
+        // TODO(phisyx): à améliorer ASAP.
+        match control_flow {
+            | ControlFlow::Continue(HTMLParserState::SwitchTo(state)) => {
+                self.tokenizer.switch_state_to(state);
+            }
+
+            | ControlFlow::Continue(
+                HTMLParserState::ProcessNextTokenExceptLF,
+            ) => {
+                self.ignore_next_line_feed = true;
+            }
+
             // TODO(phisyx): à améliorer ASAP.
-            match self.tokenizer.tree_construction.dispatcher(token) {
-                | ControlFlow::Continue(HTMLParserState::SwitchTo(
-                    state,
-                )) => {
-                    self.tokenizer.switch_state_to(state);
-                    continue;
-                }
-                | ControlFlow::Continue(
-                    HTMLParserState::ProcessNextTokenExceptLF,
-                ) => {
-                    let next = self.tokenizer.consume_next_token();
-                    match next {
-                        | Some(HTMLToken::Character('\n')) => continue,
-                        | None => continue,
-                        | _ => {
-                            self.tokenizer
-                                .tree_construction
-                                .dispatcher(next);
-                            continue;
-                        }
-                    }
-                }
-
-                // TODO(phisyx): à améliorer ASAP.
-                | ControlFlow::Continue(HTMLParserState::CustomRcdata) => {
-                    if let Some(HTMLToken::Character('\n')) =
-                        self.tokenizer.consume_next_token()
-                    {
-                        self.tokenizer.next();
-                    }
-
-                    self.tokenizer.switch_state_to("rcdata");
-                    self.tokenizer
-                        .tree_construction
-                        .original_insertion_mode
-                        .switch_to(
-                            self.tokenizer
-                                .tree_construction
-                                .insertion_mode,
-                        );
-                    self.tokenizer.tree_construction.frameset_ok_flag =
-                        FramesetOkFlag::NotOk;
-                    self.tokenizer
-                        .tree_construction
-                        .insertion_mode
-                        .switch_to(InsertionMode::Text);
-                    continue;
-                }
-
-                | ControlFlow::Continue(_) => {
-                    continue;
-                }
-
-                | ControlFlow::Break(HTMLParserFlag::Pause) => break, /* Voir TODO ci-haut */
-                | ControlFlow::Break(HTMLParserFlag::Stop) => break, /* Voir TODO ci-haut */
+            | ControlFlow::Continue(HTMLParserState::CustomRcdata) => {
+                self.ignore_next_line_feed = true;
+                self.tokenizer.switch_state_to("rcdata");
+
+                let tree_construction =
+                    &mut self.tokenizer.tree_construction;
+                tree_construction
+                    .original_insertion_mode
+                    .switch_to(tree_construction.insertion_mode);
+                tree_construction.frameset_ok_flag = FramesetOkFlag::NotOk;
+                tree_construction
+                    .insertion_mode
+                    .switch_to(InsertionMode::Text);
             }
+
+            | ControlFlow::Continue(_) => {}
+
+            | ControlFlow::Break(HTMLParserFlag::Pause) => {
+                return ControlFlow::Break(())
+            }
+            | ControlFlow::Break(HTMLParserFlag::Stop) => {
+                return ControlFlow::Break(())
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Renvoie la première erreur d'analyse collectée, s'il y en a une.
+    fn first_parse_error(
+        &mut self,
+    ) -> Result<(), (HTMLParserError, usize)> {
+        match self.tokenizer.take_parse_errors().into_iter().next() {
+            | Some(error) => Err(error),
+            | None => Ok(()),
         }
     }
 
+    /// Algorithme d'analyse des fragments HTML : analyse l'entrée comme
+    /// le contenu de l'élément de contexte et retourne les noeuds obtenus
+    /// dans un [DocumentFragmentNode].
+    ///
+    /// Voir <https://html.spec.whatwg.org/multipage/parsing.html#parsing-html-fragments>
+    pub fn parse_fragment(
+        context_element: &TreeNode<Node>,
+        input: C,
+    ) -> DocumentFragmentNode {
+        // 1. Créer un nouveau noeud Document, et le marquer comme étant
+        // un document HTML.
+        // 3. Créer un nouvel analyseur HTML, et l'associer au noeud
+        // Document qui vient d'être créé.
+        let mut parser = Self::new(DocumentNode::new(), input);
+
+        // 4. Définir l'état du tokenizer en fonction de l'élément de
+        // contexte.
+        // 5. à 11. Voir HTMLTreeConstruction::prepare_fragment_parsing.
+        let state = parser
+            .tree_construction()
+            .fragment_tokenizer_state(context_element);
+        parser.tokenizer.switch_state_to(state);
+
+        let root = parser
+            .tree_construction()
+            .prepare_fragment_parsing(context_element);
+
+        // 12. Placer l'entrée dans le flux d'entrée de l'analyseur HTML
+        // qui vient d'être créé, et lancer l'analyseur.
+        parser.run();
+
+        // 13. Retourner les noeuds enfants de root, dans l'ordre de
+        // l'arbre.
+        let fragment = DocumentFragmentNode::default();
+        while let Some(child) = root.get_first_child() {
+            child.detach_node();
+            fragment.append_child(child);
+        }
+        fragment
+    }
+
+    /// Retire et retourne les erreurs d'analyse rencontrées jusqu'à
+    /// présent, avec leur position dans l'entrée.
+    pub fn take_parse_errors(&mut self) -> Vec<(HTMLParserError, usize)> {
+        self.tokenizer.take_parse_errors()
+    }
+
     pub fn tree_construction(&mut self) -> &mut HTMLTreeConstruction {
         self.tokenizer.tree_construction.borrow_mut()
     }
 }
+
+impl HTMLParser<HTMLStreamingInput> {
+    /// Nombre de caractères que le tokenizer doit pouvoir lire au-delà
+    /// de la position courante avant d'exécuter un état : les états qui
+    /// examinent les caractères suivants (références de caractères
+    /// nommées, `DOCTYPE`, `[CDATA[`, ...) ne doivent pas atteindre une
+    /// fin provisoire de l'entrée.
+    const STREAMING_LOOKAHEAD: usize = 64;
+
+    /// Crée un analyseur en continu : l'entrée est fournie morceau par
+    /// morceau avec [HTMLParser::feed], puis terminée avec
+    /// [HTMLParser::end].
+    pub fn streaming(document: DocumentNode) -> Self {
+        Self::new(document, HTMLStreamingInput::default())
+    }
+
+    /// Pousse un morceau de caractères dans le flux d'entrée et analyse
+    /// ce qui peut l'être. L'état du tokenizer et de la construction de
+    /// l'arbre est conservé entre les appels.
+    pub fn feed(&mut self, chunk: &str) {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let input = self.tokenizer.chars().clone();
+        input.push(chunk);
+
+        loop {
+            self.execute_pending_script();
+
+            let token = match self
+                .tokenizer
+                .next_token_if(|| input.len() > Self::STREAMING_LOOKAHEAD)
+            {
+                | Some(token) => token,
+                | None => break,
+            };
+
+            if self.process_token(token).is_break() {
+                break;
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.elapsed += start.elapsed();
+        }
+    }
+
+    /// Marque la fin du flux d'entrée et termine l'analyse.
+    pub fn end(&mut self) {
+        self.run();
+    }
+}
+
+impl<'a> HTMLParser<std::str::Chars<'a>> {
+    /// Analyse un document HTML et retourne un [rapport de
+    /// conformité](ParseReport), exploitable par les suites de tests
+    /// (html5lib-tests, ...).
+    pub fn parse_with_report(html: &'a str) -> ParseReport {
+        let mut parser = Self::new(DocumentNode::new(), html.chars());
+        parser.run();
+        ParseReport {
+            document: parser.tree_construction().document.clone(),
+            errors: parser.take_parse_errors(),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use dom::node::{Attr, Node};
+use html_elements::{interface::IsOneOfTagsInterface, tag_names};
+use infra::{namespace::Namespace, structure::tree::TreeNode};
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Algorithme de sérialisation des fragments HTML : retourne une chaîne
+/// de caractères représentant les enfants du noeud.
+///
+/// Voir <https://html.spec.whatwg.org/multipage/parsing.html#serialising-html-fragments>
+pub fn serialize_html_fragment(node: &TreeNode<Node>) -> String {
+    // 1. Si le noeud se sérialise comme vide, retourner une chaîne de
+    // caractères vide.
+    if serializes_as_void(node) {
+        return String::new();
+    }
+
+    // 2. Soit s une chaîne de caractères vide.
+    let mut s = String::new();
+
+    // 3. Si le noeud est un élément template, alors le noeud est le
+    // contenu de l'élément template.
+    let template_content: Option<TreeNode<Node>> = if node.is_element()
+        && node.element_ref().isin_html_namespace()
+        && tag_names::template == node.element_ref().local_name()
+    {
+        node.iref()
+            .content()
+            .map(|content| TreeNode::clone(&content))
+    } else {
+        None
+    };
+    let node = template_content.as_ref().unwrap_or(node);
+
+    // 4. Pour chaque noeud enfant du noeud, dans l'ordre de l'arbre,
+    // exécuter les étapes suivantes :
+    let mut maybe_child = node.get_first_child();
+    while let Some(current_node) = maybe_child {
+        serialize_child(&mut s, node, &current_node);
+        maybe_child = current_node.next_sibling();
+    }
+
+    // 5. Retourner s.
+    s
+}
+
+#[allow(deprecated)]
+fn serialize_child(
+    s: &mut String,
+    parent: &TreeNode<Node>,
+    current_node: &TreeNode<Node>,
+) {
+    // Si le noeud actuel est un élément
+    //
+    // Si le noeud actuel est un élément de l'espace de noms HTML,
+    // de l'espace de noms MathML ou de l'espace de noms SVG, alors
+    // le nom de la balise est le nom local du noeud actuel. Sinon, le
+    // nom de la balise est le nom qualifié du noeud actuel.
+    // Ajouter un caractère U+003C LESS-THAN SIGN (<), suivi du nom de
+    // la balise.
+    // Pour chaque attribut de l'élément, ajouter un caractère U+0020
+    // SPACE, le nom sérialisé de l'attribut, un caractère U+003D
+    // EQUALS SIGN (=), un caractère U+0022 QUOTATION MARK ("), la
+    // valeur de l'attribut, échappée, et un second caractère U+0022
+    // QUOTATION MARK (").
+    // Ajouter un caractère U+003E GREATER-THAN SIGN (>).
+    // Si le noeud actuel se sérialise comme vide, passer au noeud
+    // enfant suivant.
+    // Ajouter la valeur de l'exécution de l'algorithme de
+    // sérialisation des fragments HTML sur le noeud actuel, suivi
+    // d'un caractère U+003C LESS-THAN SIGN, d'un caractère U+002F
+    // SOLIDUS (/), du nom de la balise et d'un caractère U+003E
+    // GREATER-THAN SIGN (>).
+    if current_node.is_element() {
+        let tag_name = current_node.element_ref().tag_name_string();
+
+        s.push('<');
+        s.push_str(&tag_name);
+        current_node.attributes().iter().for_each(|attr| {
+            s.push(' ');
+            s.push_str(&serialized_attribute_name(attr));
+            s.push_str("=\"");
+            s.push_str(&escape_string(&attr.value(), true));
+            s.push('"');
+        });
+        s.push('>');
+
+        if serializes_as_void(current_node) {
+            return;
+        }
+
+        s.push_str(&serialize_html_fragment(current_node));
+        s.push_str("</");
+        s.push_str(&tag_name);
+        s.push('>');
+    }
+    // Si le noeud actuel est un noeud Text
+    //
+    // Si le parent du noeud actuel est un élément style, script, xmp,
+    // iframe, noembed, noframes ou plaintext, ajouter la valeur de
+    // l'attribut data du noeud actuel, littéralement.
+    // Sinon, ajouter la valeur de l'attribut data du noeud actuel,
+    // échappée.
+    else if current_node.is_text() {
+        let data = current_node.character_data_ref().data();
+        if parent.is_element()
+            && parent.element_ref().local_name().is_one_of([
+                tag_names::style,
+                tag_names::script,
+                tag_names::xmp,
+                tag_names::iframe,
+                tag_names::noembed,
+                tag_names::noframes,
+                tag_names::plaintext,
+            ])
+        {
+            s.push_str(&data);
+        } else {
+            s.push_str(&escape_string(&data, false));
+        }
+    }
+    // Si le noeud actuel est un noeud Comment
+    //
+    // Ajouter la chaîne littérale "<!--", suivie de la valeur de
+    // l'attribut data du noeud actuel, suivie de la chaîne littérale
+    // "-->".
+    else if current_node.is_comment() {
+        s.push_str("<!--");
+        s.push_str(&current_node.character_data_ref().data());
+        s.push_str("-->");
+    }
+    // Si le noeud actuel est un DocumentType
+    //
+    // Ajouter la chaîne littérale "<!DOCTYPE", suivie d'un espace,
+    // suivie de la valeur de l'attribut name du noeud actuel, suivie
+    // de la chaîne littérale ">".
+    else if current_node.is_doctype() {
+        s.push_str("<!DOCTYPE ");
+        s.push_str(&current_node.doctype_ref().name.borrow());
+        s.push('>');
+    }
+}
+
+/// Un élément se sérialise comme vide si son nom de balise est celui
+/// d'un élément vide (void) ou est "basefont", "bgsound", "frame",
+/// "keygen" ou "param".
+#[allow(deprecated)]
+fn serializes_as_void(node: &TreeNode<Node>) -> bool {
+    node.is_element()
+        && node.element_ref().isin_html_namespace()
+        && node.element_ref().local_name().is_one_of([
+            tag_names::area,
+            tag_names::base,
+            tag_names::basefont,
+            tag_names::bgsound,
+            tag_names::br,
+            tag_names::col,
+            tag_names::embed,
+            tag_names::frame,
+            tag_names::hr,
+            tag_names::img,
+            tag_names::input,
+            tag_names::keygen,
+            tag_names::link,
+            tag_names::meta,
+            tag_names::param,
+            tag_names::source,
+            tag_names::track,
+            tag_names::wbr,
+        ])
+}
+
+/// Le nom sérialisé d'un attribut est déterminé par son espace de noms :
+///   - Aucun espace de noms : le nom local de l'attribut.
+///   - Espace de noms XML : la chaîne "xml:", suivie du nom local.
+///   - Espace de noms XMLNS, dont le nom local est "xmlns" : la chaîne
+///     "xmlns".
+///   - Espace de noms XMLNS, dont le nom local n'est pas "xmlns" : la
+///     chaîne "xmlns:", suivie du nom local.
+///   - Espace de noms XLink : la chaîne "xlink:", suivie du nom local.
+///   - Dans tous les autres cas : le nom qualifié de l'attribut.
+fn serialized_attribute_name(attr: &Attr) -> String {
+    let local_name = attr.local_name();
+    match attr.namespace_uri() {
+        | None => local_name,
+        | Some(Namespace::XML) => format!("xml:{local_name}"),
+        | Some(Namespace::XMLNS) if local_name == "xmlns" => local_name,
+        | Some(Namespace::XMLNS) => format!("xmlns:{local_name}"),
+        | Some(Namespace::XLink) => format!("xlink:{local_name}"),
+        | Some(_) => attr.name(),
+    }
+}
+
+/// Échapper une chaîne de caractères signifie exécuter les étapes
+/// suivantes :
+///   1. Remplacer toute occurrence du caractère "&" par la chaîne "&amp;".
+///   2. Remplacer toute occurrence du caractère U+00A0 NO-BREAK SPACE par
+///      la chaîne "&nbsp;".
+///   3. Si l'algorithme a été invoqué en mode attribut, remplacer toute
+///      occurrence du caractère `"` par la chaîne "&quot;".
+///   4. Si l'algorithme n'a pas été invoqué en mode attribut, remplacer
+///      toute occurrence du caractère "<" par la chaîne "&lt;", et toute
+///      occurrence du caractère ">" par la chaîne "&gt;".
+fn escape_string(input: &str, attribute_mode: bool) -> String {
+    let mut output = String::with_capacity(input.len());
+    input.chars().for_each(|ch| match ch {
+        | '&' => output.push_str("&amp;"),
+        | '\u{00A0}' => output.push_str("&nbsp;"),
+        | '"' if attribute_mode => output.push_str("&quot;"),
+        | '<' if !attribute_mode => output.push_str("&lt;"),
+        | '>' if !attribute_mode => output.push_str("&gt;"),
+        | _ => output.push(ch),
+    });
+    output
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use dom::node::DocumentNode;
+
+    use super::*;
+    use crate::HTMLParser;
+
+    fn parse_body(input: &'static str) -> TreeNode<Node> {
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), input.chars());
+        parser.run();
+        let html = parser
+            .tree_construction()
+            .document
+            .get_first_child()
+            .unwrap();
+        html.get_last_child().unwrap()
+    }
+
+    #[test]
+    fn test_serialize_namespaced_attributes() {
+        let body = parse_body(
+            "<!DOCTYPE html><html><head></head><body>\
+             <svg xmlns:xlink=\"http://www.w3.org/1999/xlink\">\
+             <use xlink:href=\"#x\" x=\"1\"></use></svg></body></html>",
+        );
+
+        let svg = body.get_first_child().unwrap();
+        let use_el = svg.get_first_child().unwrap();
+        let attrs = use_el.attributes();
+        assert_eq!(attrs[0].namespace_uri(), Some(Namespace::XLink));
+        assert_eq!(attrs[0].prefix().as_deref(), Some("xlink"));
+        assert_eq!(attrs[0].local_name(), "href");
+        assert_eq!(attrs[0].name(), "xlink:href");
+        assert_eq!(attrs[1].namespace_uri(), None);
+
+        assert_eq!(
+            serialize_html_fragment(&body),
+            "<svg xmlns:xlink=\"http://www.w3.org/1999/xlink\">\
+             <use xlink:href=\"#x\" x=\"1\"></use></svg>"
+        );
+    }
+
+    #[test]
+    fn test_serialize_escaped_attribute_value() {
+        let body = parse_body(
+            "<!DOCTYPE html><html><head></head><body>\
+             <div title='a\" & b'><hr></div></body></html>",
+        );
+        assert_eq!(
+            serialize_html_fragment(&body),
+            "<div title=\"a&quot; &amp; b\"><hr></div>"
+        );
+    }
+}
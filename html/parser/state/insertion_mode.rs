@@ -51,4 +51,15 @@ impl InsertionMode {
     pub(crate) fn switch_to(&mut self, mode: Self) {
         *self = mode;
     }
+
+    /// Les règles du mode d'insertion sont-elles implémentées ? Les
+    /// jetons d'un mode d'insertion non implémenté sont traités selon
+    /// les règles du mode d'insertion "in body" en mode de
+    /// récupération.
+    ///
+    /// NOTE(phisyx): tous les modes d'insertion le sont à ce jour ; un
+    /// mode d'insertion en cours d'implémentation doit être exclu ici.
+    pub(crate) const fn is_implemented(self) -> bool {
+        true
+    }
 }
@@ -8,6 +8,8 @@ use dom::node::Node;
 use html_elements::tag_names;
 use infra::structure::tree::TreeNode;
 
+use crate::elements_categories::BUTTON_SCOPE_ELEMENTS;
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -23,47 +25,6 @@ pub(crate) struct StackOfOpenElements {
 // -------------- //
 
 impl StackOfOpenElements {
-    ///   - applet
-    ///   - caption
-    ///   - html
-    ///   - table
-    ///   - td
-    ///   - th
-    ///   - marquee
-    ///   - object
-    ///   - template
-    ///   - MathML mi
-    ///   - MathML mo
-    ///   - MathML mn
-    ///   - MathML ms
-    ///   - MathML mtext
-    ///   - MathML annotation-xml
-    ///   - SVG foreignObject
-    ///   - SVG desc
-    ///   - SVG title
-    pub(crate) const SCOPE_ELEMENTS: [tag_names; 18] = [
-        #[allow(deprecated)]
-        tag_names::applet,
-        tag_names::caption,
-        tag_names::html,
-        tag_names::table,
-        tag_names::td,
-        tag_names::th,
-        #[allow(deprecated)]
-        tag_names::marquee,
-        tag_names::object,
-        tag_names::template,
-        tag_names::mi,
-        tag_names::mo,
-        tag_names::mn,
-        tag_names::ms,
-        tag_names::mtext,
-        tag_names::annotationXml,
-        tag_names::foreignObject,
-        tag_names::desc,
-        tag_names::title,
-    ];
-
     /// Le nœud actuel est le nœud le plus bas de cette pile d'éléments
     /// ouverts.
     pub(crate) fn current_node(&self) -> Option<&TreeNode<Node>> {
@@ -84,7 +45,7 @@ impl StackOfOpenElements {
     /// On dit que la pile d'éléments ouverts a un élément particulier dans
     /// son champ d'application lorsqu'elle a cet élément dans le champ
     /// d'application spécifique composé des types d'éléments suivants :
-    /// Voir la constante: [Self::SCOPE_ELEMENTS].
+    /// Voir la constante: [crate::elements_categories::SCOPE_ELEMENTS].
     pub(crate) fn has_element_in_scope<const N: usize>(
         &self,
         tag_name: tag_names,
@@ -93,17 +54,37 @@ impl StackOfOpenElements {
         self.has_elements_in_scope([tag_name], list)
     }
 
+    /// La pile d'éléments ouverts a un élément particulier dans la portée
+    /// du bouton lorsqu'elle a cet élément dans le champ d'application
+    /// spécifique composé des types d'éléments de
+    /// [BUTTON_SCOPE_ELEMENTS].
+    pub(crate) fn has_element_in_button_scope(
+        &self,
+        tag_name: tag_names,
+    ) -> bool {
+        self.has_element_in_scope(tag_name, BUTTON_SCOPE_ELEMENTS)
+    }
+
     pub(crate) fn has_elements_in_scope<const N: usize>(
         &self,
         tag_names_list: impl IntoIterator<Item = tag_names> + Copy,
         list: [tag_names; N],
     ) -> bool {
-        self.elements.iter().rev().any(|node| {
+        for node in self.elements.iter().rev() {
             let element = node.element_ref();
             let name = element.local_name();
-            tag_names_list.into_iter().any(|tag_name| tag_name == name)
-                || !list.into_iter().any(|tag_name| tag_name == name)
-        })
+
+            if tag_names_list.into_iter().any(|tag_name| tag_name == name)
+            {
+                return true;
+            }
+
+            if list.into_iter().any(|tag_name| tag_name == name) {
+                return false;
+            }
+        }
+
+        false
     }
 
     pub(crate) fn has_element_in_scope_except<const N: usize>(
@@ -119,14 +100,23 @@ impl StackOfOpenElements {
         tag_names_list: impl IntoIterator<Item = tag_names> + Copy,
         except_list: [tag_names; N],
     ) -> bool {
-        self.elements.iter().rev().any(|node| {
+        for node in self.elements.iter().rev() {
             let element = node.element_ref();
             let name = element.local_name();
-            tag_names_list.into_iter().any(|tag_name| tag_name == name)
-                || !except_list
-                    .into_iter()
-                    .any(|tag_name| tag_name != name)
-        })
+
+            if tag_names_list.into_iter().any(|tag_name| tag_name == name)
+            {
+                return true;
+            }
+
+            // NOTE(html): tous les éléments, à l'exception de ceux de la
+            // liste, délimitent la portée.
+            if !except_list.into_iter().any(|tag_name| tag_name == name) {
+                return false;
+            }
+        }
+
+        false
     }
 
     pub(crate) fn has_element_with_tag_name(
@@ -187,38 +177,6 @@ impl StackOfOpenElements {
     pub(crate) fn put(&mut self, element: TreeNode<Node>) {
         self.elements.push(element);
     }
-
-    pub(crate) fn button_scope_elements() -> [tag_names; 19] {
-        Self::scoped_elements_with::<19>([tag_names::button])
-    }
-
-    pub(crate) fn select_scope_elements() -> [tag_names; 2] {
-        [tag_names::optgroup, tag_names::option]
-    }
-
-    pub(crate) fn table_scope_elements() -> [tag_names; 3] {
-        [tag_names::html, tag_names::table, tag_names::template]
-    }
-
-    /// <https://github.com/rust-lang/rust/issues/83701>
-    fn scoped_elements_with<const N: usize>(
-        list: impl IntoIterator<Item = tag_names>,
-    ) -> [tag_names; N] {
-        let mut elements: [tag_names; N] = [tag_names::var; N];
-
-        Self::SCOPE_ELEMENTS
-            .into_iter()
-            .enumerate()
-            .for_each(|(i, t)| {
-                elements[i] = t;
-            });
-
-        list.into_iter().enumerate().for_each(|(i, t)| {
-            elements[i] = t;
-        });
-
-        elements
-    }
 }
 
 // -------------- //
@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use infra::primitive::codepoint::{CodePoint, CodePointIterator};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Entrée de l'analyse en continu : les morceaux de caractères poussés
+/// par [HTMLParser::feed](crate::HTMLParser::feed) sont placés dans un
+/// tampon partagé avec le flux d'entrée du tokenizer.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct HTMLStreamingInput {
+    buffer: Rc<RefCell<VecDeque<CodePoint>>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl HTMLStreamingInput {
+    /// Ajoute un morceau de caractères à la fin du tampon.
+    pub(crate) fn push(&self, chunk: &str) {
+        self.buffer.borrow_mut().extend(chunk.chars());
+    }
+
+    /// Nombre de caractères du tampon qui n'ont pas encore été lus par le
+    /// flux d'entrée du tokenizer.
+    pub(crate) fn len(&self) -> usize {
+        self.buffer.borrow().len()
+    }
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Iterator for HTMLStreamingInput {
+    type Item = CodePoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.borrow_mut().pop_front()
+    }
+}
+
+impl CodePointIterator for HTMLStreamingInput {}
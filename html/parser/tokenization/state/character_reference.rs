@@ -3,9 +3,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 use infra::{
-    primitive::codepoint::{
-        CodePoint, CodePointInterface, CodePointIterator,
-    },
+    primitive::codepoint::{CodePoint, CodePointIterator},
     structure::lists::peekable::PeekableInterface,
 };
 use parser::StreamInputIterator;
@@ -17,6 +15,11 @@ use crate::tokenization::{
     HTMLToken, HTMLTokenizer,
 };
 
+/// Longueur du plus long nom de la table des références de caractères
+/// nommées (`CounterClockwiseContourIntegral;`), sans le caractère U+0026
+/// AMPERSAND (&).
+const LONGEST_NAMED_CHARACTER_REFERENCE_LENGTH: usize = 32;
+
 impl<C> HTMLTokenizer<C>
 where
     C: CodePointIterator,
@@ -62,30 +65,23 @@ where
     pub(crate) fn handle_named_character_reference_state(
         &mut self,
     ) -> HTMLTokenizerProcessResult {
-        let ch = self
+        // NOTE(phisyx): le caractère actuel a été re-consommé, il se
+        // trouve donc en tête du flux d'entrée. Les noms de la table
+        // commencent par le caractère U+0026 AMPERSAND (&).
+        let next_chars: String = self
             .input
-            .current_input()
-            .cloned()
-            .expect("le caractère actuel");
-        let rest_of_chars: String = self.input.peek_until_end();
-        let full_str = format!("{ch}{rest_of_chars}");
+            .peek_until(LONGEST_NAMED_CHARACTER_REFERENCE_LENGTH)
+            .unwrap_or_default();
 
         let entities = &self.named_character_reference_code;
 
-        let (maybe_result, max_size) = entities.iter().fold(
-            (None, 0),
-            |(mut maybe_result, mut max_size), item| {
-                let name = item.0;
-                let size_name = name.len();
-
-                if full_str.starts_with(name) && size_name > max_size {
-                    max_size = size_name;
-                    maybe_result = Some(item);
-                }
-
-                (maybe_result, max_size)
-            },
-        );
+        let maybe_result = entities
+            .iter()
+            .filter_map(|(name, entity)| {
+                let name = name.strip_prefix('&')?;
+                next_chars.starts_with(name).then_some((name, entity))
+            })
+            .max_by_key(|(name, _)| name.len());
 
         match maybe_result {
             | Some((entity_name, entity)) => {
@@ -95,26 +91,42 @@ where
                     self.temporary_buffer.push(ch);
                 });
 
-                let mut maybe_err = None;
-                if ch != ';' {
-                    maybe_err =
-                        "missing-semicolon-after-character-reference"
-                            .into();
-
-                    if let Some(ch) = full_str.chars().nth(max_size - 1) {
-                        if (ch == '=' || ch.is_ascii_alphanumeric())
-                            && self.state.is_character_of_attribute()
-                        {
-                            {
-                                return self
-                                    .flush_temporary_buffer()
-                                    .switch_state_to("return-state")
-                                    .and_continue();
-                            }
+                let last_character_matched_is_semicolon =
+                    entity_name.ends_with(';');
+
+                // Si la référence de caractère a été consommée dans le
+                // cadre d'un attribut, que le dernier caractère
+                // correspondant n'est pas un caractère U+003B SEMICOLON
+                // (;) et que le caractère suivant est soit un caractère
+                // U+003D EQUALS SIGN (=), soit un caractère alphanumérique
+                // ASCII, alors, pour des raisons historiques, vider les
+                // points de code consommés comme référence de caractère
+                // et passer à l'état de retour.
+                if !last_character_matched_is_semicolon
+                    && self.state.is_character_of_attribute()
+                {
+                    let next_char =
+                        next_chars[entity_name.len()..].chars().next();
+                    if let Some(ch) = next_char {
+                        if ch == '=' || ch.is_ascii_alphanumeric() {
+                            return self
+                                .flush_temporary_buffer()
+                                .switch_state_to("return-state")
+                                .and_continue();
                         }
                     }
                 }
 
+                // Sinon :
+                //   1. Si le dernier caractère correspondant n'est pas un
+                //      caractère U+003B SEMICOLON (;), il s'agit d'une
+                //      erreur d'analyse de type
+                //      `missing-semicolon-after-character-reference`.
+                //   2. Définir le tampon temporaire à la chaîne vide.
+                //      Ajouter un ou deux caractères correspondant au nom
+                //      de la référence de caractère au tampon temporaire.
+                //   3. Vider les points de code consommés comme référence
+                //      de caractère. Passer à l'état de retour.
                 self.temporary_buffer.clear();
 
                 entity.codepoints.iter().for_each(|&cp| {
@@ -125,10 +137,12 @@ where
 
                 self.flush_temporary_buffer()
                     .switch_state_to("return-state");
-                if let Some(err) = maybe_err {
-                    self.and_continue_with_error(err)
-                } else {
+                if last_character_matched_is_semicolon {
                     self.and_continue()
+                } else {
+                    self.and_continue_with_error(
+                        "missing-semicolon-after-character-reference",
+                    )
                 }
             }
             | None => self
@@ -266,9 +280,10 @@ where
             // (soustraire 0x0030 du point de code du caractère) au code de
             // référence du caractère.
             | Some(ch) if ch.is_ascii_digit() => {
-                self.character_reference_code *= 16;
-                self.character_reference_code +=
-                    ((ch as u8) - 0x0030) as u32;
+                self.character_reference_code = self
+                    .character_reference_code
+                    .saturating_mul(16)
+                    .saturating_add(((ch as u8) - 0x0030) as u32);
                 self.and_continue()
             }
 
@@ -281,9 +296,10 @@ where
             | Some(ch)
                 if ch.is_ascii_hexdigit() && ch.is_ascii_uppercase() =>
             {
-                self.character_reference_code *= 16;
-                self.character_reference_code +=
-                    ((ch as u8) - 0x0037) as u32;
+                self.character_reference_code = self
+                    .character_reference_code
+                    .saturating_mul(16)
+                    .saturating_add(((ch as u8) - 0x0037) as u32);
                 self.and_continue()
             }
 
@@ -296,9 +312,10 @@ where
             | Some(ch)
                 if ch.is_ascii_hexdigit() && ch.is_ascii_lowercase() =>
             {
-                self.character_reference_code *= 16;
-                self.character_reference_code +=
-                    ((ch as u8) - 0x0057) as u32;
+                self.character_reference_code = self
+                    .character_reference_code
+                    .saturating_mul(16)
+                    .saturating_add(((ch as u8) - 0x0057) as u32);
                 self.and_continue()
             }
 
@@ -333,9 +350,10 @@ where
             // du point de code du caractère) au code de référence du
             // caractère.
             | Some(ch) if ch.is_ascii_digit() => {
-                self.character_reference_code *= 10;
-                self.character_reference_code +=
-                    ((ch as u8) - 0x0030) as u32;
+                self.character_reference_code = self
+                    .character_reference_code
+                    .saturating_mul(10)
+                    .saturating_add(((ch as u8) - 0x0030) as u32);
                 self.and_continue()
             }
 
@@ -362,9 +380,12 @@ where
     ) -> HTMLTokenizerProcessResult {
         let mut err: Option<&str> = None;
 
-        let cp = self.character_reference_code as u8 as CodePoint;
+        // NOTE(phisyx): les vérifications portent sur le nombre lui-même
+        // (et non sur un CodePoint) : un substitut ou un nombre hors de
+        // la plage unicode ne peut pas être représenté par un `char`.
+        let code = self.character_reference_code;
 
-        match self.character_reference_code {
+        match code {
             // Si le nombre est 0x00, il s'agit d'une erreur d'analyse de
             // type `null-character-reference`. Définir le code de
             // référence du caractère à 0xFFFD.
@@ -377,7 +398,7 @@ where
             // erreur d'analyse de référence de caractère hors
             // de la plage unicode. Définir le code de référence du
             // caractère à 0xFFFD.
-            | crc if crc > 0x10FFFF => {
+            | 0x11_0000.. => {
                 err = "character-reference-outside-unicode-range".into();
                 self.character_reference_code = 0xFFFD;
             }
@@ -385,14 +406,17 @@ where
             // Si le nombre est un substitut, il s'agit d'une erreur
             // d'analyse de type `surrogate-character-reference`.
             // Définir le code de référence du caractère à 0xFFFD.
-            | _ if cp.is_surrogate() => {
+            | 0xD800..=0xDFFF => {
                 err = "surrogate-character-reference".into();
                 self.character_reference_code = 0xFFFD;
             }
 
             // Si le nombre n'est pas un caractère, il s'agit d'une erreur
             // d'analyse de type `noncharacter-character-reference`.
-            | _ if cp.is_noncharacter() => {
+            | 0xFDD0..=0xFDEF => {
+                err = "noncharacter-character-reference".into();
+            }
+            | _ if code & 0xFFFE == 0xFFFE => {
                 err = "noncharacter-character-reference".into();
             }
 
@@ -403,10 +427,11 @@ where
             // avec ce nombre dans la première colonne, et définir le
             // code de référence de caractère au nombre de la deuxième
             // colonne de cette ligne.
-            | crc if crc == 0x0D
-                || (cp.is_control() && !cp.is_ascii_whitespace()) =>
-            {
+            | 0x0D | 0x01..=0x08 | 0x0B | 0x0E..=0x1F | 0x7F..=0x9F => {
                 err = "control-character-reference".into();
+                if let Some(replacement) = c1_control_replacement(code) {
+                    self.character_reference_code = replacement;
+                }
             }
             | _ => {}
         }
@@ -425,3 +450,44 @@ where
         }
     }
 }
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Tableau de remplacement des contrôles C1 de l'état
+/// `numeric-character-reference-end` : ces nombres correspondent aux
+/// caractères de l'encodage windows-1252.
+fn c1_control_replacement(code: u32) -> Option<u32> {
+    let replacement = match code {
+        | 0x80 => 0x20AC, // €
+        | 0x82 => 0x201A, // ‚
+        | 0x83 => 0x0192, // ƒ
+        | 0x84 => 0x201E, // „
+        | 0x85 => 0x2026, // …
+        | 0x86 => 0x2020, // †
+        | 0x87 => 0x2021, // ‡
+        | 0x88 => 0x02C6, // ˆ
+        | 0x89 => 0x2030, // ‰
+        | 0x8A => 0x0160, // Š
+        | 0x8B => 0x2039, // ‹
+        | 0x8C => 0x0152, // Œ
+        | 0x8E => 0x017D, // Ž
+        | 0x91 => 0x2018, // ‘
+        | 0x92 => 0x2019, // ’
+        | 0x93 => 0x201C, // “
+        | 0x94 => 0x201D, // ”
+        | 0x95 => 0x2022, // •
+        | 0x96 => 0x2013, // –
+        | 0x97 => 0x2014, // —
+        | 0x98 => 0x02DC, // ˜
+        | 0x99 => 0x2122, // ™
+        | 0x9A => 0x0161, // š
+        | 0x9B => 0x203A, // ›
+        | 0x9C => 0x0153, // œ
+        | 0x9E => 0x017E, // ž
+        | 0x9F => 0x0178, // Ÿ
+        | _ => return None,
+    };
+    Some(replacement)
+}
@@ -88,7 +88,9 @@ where
                     .element_ref()
                     .isin_html_namespace()
                 {
-                    return self.switch_state_to("cdata").and_continue();
+                    return self
+                        .switch_state_to("cdata-section")
+                        .and_continue();
                 }
 
                 return self
@@ -226,7 +228,7 @@ where
             // EOF
             //
             // Reprendre à l'état `comment-end`.
-            | Some('-') | None => {
+            | Some('>') | None => {
                 self.reconsume("comment-end").and_continue()
             }
 
@@ -148,11 +148,11 @@ where
             // U+003E GREATER-THAN SIGN (>)
             //
             // Si le jeton `end-tag` actuel est un jeton `end-tag`
-            // approprié, il faut passer à l'état `data`.
-            // Sinon, le traiter comme indiqué dans l'entrée `Anything
-            // else` ci-dessous.
+            // approprié, il faut passer à l'état `data` et émettre le
+            // jeton actuel. Sinon, le traiter comme indiqué dans l'entrée
+            // `Anything else` ci-dessous.
             | Some('>') if self.is_appropriate_end_tag() => {
-                self.switch_state_to("data").and_continue()
+                self.switch_state_to("data").and_emit()
             }
 
             // ASCII upper alpha
@@ -492,11 +492,12 @@ where
             // U+003E GREATER-THAN SIGN (>)
             //
             // Si le jeton `end-tag` actuel est un jeton `end-tag`
-            // approprié, passer à l'état `data`. Sinon, le traiter comme
-            // indiqué dans l'entrée "anything else" ci-dessous.
-            | Some('>') if self.is_appropriate_end_tag() => self
-                .switch_state_to("self-closing-start-tag")
-                .and_continue(),
+            // approprié, passer à l'état `data` et émettre le jeton
+            // actuel. Sinon, le traiter comme indiqué dans l'entrée
+            // "anything else" ci-dessous.
+            | Some('>') if self.is_appropriate_end_tag() => {
+                self.switch_state_to("data").and_emit()
+            }
 
             // ASCII upper alpha
             //
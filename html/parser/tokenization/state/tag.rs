@@ -54,7 +54,7 @@ where
             | Some('?') => self
                 .set_token(HTMLToken::new_comment(String::new()))
                 .reconsume("bogus-comment")
-                .and_emit_with_error(
+                .and_continue_with_error(
                     "unexpected-question-mark-instead-of-tag-name",
                 ),
 
@@ -115,6 +115,7 @@ where
             | None => self
                 .emit_token(HTMLToken::Character('<'))
                 .emit_token(HTMLToken::Character('/'))
+                .set_token(HTMLToken::EOF)
                 .and_emit_with_error("eof-before-tag-name"),
 
             // Anything else
@@ -460,8 +461,13 @@ where
             // U+0026 AMPERSAND (&)
             //
             // Définir l'état de retour à l'état
-            // `attribute-value-double-quoted`. Passer à l'état
+            // `attribute-value-double-quoted` (resp.
+            // `attribute-value-single-quoted`). Passer à l'état
             // `character-reference`.
+            //
+            // NOTE(phisyx): l'état de retour dépend du guillemet ouvrant,
+            // sans quoi une référence de caractère ferait basculer un
+            // attribut single-quoted dans l'état double-quoted.
             | Some('&') => self
                 .set_return_state_to(if quote == '"' {
                     "attribute-value-double-quoted"
@@ -558,7 +564,7 @@ where
             // Émettre un jeton `end-of-file`.
             | None => self
                 .set_token(HTMLToken::EOF)
-                .and_emit_with_error("unexpected-null-character"),
+                .and_emit_with_error("eof-in-tag"),
 
             // U+0022 QUOTATION MARK (")
             // U+0027 APOSTROPHE (')
@@ -400,13 +400,11 @@ impl HTMLToken {
 
         fn is_start_with(maybe_id: &Option<String>, xid: &str) -> bool {
             match maybe_id {
-                | Some(p)
-                    if p.to_lowercase()
-                        .starts_with(&xid.to_lowercase()) =>
-                {
-                    true
-                }
-                | _ => false,
+                | Some(p) => p
+                    .get(..xid.len())
+                    .filter(|prefix| prefix.eq_ignore_ascii_case(xid))
+                    .is_some(),
+                | None => false,
             }
         }
 
@@ -461,14 +459,18 @@ impl HTMLToken {
                 return QuirksMode::Yes;
             }
 
-            // L'identifiant système commence par l'une des entrées du
-            // tableau [DOCTYPE::SYSTEM_ID_STARTS_WITH_RULE_1]
-            let is_starts_with_sid = DOCTYPE::SYSTEM_ID_STARTS_WITH_RULE_1
-                .into_iter()
-                .any(|x| is_start_with(system_identifier, x));
+            // L'identifiant système est manquant et l'identifiant public
+            // commence par l'une des entrées du tableau
+            // [DOCTYPE::PUBLIC_ID_STARTS_WITH_RULE_2_1]
+            if self.is_system_identifier_missing() {
+                let is_starts_with_pid =
+                    DOCTYPE::PUBLIC_ID_STARTS_WITH_RULE_2_1
+                        .into_iter()
+                        .any(|x| is_start_with(public_identifier, x));
 
-            if is_starts_with_sid {
-                return QuirksMode::Yes;
+                if is_starts_with_pid {
+                    return QuirksMode::Yes;
+                }
             }
 
             // L'identifiant public commence par l'une des entrées du
@@ -478,7 +480,7 @@ impl HTMLToken {
                 .any(|x| is_start_with(public_identifier, x));
 
             if is_starts_with_pid {
-                return QuirksMode::Yes;
+                return QuirksMode::Limited;
             }
 
             // L'identifiant système n'est pas manquant et l'identifier
@@ -491,7 +493,7 @@ impl HTMLToken {
                         .any(|x| is_start_with(public_identifier, x));
 
                 if is_starts_with_pid {
-                    return QuirksMode::Yes;
+                    return QuirksMode::Limited;
                 }
             }
         }
@@ -666,13 +668,6 @@ impl HTMLToken {
         }
     }
 
-    pub(crate) fn clear_attributes(&mut self) {
-        assert!(matches!(self, Self::Tag { .. }));
-        if let Self::Tag { attributes, .. } = self {
-            attributes.clear();
-        }
-    }
-
     pub(crate) fn set_acknowledge_self_closing_flag(&mut self) {
         assert!(matches!(self, Self::Tag { .. }));
         if let Self::Tag {
@@ -902,9 +897,4 @@ mod DOCTYPE {
         "-//W3C//DTD HTML 4.01 Frameset//",
         "-//W3C//DTD HTML 4.01 Transitional//",
     ];
-
-    pub(crate) const SYSTEM_ID_STARTS_WITH_RULE_1: [&str; 2] = [
-        "-//W3C//DTD HTML 4.01 Frameset//",
-        "-//W3C//DTD HTML 4.01 Transitional//",
-    ];
 }
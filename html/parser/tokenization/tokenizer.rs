@@ -79,6 +79,12 @@ pub struct HTMLTokenizer<Chars> {
 
     /// La sortie de l'étape de tokenisation est une série de zéro ou plus
     /// des jetons.
+    ///
+    /// Les jetons émis forment une file (FIFO) : chaque jeton émis, que
+    /// ce soit par [HTMLTokenizer::emit_token],
+    /// [HTMLTokenizer::and_emit_current_token] ou à la fin d'un état, est
+    /// ajouté à la fin de la file, et le tokenizer retourne toujours le
+    /// premier jeton de la file.
     output: HTMLOutputStream,
 
     pub(crate) tree_construction: HTMLTreeConstruction,
@@ -98,6 +104,10 @@ pub struct HTMLTokenizer<Chars> {
     pub(super) character_reference_code: u32,
 
     last_start_tag_token: Option<HTMLToken>,
+
+    /// Les erreurs d'analyse rencontrées, avec la position (en points de
+    /// code) dans le flux d'entrée.
+    parse_errors: Vec<(HTMLParserError, usize)>,
 }
 
 #[derive(Debug)]
@@ -131,6 +141,7 @@ impl<C> HTMLTokenizer<C> {
             temporary_buffer: Default::default(),
             character_reference_code: Default::default(),
             last_start_tag_token: Default::default(),
+            parse_errors: Default::default(),
         }
     }
 }
@@ -147,11 +158,77 @@ where
         self.output.consume_next_token()
     }
 
+    /// Retire et retourne les erreurs d'analyse rencontrées jusqu'à
+    /// présent.
+    pub(crate) fn take_parse_errors(
+        &mut self,
+    ) -> Vec<(HTMLParserError, usize)> {
+        std::mem::take(&mut self.parse_errors)
+    }
+
+    /// Signale une erreur d'analyse détectée en dehors du tokenizer (par
+    /// l'étape de construction de l'arbre), à la position actuelle dans
+    /// l'entrée.
+    pub(crate) fn report_parse_error(&mut self, err: HTMLParserError) {
+        log::error!("[HTMLParserError]: {err}");
+        self.parse_errors.push((err, self.input.position()));
+    }
+
+    /// L'itérateur de caractères d'origine du flux d'entrée.
+    pub(crate) fn chars(&self) -> &C {
+        self.input.original_iterator()
+    }
+
     /// Le jeton suivant.
     pub(crate) fn consume_next_token(&mut self) -> Option<HTMLToken> {
         self.next()
     }
 
+    /// Le jeton suivant, si l'entrée disponible le permet : avant
+    /// l'exécution de chaque état, `can_consume` indique si l'état peut
+    /// lire l'entrée sans atteindre une fin provisoire du flux (analyse
+    /// en continu). Retourne [None] lorsque l'analyse doit attendre
+    /// davantage d'entrée ; l'état du tokenizer est conservé.
+    pub(crate) fn next_token_if(
+        &mut self,
+        can_consume: impl Fn() -> bool,
+    ) -> Option<Option<HTMLToken>> {
+        if !self.output.is_empty() {
+            return Some(self.output.consume_next_token());
+        }
+
+        loop {
+            if !can_consume() {
+                return None;
+            }
+
+            match self.handle_current_state() {
+                | Ok(HTMLTokenizerProcessControlFlow::Continue) => {
+                    continue
+                }
+                | Ok(HTMLTokenizerProcessControlFlow::Emit) => break,
+                | Err((err, state)) => {
+                    log::error!("[HTMLParserError]: {err}");
+                    self.parse_errors.push((err, self.input.position()));
+                    match state {
+                        | HTMLTokenizerProcessControlFlow::Continue => {
+                            continue
+                        }
+                        | HTMLTokenizerProcessControlFlow::Emit => break,
+                    }
+                }
+            }
+        }
+
+        let token = self.current_token();
+        // NOTE(phisyx): garde en mémoire la dernière balise de début
+        // émise, afin de déterminer si une balise de fin est appropriée.
+        if let Some(HTMLToken::Tag { is_end: false, .. }) = token {
+            self.last_start_tag_token = token.clone();
+        }
+        Some(token)
+    }
+
     /// Change l'état d'un jeton via une fonction de retour.
     pub(super) fn change_current_token<F: FnOnce(&mut HTMLToken)>(
         &mut self,
@@ -161,9 +238,10 @@ where
         self
     }
 
-    /// Émet le jeton actuel.
+    /// Émet le jeton actuel, sans attendre la fin de l'état : il est placé
+    /// dans la file des jetons émis.
     pub(super) fn and_emit_current_token(&mut self) -> &mut Self {
-        if let Some(token) = self.current_token() {
+        if let Some(token) = self.output.current_token().cloned() {
             self.emit_token(token);
         }
         self
@@ -179,14 +257,12 @@ where
         self
     }
 
-    /// Émet le jeton actuel.
+    /// Émet un jeton. Les jetons émis sont placés à la fin de la file des
+    /// jetons émis, dans l'ordre de leur émission : ils précèdent donc le
+    /// jeton actuel, qui n'y est ajouté que lorsque l'état se termine par
+    /// [and_emit](HTMLTokenizerProcessInterface::and_emit).
     pub(super) fn emit_token(&mut self, token: HTMLToken) -> &mut Self {
-        if matches!(token, HTMLToken::Character('<' | '/')) {
-            self.last_start_tag_token =
-                self.output.current_token().cloned();
-        }
-
-        self.output.prepend(token);
+        self.output.append(token);
         self
     }
 
@@ -267,7 +343,7 @@ where
             }),
             Some(HTMLToken::Tag {
                 name: last_tag_name,
-                is_end: true,
+                is_end: false,
                 ..
             }),
         ) = (
@@ -279,6 +355,154 @@ where
             false
         }
     }
+
+    /// Traite le prochain caractère du flux d'entrée selon l'état actuel
+    /// du tokenizer.
+    fn handle_current_state(&mut self) -> HTMLTokenizerProcessResult {
+        match self.state.current {
+            | State::Data => self.handle_data_state(),
+            | State::RCDATA => self.handle_rcdata_state(),
+            | State::RAWTEXT => self.handle_rawtext_state(),
+            | State::ScriptData => self.handle_script_data_state(),
+            | State::PLAINTEXT => self.handle_plaintext_state(),
+            | State::TagOpen => self.handle_tag_open_state(),
+            | State::EndTagOpen => self.handle_end_tag_open_state(),
+            | State::TagName => self.handle_tag_name_state(),
+            | State::RCDATALessThanSign =>
+                self.handle_rcdata_less_than_sign_state(),
+            | State::RCDATAEndTagOpen =>
+                self.handle_rcdata_end_tag_open_state(),
+            | State::RCDATAEndTagName =>
+                self.handle_rcdata_end_tag_name_state(),
+            | State::RAWTEXTLessThanSign =>
+                self.handle_rawtext_less_than_sign_state(),
+            | State::RAWTEXTEndTagOpen =>
+                self.handle_rawtext_end_tag_open_state(),
+            | State::RAWTEXTEndTagName =>
+                self.handle_rawtext_end_tag_name_state(),
+            | State::ScriptDataLessThanSign =>
+                self.handle_script_data_less_than_sign_state(),
+            | State::ScriptDataEndTagOpen =>
+                self.handle_script_data_end_tag_open_state(),
+            | State::ScriptDataEndTagName =>
+                self.handle_script_data_end_tag_name_state(),
+            | State::ScriptDataEscapeStart =>
+                self.handle_script_data_escape_start_state(),
+            | State::ScriptDataEscapeStartDash =>
+                self.handle_script_data_escape_start_dash_state(),
+            | State::ScriptDataEscaped =>
+                self.handle_script_data_escaped_state(),
+            | State::ScriptDataEscapedDash =>
+                self.handle_script_data_escaped_dash_state(),
+            | State::ScriptDataEscapedDashDash =>
+                self.handle_script_data_escaped_dash_dash_state(),
+            | State::ScriptDataEscapedLessThanSign =>
+                self.handle_script_data_escaped_less_than_sign_state(),
+            | State::ScriptDataEscapedEndTagOpen =>
+                self.handle_script_data_escaped_end_tag_open_state(),
+            | State::ScriptDataEscapedEndTagName =>
+                self.handle_script_data_escaped_end_tag_name_state(),
+            | State::ScriptDataDoubleEscapeStart =>
+                self.handle_script_data_double_escape_start_state(),
+            | State::ScriptDataDoubleEscapedState =>
+                self.handle_script_data_double_escaped_state(),
+            | State::ScriptDataDoubleEscapedDash =>
+                self.handle_script_data_double_escaped_dash_state(),
+            | State::ScriptDataDoubleEscapedDashDash =>
+                self.handle_script_data_double_escaped_dash_dash_state(),
+            | State::ScriptDataDoubleEscapedLessThanSign =>
+                self.handle_script_data_double_escaped_less_than_sign_state(),
+            | State::ScriptDataDoubleEscapeEnd =>
+                self.handle_script_data_double_escape_end_state(),
+            | State::BeforeAttributeName =>
+                self.handle_before_attribute_name_state(),
+            | State::AttributeName => self.handle_attribute_name_state(),
+            | State::AfterAttributeName =>
+                self.handle_after_attribute_name_state(),
+            | State::BeforeAttributeValue =>
+                self.handle_before_attribute_value_state(),
+            | State::AttributeValueDoubleQuoted =>
+                self.handle_attribute_value_quoted_state('"'),
+            | State::AttributeValueSingleQuoted =>
+                self.handle_attribute_value_quoted_state('\''),
+            | State::AttributeValueUnquoted =>
+                self.handle_attribute_value_unquoted_state(),
+            | State::AfterAttributeValueQuoted =>
+                self.handle_after_attribute_value_quoted_state(),
+            | State::SelfClosingStartTag =>
+                self.handle_self_closing_start_tag_state(),
+            | State::MarkupDeclarationOpen =>
+                self.handle_markup_declaration_open_state(),
+            | State::BogusComment => self.handle_bogus_comment_state(),
+            | State::CommentStart => self.handle_comment_start_state(),
+            | State::CommentLessThanSign =>
+                self.handle_comment_less_than_sign_state(),
+            | State::CommentLessThanSignBang =>
+                self.handle_comment_less_than_sign_bang_state(),
+            | State::CommentLessThanSignBangDash =>
+                self.handle_comment_less_than_sign_bang_dash_state(),
+            | State::CommentLessThanSignBangDashDash =>
+                self.handle_comment_less_than_sign_bang_dash_dash_state(),
+            | State::CommentStartDash =>
+                self.handle_comment_start_dash_state(),
+            | State::Comment => self.handle_comment_state(),
+            | State::CommentEndDash => self.handle_comment_end_dash_state(),
+            | State::CommentEnd => self.handle_comment_end_state(),
+            | State::CommentEndBang => self.handle_comment_end_bang_state(),
+            | State::DOCTYPE => self.handle_doctype_state(),
+            | State::BeforeDOCTYPEName =>
+                self.handle_before_doctype_name_state(),
+            | State::DOCTYPEName => self.handle_doctype_name_state(),
+            | State::AfterDOCTYPEName =>
+                self.handle_after_doctype_name_state(),
+            | State::AfterDOCTYPEPublicKeyword =>
+                self.handle_after_doctype_public_keyword_state(),
+            | State::BeforeDOCTYPEPublicIdentifier =>
+                self.handle_before_doctype_public_identifier_state(),
+            | State::DOCTYPEPublicIdentifierDoubleQuoted =>
+                self.handle_doctype_public_identifier_quoted('"'),
+            | State::DOCTYPEPublicIdentifierSingleQuoted =>
+                self.handle_doctype_public_identifier_quoted('\''),
+            | State::AfterDOCTYPEPublicIdentifier =>
+                self.handle_after_doctype_public_identifier_state(),
+            | State::BetweenDOCTYPEPublicAndSystemIdentifiers =>
+                self.handle_between_doctype_public_and_system_identifiers_state(),
+            | State::AfterDOCTYPESystemKeyword =>
+                self.handle_after_doctype_system_keyword_state(),
+            | State::BeforeDOCTYPESystemIdentifier =>
+                self.handle_before_doctype_system_identifier_state(),
+            | State::DOCTYPESystemIdentifierDoubleQuoted =>
+                self.handle_doctype_system_identifier_quoted_state('"'),
+            | State::DOCTYPESystemIdentifierSingleQuoted =>
+                self.handle_doctype_system_identifier_quoted_state('\''),
+            | State::AfterDOCTYPESystemIdentifier =>
+                self.handle_after_doctype_system_identifier_state(),
+            | State::BogusDOCTYPE => self.handle_bogus_doctype_state(),
+            | State::CDATASection => self.handle_cdata_section_state(),
+            | State::CDATASectionBracket =>
+                self.handle_cdata_section_bracket_state(),
+            | State::CDATASectionEnd =>
+                self.handle_cdata_section_end_state(),
+            | State::CharacterReference =>
+                self.handle_character_reference_state(),
+            | State::NamedCharacterReference =>
+                self.handle_named_character_reference_state(),
+            | State::AmbiguousAmpersand =>
+                self.handle_ambiguous_ampersand_state(),
+            | State::NumericCharacterReference =>
+                self.handle_numeric_character_reference_state(),
+            | State::HexadecimalCharacterReferenceStart =>
+                self.handle_hexadecimal_character_reference_start_state(),
+            | State::DecimalCharacterReferenceStart =>
+                self.handle_decimal_character_reference_start_state(),
+            | State::HexadecimalCharacterReference =>
+                self.handle_hexadecimal_character_reference_state(),
+            | State::DecimalCharacterReference =>
+                self.handle_decimal_character_reference_state(),
+            | State::NumericCharacterReferenceEnd =>
+                self.handle_numeric_character_reference_end_state(),
+        }
+    }
 }
 
 impl HTMLTokenizerState {
@@ -337,173 +561,7 @@ where
     type Item = HTMLToken;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.output.is_empty() {
-            return self.output.consume_next_token();
-        }
-
-        loop {
-            let state = match self.state.current {
-                | State::Data => self.handle_data_state(),
-                | State::RCDATA => self.handle_rcdata_state(),
-                | State::RAWTEXT => self.handle_rawtext_state(),
-                | State::ScriptData => self.handle_script_data_state(),
-                | State::PLAINTEXT => self.handle_plaintext_state(),
-                | State::TagOpen => self.handle_tag_open_state(),
-                | State::EndTagOpen => self.handle_end_tag_open_state(),
-                | State::TagName => self.handle_tag_name_state(),
-                | State::RCDATALessThanSign =>
-                    self.handle_rcdata_less_than_sign_state(),
-                | State::RCDATAEndTagOpen =>
-                    self.handle_rcdata_end_tag_open_state(),
-                | State::RCDATAEndTagName =>
-                    self.handle_rcdata_end_tag_name_state(),
-                | State::RAWTEXTLessThanSign =>
-                    self.handle_rawtext_less_than_sign_state(),
-                | State::RAWTEXTEndTagOpen =>
-                    self.handle_rawtext_end_tag_open_state(),
-                | State::RAWTEXTEndTagName =>
-                    self.handle_rawtext_end_tag_name_state(),
-                | State::ScriptDataLessThanSign =>
-                    self.handle_script_data_less_than_sign_state(),
-                | State::ScriptDataEndTagOpen =>
-                    self.handle_script_data_end_tag_open_state(),
-                | State::ScriptDataEndTagName =>
-                    self.handle_script_data_end_tag_name_state(),
-                | State::ScriptDataEscapeStart =>
-                    self.handle_script_data_escape_start_state(),
-                | State::ScriptDataEscapeStartDash =>
-                    self.handle_script_data_escape_start_dash_state(),
-                | State::ScriptDataEscaped =>
-                    self.handle_script_data_escaped_state(),
-                | State::ScriptDataEscapedDash =>
-                    self.handle_script_data_escaped_dash_state(),
-                | State::ScriptDataEscapedDashDash =>
-                    self.handle_script_data_escaped_dash_dash_state(),
-                | State::ScriptDataEscapedLessThanSign =>
-                    self.handle_script_data_escaped_less_than_sign_state(),
-                | State::ScriptDataEscapedEndTagOpen =>
-                    self.handle_script_data_escaped_end_tag_open_state(),
-                | State::ScriptDataEscapedEndTagName =>
-                    self.handle_script_data_escaped_end_tag_name_state(),
-                | State::ScriptDataDoubleEscapeStart =>
-                    self.handle_script_data_double_escape_start_state(),
-                | State::ScriptDataDoubleEscapedState =>
-                    self.handle_script_data_double_escaped_state(),
-                | State::ScriptDataDoubleEscapedDash =>
-                    self.handle_script_data_double_escaped_dash_state(),
-                | State::ScriptDataDoubleEscapedDashDash =>
-                    self.handle_script_data_double_escaped_dash_dash_state(),
-                | State::ScriptDataDoubleEscapedLessThanSign =>
-                    self.handle_script_data_double_escaped_less_than_sign_state(),
-                | State::ScriptDataDoubleEscapeEnd =>
-                    self.handle_script_data_double_escape_end_state(),
-                | State::BeforeAttributeName =>
-                    self.handle_before_attribute_name_state(),
-                | State::AttributeName => self.handle_attribute_name_state(),
-                | State::AfterAttributeName =>
-                    self.handle_after_attribute_name_state(),
-                | State::BeforeAttributeValue =>
-                    self.handle_before_attribute_value_state(),
-                | State::AttributeValueDoubleQuoted =>
-                    self.handle_attribute_value_quoted_state('"'),
-                | State::AttributeValueSingleQuoted =>
-                    self.handle_attribute_value_quoted_state('\''),
-                | State::AttributeValueUnquoted =>
-                    self.handle_attribute_value_unquoted_state(),
-                | State::AfterAttributeValueQuoted =>
-                    self.handle_after_attribute_value_quoted_state(),
-                | State::SelfClosingStartTag =>
-                    self.handle_self_closing_start_tag_state(),
-                | State::MarkupDeclarationOpen =>
-                    self.handle_markup_declaration_open_state(),
-                | State::BogusComment => self.handle_bogus_comment_state(),
-                | State::CommentStart => self.handle_comment_start_state(),
-                | State::CommentLessThanSign =>
-                    self.handle_comment_less_than_sign_state(),
-                | State::CommentLessThanSignBang =>
-                    self.handle_comment_less_than_sign_bang_state(),
-                | State::CommentLessThanSignBangDash =>
-                    self.handle_comment_less_than_sign_bang_dash_state(),
-                | State::CommentLessThanSignBangDashDash =>
-                    self.handle_comment_less_than_sign_bang_dash_dash_state(),
-                | State::CommentStartDash =>
-                    self.handle_comment_start_dash_state(),
-                | State::Comment => self.handle_comment_state(),
-                | State::CommentEndDash => self.handle_comment_end_dash_state(),
-                | State::CommentEnd => self.handle_comment_end_state(),
-                | State::CommentEndBang => self.handle_comment_end_bang_state(),
-                | State::DOCTYPE => self.handle_doctype_state(),
-                | State::BeforeDOCTYPEName =>
-                    self.handle_before_doctype_name_state(),
-                | State::DOCTYPEName => self.handle_doctype_name_state(),
-                | State::AfterDOCTYPEName =>
-                    self.handle_after_doctype_name_state(),
-                | State::AfterDOCTYPEPublicKeyword =>
-                    self.handle_after_doctype_public_keyword_state(),
-                | State::BeforeDOCTYPEPublicIdentifier =>
-                    self.handle_before_doctype_public_identifier_state(),
-                | State::DOCTYPEPublicIdentifierDoubleQuoted =>
-                    self.handle_doctype_public_identifier_quoted('"'),
-                | State::DOCTYPEPublicIdentifierSingleQuoted =>
-                    self.handle_doctype_public_identifier_quoted('\''),
-                | State::AfterDOCTYPEPublicIdentifier =>
-                    self.handle_after_doctype_public_identifier_state(),
-                | State::BetweenDOCTYPEPublicAndSystemIdentifiers =>
-                    self.handle_between_doctype_public_and_system_identifiers_state(),
-                | State::AfterDOCTYPESystemKeyword =>
-                    self.handle_after_doctype_system_keyword_state(),
-                | State::BeforeDOCTYPESystemIdentifier =>
-                    self.handle_before_doctype_system_identifier_state(),
-                | State::DOCTYPESystemIdentifierDoubleQuoted =>
-                    self.handle_doctype_system_identifier_quoted_state('"'),
-                | State::DOCTYPESystemIdentifierSingleQuoted =>
-                    self.handle_doctype_system_identifier_quoted_state('\''),
-                | State::AfterDOCTYPESystemIdentifier =>
-                    self.handle_after_doctype_system_identifier_state(),
-                | State::BogusDOCTYPE => self.handle_bogus_doctype_state(),
-                | State::CDATASection => self.handle_cdata_section_state(),
-                | State::CDATASectionBracket =>
-                    self.handle_cdata_section_bracket_state(),
-                | State::CDATASectionEnd =>
-                    self.handle_cdata_section_end_state(),
-                | State::CharacterReference =>
-                    self.handle_character_reference_state(),
-                | State::NamedCharacterReference =>
-                    self.handle_named_character_reference_state(),
-                | State::AmbiguousAmpersand =>
-                    self.handle_ambiguous_ampersand_state(),
-                | State::NumericCharacterReference =>
-                    self.handle_numeric_character_reference_state(),
-                | State::HexadecimalCharacterReferenceStart =>
-                    self.handle_hexadecimal_character_reference_start_state(),
-                | State::DecimalCharacterReferenceStart =>
-                    self.handle_decimal_character_reference_start_state(),
-                | State::HexadecimalCharacterReference =>
-                    self.handle_hexadecimal_character_reference_state(),
-                | State::DecimalCharacterReference =>
-                    self.handle_decimal_character_reference_state(),
-                | State::NumericCharacterReferenceEnd =>
-                    self.handle_numeric_character_reference_end_state(),
-            };
-
-            match state {
-                | Ok(HTMLTokenizerProcessControlFlow::Continue) => {
-                    continue
-                }
-                | Ok(HTMLTokenizerProcessControlFlow::Emit) => break,
-                | Err((err, state)) => {
-                    log::error!("[HTMLParserError]: {err}");
-                    match state {
-                        | HTMLTokenizerProcessControlFlow::Continue => {
-                            continue
-                        }
-                        | HTMLTokenizerProcessControlFlow::Emit => break,
-                    }
-                }
-            }
-        }
-
-        self.current_token()
+        self.next_token_if(|| true).flatten()
     }
 }
 
@@ -541,8 +599,11 @@ mod tests {
         ));
 
         let attr_name = "href";
+        // NOTE(phisyx): pour des raisons historiques, une référence
+        // nommée sans point-virgule suivie de `=` ou d'un caractère
+        // alphanumérique n'est pas décodée dans un attribut.
         let attr_value =
-            "?a=b&c=d&a0b=c&copy=1&noti=n&not=in&notin=&notin;&not;&;& &";
+            "?a=b&c=d&a0b=c&copy=1&noti=n&not=in&notin=∉¬&;& &";
 
         assert_eq!(
             token.consume_next_token(),
@@ -554,6 +615,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ambiguous_ampersand_single_quoted() {
+        // NOTE(phisyx): `&c` suivi de `=` ne correspond à aucune référence
+        // nommée et, dans un attribut, n'est pas décodé.
+        let mut html_tok =
+            get_tokenizer_html("<a href='?a=b&c=d&a0b=c'>x</a>");
+
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag()
+                    .with_name("a")
+                    .with_attributes([("href", "?a=b&c=d&a0b=c")])
+            ),
+        );
+
+        // L'attribut entre single quotes est bien fermé : le contenu de
+        // l'élément suit la balise de début.
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Character('x'))
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::new_end_tag().with_name("a"))
+        );
+        assert!(html_tok.take_parse_errors().is_empty());
+    }
+
+    #[test]
+    fn test_attribute_value_single_quoted_stays_single_quoted() {
+        let mut html_tok = get_tokenizer_html(
+            "<a href='/search?q=a&b=\"c\"&lang=fr&x' title=\"t\">y</a>",
+        );
+
+        // Chaque `&` qui n'est pas une référence de caractère revient à
+        // l'état `attribute-value-single-quoted` : les `"` font partie de
+        // la valeur et l'attribut suivant est correctement analysé.
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag().with_name("a").with_attributes(
+                    [
+                        ("href", "/search?q=a&b=\"c\"&lang=fr&x"),
+                        ("title", "t")
+                    ]
+                )
+            ),
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Character('y'))
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::new_end_tag().with_name("a"))
+        );
+        assert!(html_tok.take_parse_errors().is_empty());
+    }
+
+    #[test]
+    fn test_attribute_value_single_quoted_character_reference() {
+        let mut html_tok =
+            get_tokenizer_html("<a href='x&amp;y\"z' title=\"a&amp;'b\">");
+
+        // Après la référence de caractère, l'attribut reste entre single
+        // quotes : le caractère `"` fait partie de la valeur et seul le
+        // caractère `'` la ferme (et inversement).
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag().with_name("a").with_attributes(
+                    [("href", "x&y\"z"), ("title", "a&'b")]
+                )
+            ),
+        );
+        assert!(html_tok.take_parse_errors().is_empty());
+    }
+
+    #[test]
+    fn test_attribute_value_single_quoted_flush_temporary_buffer() {
+        let mut html_tok = get_tokenizer_html("<a title='&#x41;&#\"b'>");
+
+        // Les caractères du tampon temporaire (`&#`) sont ré-insérés dans
+        // la valeur de l'attribut, puis `"` est reconsommé dans l'état
+        // `attribute-value-single-quoted`.
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag()
+                    .with_name("a")
+                    .with_attributes([("title", "A&#\"b")])
+            ),
+        );
+        assert_eq!(html_tok.take_parse_errors().len(), 1);
+    }
+
     #[test]
     fn test_comment() {
         let mut token = get_tokenizer_html(include_str!(
@@ -566,6 +724,300 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bogus_comment() {
+        let mut html_tok = get_tokenizer_html(r#"<?xml version="1.0"?>"#);
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Comment(r#"?xml version="1.0"?"#.into()))
+        );
+
+        let mut html_tok = get_tokenizer_html("<?php ?></3>");
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Comment("?php ?".into()))
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Comment("3".into()))
+        );
+    }
+
+    #[test]
+    fn test_empty_comment() {
+        // NOTE(phisyx): consomme les états jusqu'à l'émission d'un jeton
+        // et retourne les erreurs rencontrées en chemin.
+        fn consume_until_emit(
+            html_tok: &mut HTMLTokenizer<impl CodePointIterator>,
+        ) -> Vec<String> {
+            let mut errors = vec![];
+            loop {
+                match html_tok.handle_current_state() {
+                    | Ok(HTMLTokenizerProcessControlFlow::Continue) => {
+                        continue
+                    }
+                    | Ok(HTMLTokenizerProcessControlFlow::Emit) => break,
+                    | Err((err, state)) => {
+                        errors.push(err.to_string());
+                        if let HTMLTokenizerProcessControlFlow::Emit =
+                            state
+                        {
+                            break;
+                        }
+                    }
+                }
+            }
+            errors
+        }
+
+        for (input, expected_errors) in [
+            ("<!-->", vec!["abrupt-closing-of-empty-comment"]),
+            ("<!--->", vec!["abrupt-closing-of-empty-comment"]),
+            ("<!---->", vec![]),
+        ] {
+            let mut html_tok = get_tokenizer_html(input);
+
+            let errors = consume_until_emit(&mut html_tok);
+            assert_eq!(errors, expected_errors, "{input}");
+            assert_eq!(
+                html_tok.current_token(),
+                Some(HTMLToken::Comment(String::new())),
+                "{input}"
+            );
+            assert!(matches!(html_tok.state.current, State::Data));
+
+            assert_eq!(
+                html_tok.consume_next_token(),
+                Some(HTMLToken::EOF)
+            );
+        }
+    }
+
+    #[test]
+    fn test_nested_comment() {
+        let mut html_tok = get_tokenizer_html("<!--<!--x-->y");
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Comment("<!--x".into()))
+        );
+        assert_eq!(
+            html_tok
+                .take_parse_errors()
+                .into_iter()
+                .map(|(err, _)| err.to_string())
+                .collect::<Vec<_>>(),
+            ["nested-comment"]
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::new_character('y'))
+        );
+
+        // `<!--` suivi de `>` ou de la fin du fichier n'est pas un
+        // commentaire imbriqué.
+        for (input, data) in [("<!--<!-->", "<!"), ("<!--<!--", "<!")] {
+            let mut html_tok = get_tokenizer_html(input);
+            assert_eq!(
+                html_tok.consume_next_token(),
+                Some(HTMLToken::Comment(data.into())),
+                "{input}"
+            );
+            assert!(
+                !html_tok
+                    .take_parse_errors()
+                    .iter()
+                    .any(|(err, _)| err.to_string() == "nested-comment"),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_whitespace_between_attributes() {
+        let mut html_tok =
+            get_tokenizer_html("<div a=\"1\"b=\"2\"c='3'd=4>x</div>");
+
+        // Les attributs collés sont analysés comme s'ils étaient séparés
+        // par un espace blanc.
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag()
+                    .with_name("div")
+                    .with_attributes([
+                        ("a", "1"),
+                        ("b", "2"),
+                        ("c", "3"),
+                        ("d", "4")
+                    ])
+            ),
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::Character('x'))
+        );
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::new_end_tag().with_name("div"))
+        );
+
+        let errors = html_tok.take_parse_errors();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|(err, _)| matches!(
+            err,
+            HTMLParserError::MissingWhitespaceBetweenAttributes
+        )));
+    }
+
+    #[test]
+    fn test_emit_order_at_eof() {
+        fn tokens_of(
+            mut html_tok: HTMLTokenizer<impl CodePointIterator>,
+        ) -> Vec<HTMLToken> {
+            let mut tokens = Vec::new();
+            while let Some(token) = html_tok.consume_next_token() {
+                let is_eof = matches!(token, HTMLToken::EOF);
+                tokens.push(token);
+                if is_eof {
+                    break;
+                }
+            }
+            tokens
+        }
+
+        use HTMLToken::{Character, EOF};
+
+        // Les jetons émis dans un même état le sont dans l'ordre, avant
+        // le jeton `end-of-file`.
+        assert_eq!(
+            tokens_of(get_tokenizer_html("<")),
+            [Character('<'), EOF]
+        );
+        assert_eq!(
+            tokens_of(get_tokenizer_html("a</")),
+            [Character('a'), Character('<'), Character('/'), EOF]
+        );
+
+        let mut html_tok = get_tokenizer_html("a</");
+        assert_eq!(html_tok.consume_next_token(), Some(Character('a')));
+        assert_eq!(html_tok.consume_next_token(), Some(Character('<')));
+        assert_eq!(html_tok.consume_next_token(), Some(Character('/')));
+        assert_eq!(html_tok.consume_next_token(), Some(EOF));
+        let errors = html_tok.take_parse_errors();
+        assert!(matches!(
+            errors.as_slice(),
+            [(HTMLParserError::EofBeforeTagName, _)]
+        ));
+
+        // Les jetons émis dans plusieurs états successifs (le tampon
+        // temporaire est émis après `<` et `/`), puis re-consommation de
+        // EOF.
+        for (input, expected) in [
+            ("a</", vec![Character('a'), Character('<'), Character('/')]),
+            (
+                "a</xy",
+                vec![
+                    Character('a'),
+                    Character('<'),
+                    Character('/'),
+                    Character('x'),
+                    Character('y'),
+                ],
+            ),
+            ("a<b", vec![Character('a'), Character('<'), Character('b')]),
+        ] {
+            let mut html_tok = get_tokenizer_html(input);
+            html_tok.switch_state_to("rcdata");
+            let mut expected = expected;
+            expected.push(EOF);
+            assert_eq!(tokens_of(html_tok), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_emit_order_rcdata_end_tag_name() {
+        use HTMLToken::Character;
+
+        let mut html_tok = get_tokenizer_html("<title>a</tit>b</title>");
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::new_start_tag().with_name("title"))
+        );
+        html_tok.switch_state_to("rcdata");
+
+        // `</tit>` n'est pas une balise de fin appropriée : `<`, `/` puis
+        // les caractères du tampon temporaire sont émis, dans cet ordre,
+        // avant les données suivantes.
+        for ch in "a</tit>b".chars() {
+            assert_eq!(html_tok.consume_next_token(), Some(Character(ch)));
+        }
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(HTMLToken::new_end_tag().with_name("title"))
+        );
+        assert_eq!(html_tok.consume_next_token(), Some(HTMLToken::EOF));
+    }
+
+    #[test]
+    fn test_eof_in_attribute_value_unquoted() {
+        let mut html_tok = get_tokenizer_html("<div a=b");
+
+        let err = loop {
+            match html_tok.handle_current_state() {
+                | Ok(_) => continue,
+                | Err((err, _)) => break err,
+            }
+        };
+
+        assert!(matches!(err, HTMLParserError::EofInTag));
+        assert_eq!(err.to_string(), "eof-in-tag");
+    }
+
+    #[test]
+    fn test_appropriate_end_tag() {
+        for (input, state, name, text) in [
+            ("<script>a</script>", "script-data", "script", "a"),
+            ("<script><!--a</script>", "script-data", "script", "--a"),
+            ("<title>a</title>", "rcdata", "title", "a"),
+            ("<style>a</style>", "rawtext", "style", "a"),
+        ] {
+            let mut html_tok = get_tokenizer_html(input);
+
+            assert_eq!(
+                html_tok.consume_next_token(),
+                Some(HTMLToken::new_start_tag().with_name(name)),
+                "{input}"
+            );
+
+            // NOTE(phisyx): c'est l'arbre de construction qui change
+            // l'état du tokenizer après une balise de début.
+            html_tok.switch_state_to(state);
+
+            let mut data = String::new();
+            let token = loop {
+                match html_tok.consume_next_token() {
+                    | Some(HTMLToken::Character(ch)) => data.push(ch),
+                    | token => break token,
+                }
+            };
+
+            // NOTE(phisyx): les caractères `<!` sont émis dans la même
+            // étape et ressortent dans l'ordre inverse ; seule la fin des
+            // données est vérifiée.
+            assert!(data.ends_with(text), "{input}: {data}");
+            assert_eq!(
+                token,
+                Some(HTMLToken::new_end_tag().with_name(name)),
+                "{input}"
+            );
+            assert!(matches!(html_tok.state.current, State::Data));
+            assert_eq!(
+                html_tok.consume_next_token(),
+                Some(HTMLToken::EOF)
+            );
+        }
+    }
+
     #[test]
     fn test_tag() {
         let mut token =
@@ -593,4 +1045,229 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_numeric_character_reference_end() {
+        fn decode(input: &'static str) -> (String, Vec<String>) {
+            let mut html_tok = get_tokenizer_html(input);
+            let mut output = String::new();
+            while let Some(token) = html_tok.consume_next_token() {
+                match token {
+                    | HTMLToken::Character(ch) => output.push(ch),
+                    | HTMLToken::EOF => break,
+                    | _ => {}
+                }
+            }
+            let errors = html_tok
+                .take_parse_errors()
+                .into_iter()
+                .map(|(err, _)| err.to_string())
+                .collect();
+            (output, errors)
+        }
+
+        for (input, expected_output, expected_errors) in [
+            ("&#65;", "A", vec![]),
+            ("&#x41;", "A", vec![]),
+            ("&#0;", "\u{FFFD}", vec!["null-character-reference"]),
+            (
+                "&#x110000;",
+                "\u{FFFD}",
+                vec!["character-reference-outside-unicode-range"],
+            ),
+            (
+                "&#xFFFFFFFFFF;",
+                "\u{FFFD}",
+                vec!["character-reference-outside-unicode-range"],
+            ),
+            (
+                "&#xD800;",
+                "\u{FFFD}",
+                vec!["surrogate-character-reference"],
+            ),
+            (
+                "&#xDFFF;",
+                "\u{FFFD}",
+                vec!["surrogate-character-reference"],
+            ),
+            (
+                "&#xFDD0;",
+                "\u{FDD0}",
+                vec!["noncharacter-character-reference"],
+            ),
+            (
+                "&#x10FFFF;",
+                "\u{10FFFF}",
+                vec!["noncharacter-character-reference"],
+            ),
+            ("&#x0D;", "\r", vec!["control-character-reference"]),
+            ("&#x01;", "\u{1}", vec!["control-character-reference"]),
+            ("&#x80;", "€", vec!["control-character-reference"]),
+            ("&#x81;", "\u{81}", vec!["control-character-reference"]),
+            ("&#x9F;", "Ÿ", vec!["control-character-reference"]),
+            ("&#x09;", "\t", vec![]),
+            ("&#x100;", "Ā", vec![]),
+        ] {
+            let (output, errors) = decode(input);
+            assert_eq!(output, expected_output, "{input}");
+            assert_eq!(errors, expected_errors, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_astral_numeric_character_reference() {
+        let mut html_tok = get_tokenizer_html(
+            "&#128512;&#x1F600;&#X1f600;&#x10FFFD;&#xDFFF;&#x1F600",
+        );
+        let mut output = String::new();
+        while let Some(token) = html_tok.consume_next_token() {
+            match token {
+                | HTMLToken::Character(ch) => output.push(ch),
+                | HTMLToken::EOF => break,
+                | _ => {}
+            }
+        }
+
+        // Les points de code au-delà de U+FFFF ne sont pas tronqués ; un
+        // substitut isolé donne U+FFFD.
+        assert_eq!(output, "😀😀😀\u{10FFFD}\u{FFFD}😀");
+        assert_eq!(
+            html_tok
+                .take_parse_errors()
+                .into_iter()
+                .map(|(err, _)| err.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "surrogate-character-reference",
+                "missing-semicolon-after-character-reference",
+            ]
+        );
+
+        let mut html_tok =
+            get_tokenizer_html("<a title='&#x1F600;&#127752;'>");
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag()
+                    .with_name("a")
+                    .with_attributes([("title", "😀🌈")])
+            ),
+        );
+    }
+
+    #[test]
+    fn test_named_character_reference_without_semicolon() {
+        // Dans un attribut, pour des raisons historiques, `&copy` suivi
+        // de `=` ou d'un caractère alphanumérique n'est pas décodé.
+        let mut html_tok =
+            get_tokenizer_html("<a title='a=&copy=1' alt='&copyx'>");
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag().with_name("a").with_attributes(
+                    [("title", "a=&copy=1"), ("alt", "&copyx")]
+                )
+            ),
+        );
+        assert!(html_tok.take_parse_errors().is_empty());
+
+        // Dans un attribut, s'il est suivi d'un autre caractère, il est
+        // décodé.
+        let mut html_tok = get_tokenizer_html("<a title='&copy 1'>");
+        assert_eq!(
+            html_tok.consume_next_token(),
+            Some(
+                HTMLToken::new_start_tag()
+                    .with_name("a")
+                    .with_attributes([("title", "\u{a9} 1")])
+            ),
+        );
+        assert_eq!(
+            html_tok.take_parse_errors()[0].0,
+            HTMLParserError::MissingSemicolonAfterCharacterReference
+        );
+
+        // En dehors d'un attribut, l'exception ne s'applique pas.
+        let mut html_tok = get_tokenizer_html("a=&copy=1");
+        let mut text = String::new();
+        while let Some(HTMLToken::Character(ch)) =
+            html_tok.consume_next_token()
+        {
+            text.push(ch);
+        }
+        assert_eq!(text, "a=\u{a9}=1");
+        assert_eq!(
+            html_tok.take_parse_errors()[0].0,
+            HTMLParserError::MissingSemicolonAfterCharacterReference
+        );
+    }
+
+    #[test]
+    fn test_eof_in_cdata() {
+        // Troncation en plein milieu de la section CDATA, puis des
+        // crochets de fin.
+        for (input, expected) in
+            [("ab", "ab"), ("ab]", "ab]"), ("ab]]", "ab]]"), ("", "")]
+        {
+            let mut html_tok = get_tokenizer_html(input);
+            html_tok.switch_state_to("cdata-section");
+
+            let mut data = String::new();
+            loop {
+                match html_tok.consume_next_token() {
+                    | Some(HTMLToken::Character(ch)) => data.push(ch),
+                    | Some(HTMLToken::EOF) => break,
+                    | token => panic!("{input}: {token:?}"),
+                }
+            }
+            assert_eq!(data, expected);
+
+            let errors = html_tok.take_parse_errors();
+            assert!(
+                matches!(
+                    errors.as_slice(),
+                    [(HTMLParserError::EofInCdata, _)]
+                ),
+                "{input}: {errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_eof_in_script_html_comment_like_text() {
+        // Troncation dans chacun des états échappés (et doublement
+        // échappés) des données de script.
+        for input in [
+            "<!--",
+            "<!--a",
+            "<!--a-",
+            "<!--a--",
+            "<!--<script>a",
+            "<!--<script>a-",
+            "<!--<script>a--",
+            "<!--<script>a<",
+        ] {
+            let mut html_tok = get_tokenizer_html(input);
+            html_tok.switch_state_to("script-data");
+
+            let mut data = String::new();
+            loop {
+                match html_tok.consume_next_token() {
+                    | Some(HTMLToken::Character(ch)) => data.push(ch),
+                    | Some(HTMLToken::EOF) => break,
+                    | token => panic!("{input}: {token:?}"),
+                }
+            }
+            assert_eq!(data, input);
+
+            let errors = html_tok.take_parse_errors();
+            assert!(
+                matches!(
+                    errors.as_slice(),
+                    [(HTMLParserError::EofInScriptHtmlCommentLikeText, _)]
+                ),
+                "{input}: {errors:?}"
+            );
+        }
+    }
 }
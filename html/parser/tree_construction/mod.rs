@@ -15,11 +15,11 @@ mod rules {
     mod text;
 }
 
-use std::{borrow::BorrowMut, ops::ControlFlow};
+use std::{borrow::BorrowMut, ops::ControlFlow, rc::Rc};
 
 use dom::node::{
     CommentNode, CreateElementOptions, Document, DocumentNode, Node,
-    TextNode,
+    QuirksMode, TextNode,
 };
 use html_elements::{
     interface::IsOneOfTagsInterface, tag_attributes, tag_names,
@@ -31,13 +31,14 @@ use infra::{
 use parser::StreamToken;
 
 use crate::{
+    elements_categories,
     state::{
         Entry, FormElementPointer, FramesetOkFlag, HeadElementPointer,
         InsertionMode, ListOfActiveFormattingElements, ScriptingFlag,
         StackOfOpenElements,
     },
     tokenization::{HTMLToken, HTMLTokenizerState},
-    HTMLParserFlag, HTMLParserState,
+    HTMLParserError, HTMLParserFlag, HTMLParserState,
 };
 
 // ---- //
@@ -62,6 +63,13 @@ pub struct HTMLTreeConstruction {
     list_of_active_formatting_elements: ListOfActiveFormattingElements,
     foster_parenting: bool,
     scripting_flag: ScriptingFlag,
+    /// Mode de récupération (voir
+    /// [HTMLTreeConstruction::set_best_effort]).
+    best_effort: bool,
+    /// Mode d'insertion traité comme s'il n'était pas implémenté, afin de
+    /// tester le mode de récupération.
+    #[cfg(test)]
+    unimplemented_insertion_mode: Option<InsertionMode>,
     pub(super) frameset_ok_flag: FramesetOkFlag,
     parsing_fragment: bool,
     context_element: Option<TreeNode<Node>>,
@@ -70,6 +78,18 @@ pub struct HTMLTreeConstruction {
     head_element_pointer: Option<HeadElementPointer>,
     form_element_pointer: Option<FormElementPointer>,
     pending_table_character_tokens: Vec<HTMLToken>,
+    pending_parsing_blocking_script: Option<TreeNode<Node>>,
+    /// Le jeton en cours de traitement est une balise de début dont le
+    /// drapeau self-closing est activé, mais n'a pas (encore) été
+    /// reconnu.
+    unacknowledged_self_closing_flag: bool,
+    /// Les erreurs d'analyse signalées par l'étape de construction de
+    /// l'arbre, en attente d'être remontées à l'analyseur.
+    parse_errors: Vec<HTMLParserError>,
+    /// Nombre de nœuds créés par la construction de l'arbre (voir
+    /// [parser::ParserMetrics]).
+    #[cfg(feature = "metrics")]
+    nodes_created: std::cell::Cell<usize>,
 }
 
 struct AdjustedInsertionLocation {
@@ -81,6 +101,16 @@ struct AdjustedInsertionLocation {
 // Implémentation //
 // -------------- //
 
+impl AdjustedInsertionLocation {
+    /// Emplacement d'insertion correspondant au dernier enfant du parent.
+    fn last_child_of(parent: &TreeNode<Node>) -> Self {
+        Self {
+            parent: Some(TreeNode::clone(parent)),
+            insert_before_sibling: None,
+        }
+    }
+}
+
 impl HTMLTreeConstruction {
     pub(super) fn new(document: DocumentNode) -> Self {
         Self {
@@ -90,11 +120,229 @@ impl HTMLTreeConstruction {
     }
 }
 
+impl HTMLTreeConstruction {
+    /// Le mode du document en cours de construction, déterminé par son
+    /// doctype dans le mode d'insertion "initial".
+    pub fn quirks_mode(&self) -> QuirksMode {
+        self.document.get().quirks_mode()
+    }
+
+    /// Le document est-il en mode "quirks" ? Les rares règles de
+    /// construction de l'arbre qui dépendent du mode du document
+    /// l'utilisent.
+    pub fn is_quirks(&self) -> bool {
+        matches!(self.quirks_mode(), QuirksMode::Yes)
+    }
+
+    /// Le drapeau de scripting est-il activé ? Il l'est par défaut.
+    pub fn is_scripting_enabled(&self) -> bool {
+        self.scripting_flag == ScriptingFlag::Enabled
+    }
+
+    /// Active ou désactive le drapeau de scripting. Les éléments noscript
+    /// sont analysés comme du texte brut lorsque le drapeau est activé,
+    /// et comme des éléments ordinaires lorsqu'il est désactivé.
+    pub fn set_scripting(&mut self, enabled: bool) {
+        self.scripting_flag = if enabled {
+            ScriptingFlag::Enabled
+        } else {
+            ScriptingFlag::Disabled
+        };
+    }
+
+    /// Active ou désactive le mode de récupération : les jetons d'un
+    /// mode d'insertion dont les règles ne sont pas implémentées sont
+    /// traités selon les règles du mode d'insertion "in body", avec un
+    /// avertissement loggé, au lieu de paniquer.
+    pub fn set_best_effort(&mut self, enabled: bool) {
+        self.best_effort = enabled;
+    }
+
+    /// Nombre de nœuds créés depuis la création de l'instance, ou la
+    /// dernière remise à zéro.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn take_nodes_created(&self) -> usize {
+        self.nodes_created.take()
+    }
+
+    /// Compte un nœud créé par la construction de l'arbre.
+    fn count_created_node(&self) {
+        #[cfg(feature = "metrics")]
+        self.nodes_created.set(self.nodes_created.get() + 1);
+    }
+
+    /// Le nombre d'éléments de la pile des éléments ouverts.
+    pub(crate) fn open_elements_count(&self) -> usize {
+        self.stack_of_open_elements.len()
+    }
+
+    /// Retire de la pile des éléments ouverts, et de l'arbre, les
+    /// éléments au-delà de la limite donnée.
+    pub(crate) fn truncate_open_elements(&mut self, limit: usize) {
+        if self.stack_of_open_elements.len() > limit {
+            self.stack_of_open_elements
+                .drain(limit..)
+                .for_each(|element| element.detach_node());
+        }
+    }
+
+    /// Les noms des éléments de la pile des éléments ouverts, du plus
+    /// ancien (généralement html) au nœud actuel. Utile pour diagnostiquer
+    /// la construction d'un arbre.
+    #[cfg(debug_assertions)]
+    pub fn open_elements_debug(&self) -> Vec<String> {
+        self.stack_of_open_elements
+            .iter()
+            .map(|node| node.element_ref().local_name())
+            .collect()
+    }
+
+    /// Reconnaît le drapeau self-closing de la balise de début en cours
+    /// de traitement.
+    fn acknowledge_self_closing_flag(
+        &mut self,
+        tag_token: &mut HTMLToken,
+    ) {
+        tag_token.set_acknowledge_self_closing_flag();
+        self.unacknowledged_self_closing_flag = false;
+    }
+
+    /// Lorsqu'une balise de début dont le drapeau self-closing est activé
+    /// est émise, si le drapeau n'est pas reconnu lors du traitement du
+    /// jeton par l'étape de construction de l'arbre, il s'agit d'une
+    /// erreur d'analyse de type
+    /// `non-void-html-element-start-tag-with-trailing-solidus`.
+    ///
+    /// Retourne vrai, une seule fois, si le dernier jeton traité était
+    /// dans ce cas.
+    pub(super) fn take_unacknowledged_self_closing_flag(
+        &mut self,
+    ) -> bool {
+        std::mem::take(&mut self.unacknowledged_self_closing_flag)
+    }
+
+    /// Retire et retourne l'élément script complété (sans attribut src)
+    /// en attente d'exécution, s'il y en a un.
+    pub(super) fn take_pending_parsing_blocking_script(
+        &mut self,
+    ) -> Option<TreeNode<Node>> {
+        self.pending_parsing_blocking_script.take()
+    }
+
+    /// Prépare la construction de l'arbre pour l'algorithme d'analyse des
+    /// fragments HTML et retourne l'élément racine dans lequel le
+    /// fragment sera construit.
+    ///
+    /// Voir <https://html.spec.whatwg.org/multipage/parsing.html#parsing-html-fragments>
+    pub(super) fn prepare_fragment_parsing(
+        &mut self,
+        context_element: &TreeNode<Node>,
+    ) -> TreeNode<Node> {
+        self.parsing_fragment = true;
+        self.context_element.replace(context_element.to_owned());
+
+        // 5. Soit root un nouvel élément html sans attributs.
+        // 6. Ajouter l'élément root au Document créé.
+        // 7. Mettre en place la pile d'éléments ouverts de l'analyseur de
+        // manière à ce qu'elle ne contienne que l'élément root.
+        let root = Document::create_element("html", None)
+            .expect("Un élément DOM HTMLHtmlElement");
+        self.count_created_node();
+        root.set_document(&self.document);
+        self.document.append_child(root.to_owned());
+        self.stack_of_open_elements.put(root.to_owned());
+
+        // 8. Si l'élément de contexte est un élément template, pousser
+        // "in template" sur la pile des modes d'insertion de template.
+        if tag_names::template
+            == context_element.element_ref().local_name()
+        {
+            self.stack_of_template_insertion_modes
+                .push(InsertionMode::InTemplate);
+        }
+
+        // 10. Réinitialiser le mode d'insertion de manière appropriée.
+        self.reset_insertion_mode_appropriately();
+
+        // 11. Définir le pointeur d'élément form de l'analyseur sur le
+        // noeud le plus proche de l'élément de contexte qui est un élément
+        // form (en remontant la chaîne des ancêtres, y compris l'élément
+        // lui-même), s'il y en a un.
+        let mut maybe_node = Some(context_element.to_owned());
+        while let Some(node) = maybe_node {
+            if node.is_element()
+                && tag_names::form == node.element_ref().local_name()
+            {
+                self.form_element_pointer.replace(node);
+                break;
+            }
+            maybe_node = node.parent_node();
+        }
+
+        root
+    }
+
+    /// 4. L'état du tokenizer dépend de l'élément de contexte :
+    ///   - title, textarea : passer le tokenizer à l'état RCDATA.
+    ///   - style, xmp, iframe, noembed, noframes : passer le tokenizer à
+    ///     l'état RAWTEXT.
+    ///   - script : passer le tokenizer à l'état script data.
+    ///   - noscript : si le drapeau de scripting est activé, passer le
+    ///     tokenizer à l'état RAWTEXT. Sinon, laisser le tokenizer à
+    ///     l'état data.
+    ///   - plaintext : passer le tokenizer à l'état PLAINTEXT.
+    ///   - Tout autre élément : laisser le tokenizer à l'état data.
+    #[allow(deprecated)]
+    pub(super) fn fragment_tokenizer_state(
+        &self,
+        context_element: &TreeNode<Node>,
+    ) -> &'static str {
+        let name = context_element.element_ref().local_name();
+
+        if name.is_one_of([tag_names::title, tag_names::textarea]) {
+            "rcdata"
+        } else if name.is_one_of([
+            tag_names::style,
+            tag_names::xmp,
+            tag_names::iframe,
+            tag_names::noembed,
+            tag_names::noframes,
+        ]) {
+            "rawtext"
+        } else if tag_names::script == name {
+            "script-data"
+        } else if tag_names::noscript == name
+            && self.scripting_flag == ScriptingFlag::Enabled
+        {
+            "rawtext"
+        } else if tag_names::plaintext == name {
+            "plaintext"
+        } else {
+            "data"
+        }
+    }
+}
+
 impl HTMLTreeConstruction {
     pub(super) fn dispatcher(
         &mut self,
         token: Option<HTMLToken>,
     ) -> HTMLTreeConstructionControlFlow {
+        // Les caractères consécutifs sont regroupés dans un même noeud
+        // Text : ils lui sont ajoutés dès qu'un autre jeton est traité.
+        if !matches!(token, Some(HTMLToken::Character(_))) {
+            self.flush_character_insertions();
+        }
+
+        self.unacknowledged_self_closing_flag = matches!(
+            token,
+            Some(HTMLToken::Tag {
+                is_end: false,
+                self_closing_flag: true,
+                ..
+            })
+        );
+
         match token {
             | None => ControlFlow::Break(HTMLParserFlag::Stop),
 
@@ -144,6 +392,25 @@ impl HTMLTreeConstruction {
         m: InsertionMode,
         token: HTMLToken,
     ) -> HTMLTreeConstructionControlFlow {
+        let is_implemented = m.is_implemented();
+        #[cfg(test)]
+        let is_implemented =
+            is_implemented && self.unimplemented_insertion_mode != Some(m);
+
+        if !is_implemented {
+            assert!(
+                self.best_effort,
+                "Le mode d'insertion {m:?} n'est pas implémenté."
+            );
+
+            log::warn!(
+                "Le mode d'insertion {m:?} n'est pas implémenté : le \
+                 jeton {token:?} est traité selon les règles du mode \
+                 d'insertion InBody."
+            );
+            return self.handle_in_body_insertion_mode(token);
+        }
+
         match m {
             | InsertionMode::Initial => {
                 self.handle_initial_insertion_mode(token)
@@ -253,7 +520,9 @@ impl HTMLTreeConstruction {
             // A comment token
             //
             // Insérer le commentaire.
-            | HTMLToken::Comment(comment) => self.insert_comment(comment),
+            | HTMLToken::Comment(comment) => {
+                self.insert_comment(comment, None)
+            }
 
             // A DOCTYPE token
             //
@@ -341,21 +610,23 @@ impl HTMLTreeConstruction {
             {
                 self.parse_error(&token);
 
-                let maybe_cnode = self.current_node().cloned();
-
-                if maybe_cnode.is_none() {
+                if self.current_node().is_none() {
                     return HTMLTreeConstructionControlFlow::Continue(
                         HTMLParserState::Ignore,
                     );
                 }
 
-                while let Some(cnode) = maybe_cnode.as_ref() {
-                    if !cnode.is_mathml_text_integration_point()
-                        && !cnode.is_html_text_integration_point()
-                        && !cnode.isin_html_namespace()
+                // NOTE(phisyx): le noeud actuel doit être relu à chaque
+                // itération, puisque la pile est dépilée dans la boucle.
+                while let Some(cnode) = self.current_node() {
+                    if cnode.is_mathml_text_integration_point()
+                        || cnode.is_html_text_integration_point()
+                        || cnode.isin_html_namespace()
                     {
-                        self.stack_of_open_elements.pop();
+                        break;
                     }
+
+                    self.stack_of_open_elements.pop();
                 }
 
                 return self.process_using_the_rules_for(
@@ -433,13 +704,82 @@ impl HTMLTreeConstruction {
                 if tag_names::script == token.name()
                     && cnode.element_ref().isin_svg_namespace()
                 {
-                    token.as_tag_mut().set_acknowledge_self_closing_flag();
+                    self.acknowledge_self_closing_flag(token.as_tag_mut());
                     return self.process_using_the_rules_for(
                         self.insertion_mode,
                         token,
                     );
                 } else {
                     self.stack_of_open_elements.pop();
+                    self.acknowledge_self_closing_flag(token.as_tag_mut());
+                }
+            }
+
+            // Any other end tag
+            //
+            // Exécuter ces étapes :
+            //   1. Initialiser le noeud pour qu'il soit le noeud actuel
+            //      (le noeud le plus bas de la pile).
+            //   2. Si le nom de balise du noeud, converti en minuscules
+            //      ASCII, n'est pas le même que le nom de balise du jeton,
+            //      il s'agit d'une erreur d'analyse.
+            //   3. Boucle : si le noeud est l'élément le plus haut de la
+            //      pile d'éléments ouverts, retourner. (cas fragment)
+            //   4. Si le nom de balise du noeud, converti en minuscules
+            //      ASCII, est le même que le nom de balise du jeton,
+            //      retirer des éléments de la pile d'éléments ouverts
+            //      jusqu'à ce que le noeud ait été retiré, puis retourner.
+            //   5. Définir le noeud comme l'entrée précédente dans la pile
+            //      d'éléments ouverts.
+            //   6. Si le noeud n'est pas un élément de l'espace de noms
+            //      HTML, retourner à l'étape nommée boucle.
+            //   7. Sinon, traiter le jeton selon les règles données dans
+            //      la section correspondant au mode d'insertion actuel
+            //      dans le contenu HTML.
+            //
+            // NOTE(phisyx): la balise de fin "script" d'un élément script
+            // SVG est traitée ici, puisque les scripts ne sont pas
+            // exécutés.
+            | HTMLToken::Tag {
+                ref name,
+                is_end: true,
+                ..
+            } => {
+                let mut index = self.stack_of_open_elements.len() - 1;
+
+                let local_name = self.stack_of_open_elements[index]
+                    .element_ref()
+                    .local_name();
+                if !local_name.eq_ignore_ascii_case(name) {
+                    self.parse_error(&token);
+                }
+
+                loop {
+                    if index == 0 {
+                        break;
+                    }
+
+                    let node = &self.stack_of_open_elements[index];
+                    if node
+                        .element_ref()
+                        .local_name()
+                        .eq_ignore_ascii_case(name)
+                    {
+                        self.stack_of_open_elements.truncate(index);
+                        break;
+                    }
+
+                    index -= 1;
+
+                    if self.stack_of_open_elements[index]
+                        .element_ref()
+                        .isin_html_namespace()
+                    {
+                        return self.process_using_the_rules_for(
+                            self.insertion_mode,
+                            token,
+                        );
+                    }
                 }
             }
 
@@ -677,12 +1017,16 @@ impl HTMLTreeConstruction {
             );
 
             if let Ok(element) = maybe_element.as_ref() {
+                self.count_created_node();
                 element.set_document(document);
 
                 attributes.iter().for_each(|attribute| {
-                    element
-                        .element_ref()
-                        .set_attribute(&attribute.name, &attribute.value);
+                    element.element_ref().set_attribute_ns(
+                        attribute.namespace_uri,
+                        attribute.prefix.as_deref(),
+                        &attribute.name,
+                        &attribute.value,
+                    );
                 });
             }
 
@@ -862,33 +1206,38 @@ impl HTMLTreeConstruction {
             return None;
         }
 
-        let is_text = parent
-            .get_last_child()
-            .as_ref()
-            .filter(|last_child| last_child.is_text())
-            .cloned();
+        // S'il y a un noeud Text immédiatement avant l'emplacement
+        // d'insertion ajusté, les données lui sont ajoutées.
+        let sibling = adjusted_insertion_location.insert_before_sibling;
+        let is_text = match sibling.as_ref() {
+            | Some(sibling) => sibling.prev_sibling(),
+            | None => parent.get_last_child(),
+        }
+        .filter(|node| node.is_text());
 
         if is_text.is_some() {
             return is_text;
         }
 
         let new_text_node = TextNode::new(&self.document, String::new());
-        parent.append_child(new_text_node.to_owned());
+        self.count_created_node();
+        parent.insert_before(new_text_node.to_owned(), sibling.as_ref());
         Some(new_text_node.to_owned())
     }
 
+    /// Ajoute les caractères en attente au noeud Text en cours
+    /// d'insertion, puis vide le tampon : les caractères suivants seront
+    /// insérés dans le noeud Text retourné par
+    /// [HTMLTreeConstruction::find_character_insertion_node].
     fn flush_character_insertions(&mut self) {
-        if self.character_insertion_builder.is_empty() {
-            return;
-        }
-
         if let Some(character_insertion_node) =
-            self.character_insertion_node.as_ref()
+            self.character_insertion_node.take()
         {
             character_insertion_node
-                .set_data(&self.character_insertion_builder);
-            self.character_insertion_builder.clear();
+                .character_data_ref()
+                .append_data(&self.character_insertion_builder);
         }
+        self.character_insertion_builder.clear();
     }
 
     /// <https://html.spec.whatwg.org/multipage/parsing.html#generate-implied-end-tags>
@@ -907,18 +1256,9 @@ impl HTMLTreeConstruction {
             let element = cnode.element_ref();
             let name = element.local_name();
             if predicate(&name)
-                && name.is_one_of([
-                    tag_names::dd,
-                    tag_names::dt,
-                    tag_names::li,
-                    tag_names::optgroup,
-                    tag_names::option,
-                    tag_names::p,
-                    tag_names::rb,
-                    tag_names::rp,
-                    tag_names::rt,
-                    tag_names::rtc,
-                ])
+                && name.is_one_of(
+                    elements_categories::IMPLIED_END_TAGS_ELEMENTS,
+                )
             {
                 self.stack_of_open_elements.pop();
             } else {
@@ -936,6 +1276,63 @@ impl HTMLTreeConstruction {
         });
     }
 
+    /// Lorsque les étapes ci-dessous indiquent que l'agent utilisateur
+    /// doit fermer un élément p, cela signifie que l'agent utilisateur
+    /// doit exécuter les étapes suivantes :
+    ///   1. Générer des balises de fin implicites, sauf pour les éléments
+    ///      p.
+    ///   2. Si le nœud actuel n'est pas un élément p, il s'agit d'une
+    ///      erreur d'analyse.
+    ///   3. Extraire des éléments de la pile des éléments ouverts jusqu'à
+    ///      ce qu'un élément p ait été extrait de la pile.
+    ///
+    /// <https://html.spec.whatwg.org/multipage/parsing.html#close-a-p-element>
+    fn close_a_p_element(&mut self, token: &HTMLToken) {
+        let tag_name = tag_names::p;
+
+        self.generate_implied_end_tags_except_for(tag_name);
+
+        if let Some(cnode) = self.current_node() {
+            if tag_name != cnode.element_ref().local_name() {
+                self.parse_error(token);
+            }
+        }
+
+        self.stack_of_open_elements.pop_until_tag(tag_name);
+    }
+
+    /// Lorsque les étapes ci-dessous indiquent de fermer la cellule,
+    /// elles signifient qu'il faut exécuter l'algorithme suivant :
+    ///   1. Générer des balises de fin implicites.
+    ///   2. Si le nœud actuel n'est pas un élément td ou un élément th, il
+    ///      s'agit d'une erreur d'analyse.
+    ///   3. Retirer des éléments de la pile d'éléments ouverts jusqu'à ce
+    ///      qu'un élément td ou un élément th ait été retiré de la pile.
+    ///   4. Effacer la liste des éléments de mise en forme actifs jusqu'au
+    ///      dernier marqueur.
+    ///   5. Passer le mode d'insertion à "in row".
+    ///
+    /// <https://html.spec.whatwg.org/multipage/parsing.html#close-the-cell>
+    fn close_the_cell(&mut self, token: &HTMLToken) {
+        self.generate_implied_end_tags();
+
+        if let Some(cnode) = self.current_node() {
+            if !cnode
+                .element_ref()
+                .tag_name()
+                .is_one_of([tag_names::td, tag_names::th])
+            {
+                self.parse_error(token);
+            }
+        }
+
+        self.stack_of_open_elements
+            .pop_until_tags([tag_names::td, tag_names::th]);
+        self.list_of_active_formatting_elements
+            .clear_up_to_the_last_marker();
+        self.insertion_mode.switch_to(InsertionMode::InRow);
+    }
+
     /// Lorsque les étapes ci-dessous exigent que l'UA génère de manière
     /// exhaustive toutes les balises de fin implicites, alors, si le noeud
     /// actuel est un élément caption, un élément colgroup, un élément dd,
@@ -947,26 +1344,9 @@ impl HTMLTreeConstruction {
     #[allow(deprecated)]
     fn generate_all_implied_end_tags_thoroughly(&mut self) {
         while let Some(cnode) = self.current_node() {
-            if cnode.element_ref().local_name().is_one_of([
-                tag_names::caption,
-                tag_names::colgroup,
-                tag_names::dd,
-                tag_names::dt,
-                tag_names::li,
-                tag_names::optgroup,
-                tag_names::option,
-                tag_names::p,
-                tag_names::rb,
-                tag_names::rp,
-                tag_names::rt,
-                tag_names::rtc,
-                tag_names::tbody,
-                tag_names::td,
-                tag_names::tfoot,
-                tag_names::th,
-                tag_names::thead,
-                tag_names::tr,
-            ]) {
+            if cnode.element_ref().local_name().is_one_of(
+                elements_categories::THOROUGHLY_IMPLIED_END_TAGS_ELEMENTS,
+            ) {
                 self.stack_of_open_elements.pop();
             } else {
                 break;
@@ -981,29 +1361,40 @@ impl HTMLTreeConstruction {
         if let (Some(a), Some(b)) =
             (maybe_node.as_ref(), self.character_insertion_node.as_ref())
         {
-            if a == b {
+            if Rc::ptr_eq(a, b) {
                 self.character_insertion_builder.push(ch);
                 return;
             }
         }
 
-        if self.character_insertion_node.is_none() {
-            self.character_insertion_node = maybe_node;
-            self.character_insertion_builder.push(ch);
-            return;
-        }
-
         self.flush_character_insertions();
         self.character_insertion_node = maybe_node;
         self.character_insertion_builder.push(ch);
     }
 
     /// <https://html.spec.whatwg.org/multipage/parsing.html#insert-a-comment>
-    fn insert_comment(&self, comment: String) {
+    ///
+    /// Lorsque la position est spécifiée, elle est utilisée comme
+    /// emplacement d'insertion ajusté ; sinon, l'emplacement approprié
+    /// pour insérer un noeud est utilisé.
+    fn insert_comment(
+        &self,
+        comment: String,
+        position: Option<AdjustedInsertionLocation>,
+    ) {
+        // 1. Soit data les données données dans le jeton de commentaire
+        // en cours de traitement.
+        //
+        // 2. Si la position a été spécifiée, l'emplacement d'insertion
+        // ajusté est cette position. Sinon, l'emplacement d'insertion
+        // ajusté est l'emplacement approprié pour insérer un noeud.
         let mut adjusted_insertion_location =
-            self.find_appropriate_place_for_inserting_node(None);
+            position.unwrap_or_else(|| {
+                self.find_appropriate_place_for_inserting_node(None)
+            });
 
         let comment = CommentNode::new(&self.document, comment);
+        self.count_created_node();
 
         if let Some(ref mut parent) = adjusted_insertion_location.parent {
             parent.insert_before(
@@ -1053,26 +1444,34 @@ impl HTMLTreeConstruction {
     }
 
     /// <https://html.spec.whatwg.org/multipage/parsing.html#parse-errors>
-    fn parse_error(&self, token: &HTMLToken) {
-        match token {
-            | HTMLToken::Tag { name, is_end, .. } => {
-                if *is_end {
-                    log::error!("Balise de fin inattendue: {name}");
-                } else {
-                    log::error!("Balise de début inattendue: {name}");
-                }
+    ///
+    /// L'erreur est collectée (voir
+    /// [HTMLTreeConstruction::take_parse_errors]) puis signalée par
+    /// l'analyseur, comme les erreurs du tokenizer.
+    fn parse_error(&mut self, token: &HTMLToken) {
+        let error = match token {
+            | HTMLToken::Tag { is_end: true, .. } => {
+                HTMLParserError::UnexpectedEndTag
             }
-            | HTMLToken::DOCTYPE { .. } => {
-                log::error!("DOCTYPE inattendu")
+            | HTMLToken::Tag { is_end: false, .. } => {
+                HTMLParserError::UnexpectedStartTag
             }
-            | HTMLToken::Comment(_) => {
-                log::error!("Commentaire inattendu")
+            | HTMLToken::DOCTYPE { .. } => {
+                HTMLParserError::UnexpectedDOCTYPE
             }
+            | HTMLToken::Comment(_) => HTMLParserError::UnexpectedComment,
             | HTMLToken::Character(_) => {
-                log::error!("Caractère inattendu")
+                HTMLParserError::UnexpectedCharacter
             }
-            | HTMLToken::EOF => log::error!("End Of File: inattendu"),
-        }
+            | HTMLToken::EOF => HTMLParserError::UnexpectedEof,
+        };
+        self.parse_errors.push(error);
+    }
+
+    /// Retire et retourne les erreurs d'analyse signalées par l'étape de
+    /// construction de l'arbre depuis le dernier appel.
+    pub(super) fn take_parse_errors(&mut self) -> Vec<HTMLParserError> {
+        std::mem::take(&mut self.parse_errors)
     }
 
     /// L'algorithme générique d'analyse syntaxique des éléments de
@@ -1104,6 +1503,33 @@ impl HTMLTreeConstruction {
         )
     }
 
+    /// L'algorithme générique d'analyse syntaxique des éléments de texte
+    /// brut (style, xmp, iframe, noembed, noframes, et noscript lorsque
+    /// le drapeau de scripting est activé).
+    ///
+    /// <https://html.spec.whatwg.org/multipage/parsing.html#generic-raw-text-element-parsing-algorithm>
+    fn follow_generic_raw_text_parsing(
+        &mut self,
+        tag_token: &HTMLToken,
+    ) -> HTMLTreeConstructionControlFlow {
+        self.parse_generic_element(tag_token, HTMLTokenizerState::RAWTEXT)
+    }
+
+    /// L'algorithme générique d'analyse syntaxique des éléments RCDATA
+    /// (title).
+    ///
+    /// NOTE(html): l'élément textarea passe lui aussi le tokenizer à
+    /// l'état RCDATA, mais ses étapes propres ignorent un éventuel saut
+    /// de ligne initial (voir [HTMLParserState::CustomRcdata]).
+    ///
+    /// <https://html.spec.whatwg.org/multipage/parsing.html#generic-rcdata-element-parsing-algorithm>
+    fn follow_generic_rcdata_parsing(
+        &mut self,
+        tag_token: &HTMLToken,
+    ) -> HTMLTreeConstructionControlFlow {
+        self.parse_generic_element(tag_token, HTMLTokenizerState::RCDATA)
+    }
+
     /// Lorsque les étapes ci-dessous exigent que l'UA reconstruise les
     /// éléments de mise en forme actifs, l'UA doit effectuer les étapes
     /// suivantes :
@@ -1362,7 +1788,6 @@ impl HTMLTreeConstruction {
     fn run_adoption_agency_algorithm(
         &mut self,
         token: &HTMLToken,
-        is_special_tag: &impl Fn(tag_names, Namespace) -> bool,
     ) -> bool {
         let subject = token.as_tag().tag_name();
 
@@ -1406,7 +1831,7 @@ impl HTMLTreeConstruction {
             if self.stack_of_open_elements.contains(&formatting_element)
                 && !self.stack_of_open_elements.has_element_in_scope(
                     formatting_element.element_ref().tag_name(),
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 )
             {
                 self.parse_error(token);
@@ -1419,17 +1844,24 @@ impl HTMLTreeConstruction {
                 self.parse_error(token);
             }
 
+            let formatting_element_stack_idx = self
+                .stack_of_open_elements
+                .iter()
+                .position(|el| formatting_element.eq(el))
+                .expect("L'élément de mise en forme");
+
+            // Le bloc le plus éloigné est le noeud le plus haut de la pile
+            // d'éléments ouverts qui est plus bas dans la pile que
+            // l'élément de mise en forme, et qui est un élément de la
+            // catégorie spéciale.
             let maybe_furthest_block = self
                 .stack_of_open_elements
                 .iter()
                 .enumerate()
-                .rfind(|(_, el)| {
-                    if formatting_element.eq(el) {
-                        return false;
-                    }
-
+                .skip(formatting_element_stack_idx + 1)
+                .find(|(_, el)| {
                     let el = el.element_ref();
-                    is_special_tag(
+                    elements_categories::is_special(
                         el.tag_name(),
                         el.namespace().expect(
                             "Devrait être un espace de nom valide",
@@ -1455,21 +1887,13 @@ impl HTMLTreeConstruction {
             let (furthest_block_idx, furthest_block) =
                 maybe_furthest_block.unwrap();
 
-            let common_ancestor: Option<TreeNode<Node>> = {
-                let mut found_node = None;
-                for (index, element) in
-                    self.stack_of_open_elements.iter().rev().enumerate()
-                {
-                    if formatting_element.eq(element) {
-                        if index < self.stack_of_open_elements.len() - 1 {
-                            found_node =
-                                self.stack_of_open_elements.get(index - 1)
-                        }
-                        break;
-                    }
-                }
-                found_node.cloned()
-            };
+            // L'ancêtre commun est l'élément situé immédiatement au-dessus
+            // de l'élément de mise en forme dans la pile d'éléments
+            // ouverts.
+            let common_ancestor: Option<TreeNode<Node>> = self
+                .stack_of_open_elements
+                .element_immediately_above(formatting_element_stack_idx)
+                .map(|(_, element)| element.to_owned());
 
             let mut bookmark = self
                 .list_of_active_formatting_elements
@@ -1599,10 +2023,8 @@ impl HTMLTreeConstruction {
 
 #[cfg(test)]
 mod tests {
-    use dom::node::QuirksMode;
-
     use super::*;
-    use crate::HTMLParser;
+    use crate::{HTMLParser, HTMLParserError};
 
     macro_rules! load_fixture {
         ($filename:literal) => {{
@@ -1667,7 +2089,9 @@ mod tests {
         tree.handle_initial_insertion_mode(token);
         let doc = tree.document.document_ref();
         assert_eq!(*doc.quirks_mode.borrow(), QuirksMode::Yes);
-        assert_eq!(tree.insertion_mode, InsertionMode::BeforeHTML);
+        // Le jeton est retraité à partir du mode "before html", jusqu'à
+        // l'insertion implicite des éléments html, head et body.
+        assert_eq!(tree.insertion_mode, InsertionMode::InBody);
     }
 
     #[test]
@@ -1701,4 +2125,1849 @@ mod tests {
         assert_eq!(tag_names::html, doc.element_ref().local_name());
         assert_ne!(tag_names::head, doc.element_ref().local_name());
     }
+
+    #[test]
+    fn test_in_body_insertion_mode_dd_dt() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <dl><dt><p>a<dd>b<dt>c<div><dd>d</dl>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let dl = body.get_first_child().unwrap();
+        assert_eq!(tag_names::dl, dl.element_ref().local_name());
+
+        // <dt><p>a<dd> : le p est fermé en même temps que le dt.
+        let dt = dl.get_first_child().unwrap();
+        assert_eq!(tag_names::dt, dt.element_ref().local_name());
+        let p = dt.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert!(p.next_sibling().is_none());
+
+        let dd = dt.next_sibling().unwrap();
+        assert_eq!(tag_names::dd, dd.element_ref().local_name());
+
+        // <dt>c<div><dd> : le div ne stoppe pas la remontée de la pile.
+        let dt = dd.next_sibling().unwrap();
+        assert_eq!(tag_names::dt, dt.element_ref().local_name());
+        let div = dt.get_last_child().unwrap();
+        assert_eq!(tag_names::div, div.element_ref().local_name());
+
+        let dd = dt.next_sibling().unwrap();
+        assert_eq!(tag_names::dd, dd.element_ref().local_name());
+        assert!(dd.next_sibling().is_none());
+    }
+
+    #[test]
+    fn test_parse_fragment() {
+        let context_element =
+            Document::create_element("ul", None).unwrap();
+        let fragment = HTMLParser::parse_fragment(
+            &context_element,
+            "<li>a<li>b".chars(),
+        );
+        assert!(fragment.is_document_fragment());
+
+        let li = fragment.get_first_child().unwrap();
+        assert_eq!(tag_names::li, li.element_ref().local_name());
+        assert!(li.parent_node().unwrap().is_document_fragment());
+        assert!(li.get_first_child().unwrap().is_text());
+
+        let li = li.next_sibling().unwrap();
+        assert_eq!(tag_names::li, li.element_ref().local_name());
+        assert!(li.get_first_child().unwrap().is_text());
+        assert!(li.next_sibling().is_none());
+        assert!(li.prev_sibling().is_some());
+
+        let last_li = fragment.get_last_child().unwrap();
+        assert!(std::rc::Rc::ptr_eq(&li, &last_li));
+    }
+
+    #[test]
+    fn test_parse_with_report() {
+        let report = HTMLParser::parse_with_report(
+            "<!DOCTYPE html><html><head></head><body></body></html>",
+        );
+        assert!(report.errors.is_empty());
+        assert!(report.document.get_first_child().is_some());
+
+        let html = "<!DOCTYPE html><html><head></head><body>\
+                    <!--><div a=b c=d\"></div></body></html>";
+        let report = HTMLParser::parse_with_report(html);
+        let errors = report
+            .errors
+            .iter()
+            .map(|(err, position)| (err.to_string(), *position))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                (
+                    "abrupt-closing-of-empty-comment".to_owned(),
+                    html.find("<!-->").unwrap() + 5
+                ),
+                (
+                    "unexpected-character-in-unquoted-attribute-value"
+                        .to_owned(),
+                    html.find('"').unwrap() + 1
+                ),
+            ]
+        );
+
+        // Les erreurs de l'étape de construction de l'arbre sont
+        // également rapportées.
+        let html = "<!DOCTYPE html><html><head></head><body>\
+                    </p></br></body></html>";
+        let report = HTMLParser::parse_with_report(html);
+        let errors = report
+            .errors
+            .iter()
+            .map(|(err, position)| (err.to_string(), *position))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                (
+                    "unexpected-end-tag".to_owned(),
+                    html.find("</p>").unwrap() + 4
+                ),
+                (
+                    "unexpected-end-tag".to_owned(),
+                    html.find("</br>").unwrap() + 5
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_body_and_html_end_tags() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body></body><!--a-->"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        assert!(html.get_last_child().unwrap().is_comment());
+
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body></html><!--b-->"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert!(tree.document.get_last_child().unwrap().is_comment());
+    }
+
+    #[test]
+    fn test_foreign_content_breakout() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <svg><g><circle><p>a</p></svg>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let svg = body.get_first_child().unwrap();
+        assert_eq!(tag_names::svg, svg.element_ref().local_name());
+        assert!(svg.element_ref().isin_svg_namespace());
+
+        let p = svg.next_sibling().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert!(p.element_ref().isin_html_namespace());
+    }
+
+    #[test]
+    fn test_comment_after_body_and_after_html() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body></body>\
+             <!--a--></html><!--b-->"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+
+        let a = html.get_last_child().unwrap();
+        assert!(a.is_comment());
+        assert_eq!(a.character_data_ref().data(), "a");
+
+        let b = tree.document.get_last_child().unwrap();
+        assert!(b.is_comment());
+        assert_eq!(b.character_data_ref().data(), "b");
+        assert!(b.prev_sibling().unwrap().is_element());
+    }
+
+    #[test]
+    fn test_template_end_tag() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <template>x</template><p></p></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert!(tree.stack_of_template_insertion_modes.is_empty());
+
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let template = body.get_first_child().unwrap();
+        assert_eq!(
+            tag_names::template,
+            template.element_ref().local_name()
+        );
+
+        let p = template.next_sibling().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+
+        // Pas de template ouvert : le jeton est ignoré.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             </template><p></p></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let p = body.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+    }
+
+    #[test]
+    fn test_quirks_mode() {
+        fn quirks_mode_of(input: &'static str) -> QuirksMode {
+            let mut parser =
+                HTMLParser::new(DocumentNode::new(), input.chars());
+            parser.run();
+            parser.tree_construction().document.get().quirks_mode()
+        }
+
+        assert_eq!(
+            quirks_mode_of("<!DOCTYPE html><html>"),
+            QuirksMode::No
+        );
+        assert_eq!(
+            quirks_mode_of(
+                "<!DOCTYPE html SYSTEM \"about:legacy-compat\">"
+            ),
+            QuirksMode::No
+        );
+        assert_eq!(quirks_mode_of("<html>"), QuirksMode::Yes);
+        assert_eq!(quirks_mode_of("<!DOCTYPE svg>"), QuirksMode::Yes);
+        assert_eq!(
+            quirks_mode_of(
+                "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01 \
+                 Transitional//EN\">"
+            ),
+            QuirksMode::Yes
+        );
+        assert_eq!(
+            quirks_mode_of(
+                "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01 \
+                 Transitional//EN\" \
+                 \"http://www.w3.org/TR/html4/loose.dtd\">"
+            ),
+            QuirksMode::Limited
+        );
+        assert_eq!(
+            quirks_mode_of(
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 \
+                 Strict//EN\">"
+            ),
+            QuirksMode::No
+        );
+
+        // En mode quirks, un élément table ne ferme pas l'élément p.
+        let mut parser = test_the_str!("<html><body><p><table>");
+        parser.run();
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let p = body.get_first_child().unwrap();
+        let table = p.get_first_child().unwrap();
+        assert_eq!(tag_names::table, table.element_ref().local_name());
+        assert!(tree.is_quirks());
+
+        let mut parser =
+            test_the_str!("<!DOCTYPE html><html><body><p><table>");
+        parser.run();
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let p = body.get_first_child().unwrap();
+        assert!(p.get_first_child().is_none());
+        let table = p.next_sibling().unwrap();
+        assert_eq!(tag_names::table, table.element_ref().local_name());
+        assert!(!tree.is_quirks());
+    }
+
+    #[test]
+    fn test_merge_html_body_attributes() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html lang=fr><head></head>\
+             <body class=a><p><html lang=en dir=rtl>\
+             <body id=b class=c></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let element = html.element_ref();
+        assert_eq!(element.get_attribute("lang").as_deref(), Some("fr"));
+        assert_eq!(element.get_attribute("dir").as_deref(), Some("rtl"));
+
+        let body = html.get_last_child().unwrap();
+        assert_eq!(tag_names::body, body.element_ref().local_name());
+        let element = body.element_ref();
+        assert_eq!(element.get_attribute("class").as_deref(), Some("a"));
+        assert_eq!(element.get_attribute("id").as_deref(), Some("b"));
+
+        let p = body.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert!(p.get_first_child().is_none());
+    }
+
+    #[test]
+    fn test_select_end_tag() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <select><option>a</select>b<p></p>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert_eq!(tree.insertion_mode, InsertionMode::InBody);
+
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let select = body.get_first_child().unwrap();
+        assert_eq!(tag_names::select, select.element_ref().local_name());
+        let option = select.get_first_child().unwrap();
+        assert_eq!(tag_names::option, option.element_ref().local_name());
+
+        let text = select.next_sibling().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), "b");
+
+        // Pas de select ouvert : le jeton est ignoré.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             </select><p></p>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let p = body.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+    }
+
+    #[test]
+    fn test_character_insertions() {
+        // Les caractères en attente sont ajoutés au noeud Text une seule
+        // fois : une balise de fin ignorée ne les duplique pas, et un
+        // commentaire commence un nouveau noeud Text.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <p>a</foo>b</bar>c<!--x-->d</p>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        let p = body.get_first_child().unwrap();
+
+        let text = p.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "abc");
+        let comment = text.next_sibling().unwrap();
+        assert!(comment.is_comment());
+        let text = comment.next_sibling().unwrap();
+        assert_eq!(text.character_data_ref().data(), "d");
+        assert!(text.next_sibling().is_none());
+    }
+
+    #[test]
+    fn test_pending_table_character_tokens() {
+        // Que des espaces blancs : les caractères sont insérés dans la
+        // table.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <table> \n<tr></tr></table></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let table = body.get_first_child().unwrap();
+        assert_eq!(tag_names::table, table.element_ref().local_name());
+        let text = table.get_first_child().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), " \n");
+    }
+
+    #[test]
+    fn test_foster_parented_character_tokens() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <table>foo<tr></tr></table></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let text = body.get_first_child().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), "foo");
+
+        let table = text.next_sibling().unwrap();
+        assert_eq!(tag_names::table, table.element_ref().local_name());
+        assert_eq!(table.prev_sibling().as_ref(), Some(&text));
+        assert!(table.next_sibling().is_none());
+    }
+
+    #[test]
+    fn test_run_strict() {
+        let mut parser = HTMLParser::new(
+            DocumentNode::new(),
+            "<!DOCTYPE html><html><head></head><body></body></html>"
+                .chars(),
+        );
+        assert_eq!(parser.run_strict(), Ok(()));
+
+        let html = "<!DOCTYPE html><html><head></head><body>\
+                    <!--><div a=b c=d\"></div></body></html>";
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), html.chars());
+        assert_eq!(
+            parser.run_strict(),
+            Err((
+                HTMLParserError::AbruptClosingOfEmptyComment,
+                html.find("<!-->").unwrap() + 5
+            ))
+        );
+        // L'analyse s'est arrêtée avant l'élément div.
+        let body = parser
+            .tree_construction()
+            .document
+            .get_first_child()
+            .unwrap()
+            .get_last_child()
+            .unwrap();
+        assert!(body.get_first_child().is_none());
+
+        // Le mode permissif reste le comportement par défaut.
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), html.chars());
+        parser.run();
+        assert_eq!(parser.take_parse_errors().len(), 2);
+
+        // Les erreurs de l'étape de construction de l'arbre arrêtent
+        // également l'analyse.
+        let html = "<!DOCTYPE html><html><head></head><body>\
+                    <p></div><span></span></body></html>";
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), html.chars());
+        assert_eq!(
+            parser.run_strict(),
+            Err((
+                HTMLParserError::UnexpectedEndTag,
+                html.find("</div>").unwrap() + 6
+            ))
+        );
+        let body = parser.tree_construction().document.body().unwrap();
+        let p = body.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert!(p.next_sibling().is_none());
+        assert!(p.get_first_child().is_none());
+    }
+
+    #[test]
+    fn test_option_optgroup_in_body() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <option>a<option>b<optgroup><option>c</body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let first = body.get_first_child().unwrap();
+        assert_eq!(tag_names::option, first.element_ref().local_name());
+        assert_eq!(
+            first.get_first_child().unwrap().character_data_ref().data(),
+            "a"
+        );
+
+        let second = first.next_sibling().unwrap();
+        assert_eq!(tag_names::option, second.element_ref().local_name());
+        assert_eq!(
+            second
+                .get_first_child()
+                .unwrap()
+                .character_data_ref()
+                .data(),
+            "b"
+        );
+
+        let optgroup = second.next_sibling().unwrap();
+        assert_eq!(
+            tag_names::optgroup,
+            optgroup.element_ref().local_name()
+        );
+        assert!(optgroup.next_sibling().is_none());
+
+        let option = optgroup.get_first_child().unwrap();
+        assert_eq!(tag_names::option, option.element_ref().local_name());
+    }
+
+    #[test]
+    fn test_meta_element_attributes() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head>\
+             <meta charset=\"utf-8\">\
+             <meta name=\"description\" content=\"x\">\
+             </head><body></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let head = html.get_first_child().unwrap();
+
+        let charset = head.get_first_child().unwrap();
+        let meta = charset.meta_ref();
+        assert_eq!(meta.charset().as_deref(), Some("utf-8"));
+        assert_eq!(meta.name(), None);
+        assert_eq!(meta.content(), None);
+        assert_eq!(meta.http_equiv(), None);
+
+        let description = charset.next_sibling().unwrap();
+        let meta = description.meta_ref();
+        assert_eq!(meta.charset(), None);
+        assert_eq!(meta.name().as_deref(), Some("description"));
+        assert_eq!(meta.content().as_deref(), Some("x"));
+    }
+
+    #[test]
+    fn test_visible_text() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head><title>titre</title>\
+             <style>p { color: red; }</style></head><body>\
+             <p>Hello <b>world</b></p><script>alert(1)</script>\
+             <div>a<br>b</div><ul><li>c</li><li>d</li></ul>e\
+             </body></html>"
+        );
+        parser.run();
+
+        let document = parser.tree_construction().document.clone();
+        assert_eq!(document.visible_text(), "Hello world\na\nb\nc\nd\ne");
+    }
+
+    #[test]
+    fn test_annotation_xml_html_integration_point() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <math><annotation-xml encoding=\"text/html\"><div>a\
+             </div></annotation-xml></math></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let math = body.get_first_child().unwrap();
+        let annotation_xml = math.get_first_child().unwrap();
+        assert!(!annotation_xml.element_ref().isin_html_namespace());
+        assert!(annotation_xml
+            .element_ref()
+            .is_html_text_integration_point());
+
+        let div = annotation_xml.get_first_child().unwrap();
+        assert_eq!(tag_names::div, div.element_ref().local_name());
+        assert!(div.element_ref().isin_html_namespace());
+
+        // Sans attribut encoding, annotation-xml n'est pas un point
+        // d'intégration HTML : l'élément div sort du contenu étranger.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <math><annotation-xml><div>a</div></annotation-xml></math>\
+             </body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let math = body.get_first_child().unwrap();
+        let annotation_xml = math.get_first_child().unwrap();
+        assert!(!annotation_xml
+            .element_ref()
+            .is_html_text_integration_point());
+        assert!(annotation_xml.get_first_child().is_none());
+
+        let div = math.next_sibling().unwrap();
+        assert_eq!(tag_names::div, div.element_ref().local_name());
+        assert!(div.element_ref().isin_html_namespace());
+    }
+
+    #[test]
+    fn test_implied_head_element() {
+        for input in ["<body>x", "x", "<html>x</html>"] {
+            let mut parser =
+                HTMLParser::new(DocumentNode::new(), input.chars());
+            parser.run();
+
+            let tree = parser.tree_construction();
+            let html = tree.document.get_first_child().unwrap();
+            assert_eq!(tag_names::html, html.element_ref().local_name());
+
+            let head = html.get_first_child().unwrap();
+            assert_eq!(tag_names::head, head.element_ref().local_name());
+            assert!(head.get_first_child().is_none());
+            assert_eq!(tree.head_element_pointer.as_ref(), Some(&head));
+
+            let body = head.next_sibling().unwrap();
+            assert_eq!(tag_names::body, body.element_ref().local_name());
+            assert!(body.next_sibling().is_none());
+
+            let text = body.get_first_child().unwrap();
+            assert_eq!(text.character_data_ref().data(), "x");
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_noframes_noembed_rawtext() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <noframes><p>x</noframes><noembed><b>y</noembed>\
+             <p>z</p></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let noframes = body.get_first_child().unwrap();
+        assert_eq!(
+            tag_names::noframes,
+            noframes.element_ref().local_name()
+        );
+        let text = noframes.get_first_child().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), "<p>x");
+        assert!(text.next_sibling().is_none());
+
+        let noembed = noframes.next_sibling().unwrap();
+        assert_eq!(tag_names::noembed, noembed.element_ref().local_name());
+        let text = noembed.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "<b>y");
+
+        // Le mode d'insertion d'origine est rétabli à la fermeture.
+        let p = noembed.next_sibling().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert_eq!(
+            p.get_first_child().unwrap().character_data_ref().data(),
+            "z"
+        );
+    }
+
+    #[test]
+    fn test_insert_before_and_remove_child() {
+        use dom::{exception::DOMException, node::NodeMutationInterface};
+
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <div><p>a</p><hr></div><span></span></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let div = body.get_first_child().unwrap();
+        let span = body.get_last_child().unwrap();
+        let p = div.get_first_child().unwrap();
+        let hr = div.get_last_child().unwrap();
+
+        // L'enfant de référence doit être un enfant du parent.
+        assert!(matches!(
+            span.try_insert_before(p.clone(), Some(&hr)),
+            Err(DOMException::NotFoundError)
+        ));
+        assert_eq!(p.parent_node().as_ref(), Some(&div));
+
+        assert!(div.try_insert_before(hr.clone(), Some(&p)).is_ok());
+        assert!(std::rc::Rc::ptr_eq(&div.get_first_child().unwrap(), &hr));
+        assert!(std::rc::Rc::ptr_eq(&div.get_last_child().unwrap(), &p));
+
+        // Insérer un noeud avant lui-même ne change rien.
+        assert!(div.try_insert_before(hr.clone(), Some(&hr)).is_ok());
+        assert!(std::rc::Rc::ptr_eq(&div.get_first_child().unwrap(), &hr));
+        assert!(std::rc::Rc::ptr_eq(&hr.next_sibling().unwrap(), &p));
+
+        assert!(matches!(
+            span.remove_child(&p),
+            Err(DOMException::NotFoundError)
+        ));
+        let removed = div.remove_child(&p).unwrap();
+        assert!(std::rc::Rc::ptr_eq(&removed, &p));
+        assert!(p.parent_node().is_none());
+        assert!(hr.next_sibling().is_none());
+        assert!(std::rc::Rc::ptr_eq(&div.get_last_child().unwrap(), &hr));
+    }
+
+    #[test]
+    fn test_on_script_hook() {
+        use std::{cell::RefCell, rc::Rc};
+
+        let scripts: Rc<RefCell<Vec<String>>> = Rc::default();
+        let mut parser = HTMLParser::new(
+            DocumentNode::new(),
+            "<!DOCTYPE html><html><head><script>let a = 1 < 2;</script>\
+             </head><body><script src=\"a.js\"></script>\
+             <script></script></body></html>"
+                .chars(),
+        );
+        parser.on_script({
+            let scripts = scripts.clone();
+            move |_: &_, text: &str| {
+                RefCell::borrow_mut(&scripts).push(text.to_owned())
+            }
+        });
+        parser.run();
+
+        // Le script externe (avec un attribut src) n'est pas exécuté par
+        // le hook ; le script vide l'est.
+        assert_eq!(*scripts.borrow(), ["let a = 1 < 2;", ""]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_pre_listing_initial_line_feed() {
+        let mut parser = HTMLParser::new(
+            DocumentNode::new(),
+            "<!DOCTYPE html><html><head></head><body>\
+             <listing>\ncode\n</listing><pre>\n\ncode</pre></body></html>"
+                .chars(),
+        );
+        parser.run();
+
+        let body = parser
+            .tree_construction()
+            .document
+            .get_first_child()
+            .unwrap()
+            .get_last_child()
+            .unwrap();
+
+        let listing = body.get_first_child().unwrap();
+        assert_eq!(tag_names::listing, listing.element_ref().local_name());
+        let text = listing.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "code\n");
+
+        // Seul le premier saut de ligne est ignoré.
+        let pre = listing.next_sibling().unwrap();
+        let text = pre.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "\ncode");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_xmp_rawtext() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><head></head><body>\
+             <p>a<xmp><b>x</xmp></body></html>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert_eq!(tree.frameset_ok_flag, FramesetOkFlag::NotOk);
+
+        let body =
+            tree.document.get_first_child().unwrap().get_last_child();
+        let p = body.unwrap().get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert_eq!(
+            p.get_first_child().unwrap().character_data_ref().data(),
+            "a"
+        );
+
+        // Le paragraphe est fermé avant l'insertion de l'élément xmp, dont
+        // le contenu est du texte brut.
+        let xmp = p.next_sibling().unwrap();
+        assert_eq!(tag_names::xmp, xmp.element_ref().local_name());
+        let text = xmp.get_first_child().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), "<b>x");
+        assert!(text.next_sibling().is_none());
+    }
+
+    #[test]
+    fn test_streaming_feed() {
+        use crate::serialize_html_fragment;
+
+        let html = "<!DOCTYPE html><html><head><title>a &amp; b</title>\
+                    <script>let a = 1 < 2;</script></head><body>\
+                    <!-- commentaire --><p class='x'>Hello &copy; <b>world\
+                    </b></p><textarea>\nfoo</textarea>\
+                    <pre>\nbar</pre><table><tr><td>1</td></tr></table>\
+                    </body></html>";
+
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), html.chars());
+        parser.run();
+        let expected =
+            serialize_html_fragment(&parser.tree_construction().document);
+
+        let (first, second) = html.split_at(html.len() / 2);
+        let mut parser = HTMLParser::streaming(DocumentNode::new());
+        parser.feed(first);
+        parser.feed(second);
+        parser.end();
+        let document = &parser.tree_construction().document;
+        assert_eq!(serialize_html_fragment(document), expected);
+
+        // Caractère par caractère : le tokenizer ne lit jamais une fin
+        // provisoire de l'entrée.
+        let mut parser = HTMLParser::streaming(DocumentNode::new());
+        html.char_indices().for_each(|(idx, ch)| {
+            parser.feed(&html[idx..idx + ch.len_utf8()])
+        });
+        parser.end();
+        let document = &parser.tree_construction().document;
+        assert_eq!(serialize_html_fragment(document), expected);
+        assert!(expected.contains("<textarea>foo</textarea>"));
+    }
+
+    #[test]
+    fn test_create_text_node_and_comment() {
+        let mut parser =
+            test_the_str!("<!DOCTYPE html><html><body><p></p></body>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let p = html.get_last_child().unwrap().get_first_child().unwrap();
+
+        let text = tree.document.create_text_node("Hello");
+        let comment = tree.document.create_comment("commentaire");
+        assert!(text.is_text());
+        assert!(comment.is_comment());
+
+        for node in [&text, &comment] {
+            let owner_document = node.owner_document().unwrap();
+            assert!(std::rc::Rc::ptr_eq(&owner_document, &tree.document));
+            assert!(node.parent_node().is_none());
+        }
+
+        p.append_child(text.clone());
+        p.append_child(comment.clone());
+        assert_eq!(text.parent_node().as_ref(), Some(&p));
+        assert!(std::rc::Rc::ptr_eq(
+            &p.get_last_child().unwrap(),
+            &comment
+        ));
+        assert_eq!(text.character_data_ref().data(), "Hello");
+        assert_eq!(comment.character_data_ref().data(), "commentaire");
+    }
+
+    #[test]
+    fn test_owner_document_and_root() {
+        let mut parser =
+            test_the_str!("<!DOCTYPE html><html><body><p>a</p></body>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let p = html.get_last_child().unwrap().get_first_child().unwrap();
+        let text = p.get_first_child().unwrap();
+
+        assert_eq!(text.owner_document().as_ref(), Some(&tree.document));
+        assert!(std::rc::Rc::ptr_eq(&text.get_root(), &tree.document));
+
+        // Un noeud détaché conserve son document de noeud, mais n'en est
+        // plus un descendant.
+        p.detach_node();
+        assert!(std::rc::Rc::ptr_eq(&text.get_root(), &p));
+        assert!(text.get_root().parent_node().is_none());
+        assert_eq!(text.owner_document().as_ref(), Some(&tree.document));
+    }
+
+    #[test]
+    fn test_implied_li_dd_dt_end_tags() {
+        fn children_names(node: &TreeNode<Node>) -> Vec<String> {
+            let mut names = Vec::new();
+            node.foreach_child(|child| {
+                if child.is_element() {
+                    names.push(child.element_ref().local_name());
+                }
+            });
+            names
+        }
+
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><body><ul><li>a<li>b</ul>\
+             <dl><dt>a<dd>b<dt>c</dl>\
+             <ol><li><div>a<li>b</ol>\
+             <ul><li><ul><li>a</ul></ul></body>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        assert_eq!(children_names(&body), ["ul", "dl", "ol", "ul"]);
+
+        let ul = body.get_first_child().unwrap();
+        assert_eq!(children_names(&ul), ["li", "li"]);
+        let li = ul.get_first_child().unwrap();
+        assert!(li.get_first_child().unwrap().is_text());
+
+        let dl = ul.next_sibling().unwrap();
+        assert_eq!(children_names(&dl), ["dt", "dd", "dt"]);
+
+        // Un élément div n'arrête pas la recherche de l'élément li.
+        let ol = dl.next_sibling().unwrap();
+        assert_eq!(children_names(&ol), ["li", "li"]);
+
+        // Un élément ul (catégorie spéciale) l'arrête : les éléments li
+        // sont imbriqués.
+        let ul = ol.next_sibling().unwrap();
+        assert_eq!(children_names(&ul), ["li"]);
+        let nested_ul = ul.get_first_child().unwrap().get_first_child();
+        assert_eq!(children_names(&nested_ul.unwrap()), ["li"]);
+    }
+
+    #[test]
+    fn test_close_a_p_element_before_block() {
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><html><body><p>a<div>b</div>\
+             <p>c<h1>d</h1><p>e<ul><li>f</ul>\
+             <p>g<button><div>h</div></button></body>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let mut names = Vec::new();
+        body.foreach_child(|child| {
+            names.push(child.element_ref().local_name());
+        });
+        assert_eq!(names, ["p", "div", "p", "h1", "p", "ul", "p"]);
+
+        let p = body.get_first_child().unwrap();
+        assert!(p.get_first_child().unwrap().is_text());
+        assert!(p.get_first_child().unwrap().next_sibling().is_none());
+
+        // L'élément button délimite la portée : l'élément p ouvert avant
+        // lui n'est pas fermé par l'élément div.
+        let p = body.get_last_child().unwrap();
+        let button = p.get_last_child().unwrap();
+        assert_eq!(tag_names::button, button.element_ref().local_name());
+        let div = button.get_first_child().unwrap();
+        assert_eq!(tag_names::div, div.element_ref().local_name());
+    }
+
+    #[test]
+    fn test_parse_utf8_bytes() {
+        use infra::primitive::codepoint::UTF8CodePoints;
+
+        let bytes = "<!DOCTYPE html><p>café €</p>".as_bytes();
+        let mut parser = HTMLParser::new(
+            DocumentNode::new(),
+            UTF8CodePoints::new(bytes),
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert_eq!(tree.document.visible_text(), "café €");
+    }
+
+    #[test]
+    fn test_noscript_in_body() {
+        // Drapeau de scripting désactivé : noscript est un élément
+        // ordinaire.
+        let mut parser = test_the_str!("<body><noscript><p>x");
+        parser.scripting(false);
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert!(!tree.is_scripting_enabled());
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let noscript = body.get_first_child().unwrap();
+        assert_eq!(
+            tag_names::noscript,
+            noscript.element_ref().local_name()
+        );
+        let p = noscript.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert_eq!(
+            p.get_first_child().unwrap().character_data_ref().data(),
+            "x"
+        );
+
+        // Drapeau de scripting activé : le contenu est du texte brut.
+        let mut parser = test_the_str!("<body><noscript><p>x</noscript>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        assert!(tree.is_scripting_enabled());
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let noscript = body.get_first_child().unwrap();
+        let text = noscript.get_first_child().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), "<p>x");
+    }
+
+    #[test]
+    fn test_close_the_cell() {
+        let mut parser =
+            test_the_str!("<table><tr><td>a<td><p>b<th>c</table>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let table = body.get_first_child().unwrap();
+        let tbody = table.get_first_child().unwrap();
+        assert_eq!(tag_names::tbody, tbody.element_ref().local_name());
+        let tr = tbody.get_first_child().unwrap();
+        assert_eq!(tag_names::tr, tr.element_ref().local_name());
+        assert!(tr.next_sibling().is_none());
+
+        let td1 = tr.get_first_child().unwrap();
+        assert_eq!(tag_names::td, td1.element_ref().local_name());
+        assert_eq!(
+            td1.get_first_child().unwrap().character_data_ref().data(),
+            "a"
+        );
+
+        // La cellule courante est fermée, même si un élément p (dont la
+        // balise de fin est implicite) est encore ouvert.
+        let td2 = td1.next_sibling().unwrap();
+        assert_eq!(tag_names::td, td2.element_ref().local_name());
+        let p = td2.get_first_child().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+
+        let th = td2.next_sibling().unwrap();
+        assert_eq!(tag_names::th, th.element_ref().local_name());
+        assert_eq!(
+            th.get_first_child().unwrap().character_data_ref().data(),
+            "c"
+        );
+        assert!(th.next_sibling().is_none());
+    }
+
+    #[test]
+    fn test_generic_rcdata_and_raw_text_parsing() {
+        let mut parser = test_the_str!(
+            "<head><title>a &amp; <b></title>\
+             <style>a &amp; <b></style></head>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let head = html.get_first_child().unwrap();
+
+        // RCDATA : les références de caractères sont décodées, mais pas
+        // les balises.
+        let title = head.get_first_child().unwrap();
+        assert_eq!(tag_names::title, title.element_ref().local_name());
+        let text = title.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "a & <b>");
+        assert!(text.next_sibling().is_none());
+
+        // RAWTEXT : ni les références de caractères, ni les balises.
+        let style = title.next_sibling().unwrap();
+        assert_eq!(tag_names::style, style.element_ref().local_name());
+        let text = style.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "a &amp; <b>");
+
+        // Le mode d'insertion d'origine est rétabli : la balise de fin
+        // head ferme l'élément head.
+        assert!(style.next_sibling().is_none());
+        let body = head.next_sibling().unwrap();
+        assert_eq!(tag_names::body, body.element_ref().local_name());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_open_elements_debug() {
+        let mut parser = test_the_str!("<div><p><b>x");
+        parser.run();
+        assert_eq!(
+            parser.tree_construction().open_elements_debug(),
+            ["html", "body", "div", "p", "b"]
+        );
+
+        let mut parser = test_the_str!("<table><tr><td><svg><circle>");
+        parser.run();
+        assert_eq!(
+            parser.tree_construction().open_elements_debug(),
+            [
+                "html", "body", "table", "tbody", "tr", "td", "svg",
+                "circle"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tag_names_comparisons() {
+        assert_eq!(tag_names::div.as_str(), "div");
+        assert_eq!(tag_names::annotationXml.as_str(), "annotation-xml");
+        assert_eq!(tag_names::annotationXml.to_string(), "annotation-xml");
+
+        assert!(tag_names::title.eq_ascii("TiTlE"));
+        assert!(tag_names::annotationXml.eq_ascii("Annotation-XML"));
+        // Seules les lettres ASCII sont insensibles à la casse.
+        assert!(!tag_names::title.eq_ascii("TİTLE"));
+        assert!(!tag_names::title.eq_ascii("titl"));
+
+        assert!(tag_names::td == "td");
+        assert!(tag_names::td != "TD");
+        assert!("th".is_one_of([tag_names::td, tag_names::th]));
+        assert!(!"tr".is_one_of([tag_names::td, tag_names::th]));
+
+        // Les identifiants publics du DOCTYPE sont comparés sans tenir
+        // compte de la casse ASCII.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html PUBLIC \"-//w3c//DTD HTML 3.2 Final//EN\">"
+        );
+        parser.run();
+        assert!(parser.tree_construction().is_quirks());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_xmp_iframe_noembed_raw_text() {
+        let mut parser = test_the_str!(
+            "<body><xmp><p>not a tag</xmp>\
+             <iframe><b>x</b></iframe><noembed><i>y</noembed><p>z"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+
+        let mut element = body.get_first_child();
+        for (name, data) in [
+            (tag_names::xmp, "<p>not a tag"),
+            (tag_names::iframe, "<b>x</b>"),
+            (tag_names::noembed, "<i>y"),
+        ] {
+            let node = element.unwrap();
+            assert_eq!(name, node.element_ref().local_name());
+            let text = node.get_first_child().unwrap();
+            assert!(text.is_text());
+            assert_eq!(text.character_data_ref().data(), data);
+            assert!(text.next_sibling().is_none());
+            element = node.next_sibling();
+        }
+
+        // Le mode d'insertion d'origine ("in body") est rétabli après
+        // chaque élément : le dernier p est un élément.
+        let p = element.unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+        assert_eq!(
+            p.get_first_child().unwrap().character_data_ref().data(),
+            "z"
+        );
+        assert_eq!(tree.insertion_mode, InsertionMode::InBody);
+    }
+
+    #[test]
+    fn test_non_void_element_start_tag_with_trailing_solidus() {
+        use crate::HTMLParserError;
+
+        let mut parser = test_the_str!("<!DOCTYPE html><body><div/>x");
+        parser.run();
+        assert_eq!(
+            parser
+                .take_parse_errors()
+                .into_iter()
+                .map(|(error, _)| error)
+                .collect::<Vec<_>>(),
+            [HTMLParserError::NonVoidHTMLElementStartTagWithTrailingSolidus]
+        );
+
+        // La balise ouvre l'élément.
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let div = body.get_first_child().unwrap();
+        assert_eq!(tag_names::div, div.element_ref().local_name());
+        assert_eq!(
+            div.get_first_child().unwrap().character_data_ref().data(),
+            "x"
+        );
+
+        // Le drapeau est reconnu pour les éléments vides et les éléments
+        // étrangers.
+        let mut parser = test_the_str!(
+            "<!DOCTYPE html><head><meta charset=utf-8/><link/></head>\
+             <body><br/><img/><hr/><svg><path/></svg>\
+             <table><col/></table>"
+        );
+        parser.run();
+        assert!(parser.take_parse_errors().is_empty());
+    }
+
+    #[test]
+    fn test_in_column_group() {
+        let mut parser = test_the_str!(
+            "<table><colgroup> <col><col></colgroup><tr><td>x</table>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let table = body.get_first_child().unwrap();
+        let colgroup = table.get_first_child().unwrap();
+        assert_eq!(
+            tag_names::colgroup,
+            colgroup.element_ref().local_name()
+        );
+
+        let whitespace = colgroup.get_first_child().unwrap();
+        assert_eq!(whitespace.character_data_ref().data(), " ");
+        let col1 = whitespace.next_sibling().unwrap();
+        assert_eq!(tag_names::col, col1.element_ref().local_name());
+        assert!(col1.get_first_child().is_none());
+        let col2 = col1.next_sibling().unwrap();
+        assert_eq!(tag_names::col, col2.element_ref().local_name());
+        assert!(col2.get_first_child().is_none());
+        assert!(col2.next_sibling().is_none());
+
+        let tbody = colgroup.next_sibling().unwrap();
+        assert_eq!(tag_names::tbody, tbody.element_ref().local_name());
+
+        // Toute autre balise ferme implicitement l'élément colgroup puis
+        // est retraitée dans le mode d'insertion "in table".
+        let mut parser = test_the_str!("<table><colgroup><col><tr>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.get_first_child().unwrap();
+        let body = html.get_last_child().unwrap();
+        let table = body.get_first_child().unwrap();
+        let colgroup = table.get_first_child().unwrap();
+        assert_eq!(
+            tag_names::colgroup,
+            colgroup.element_ref().local_name()
+        );
+        assert_eq!(
+            tag_names::col,
+            colgroup
+                .get_first_child()
+                .unwrap()
+                .element_ref()
+                .local_name()
+        );
+        let tbody = colgroup.next_sibling().unwrap();
+        assert_eq!(tag_names::tbody, tbody.element_ref().local_name());
+        assert_eq!(
+            tag_names::tr,
+            tbody.get_first_child().unwrap().element_ref().local_name()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_document_element_head_body() {
+        let document = DocumentNode::new();
+        assert!(document.document_element().is_none());
+        assert!(document.head().is_none());
+        assert!(document.body().is_none());
+
+        let mut parser =
+            test_the_str!("<!DOCTYPE html><title>t</title><p>x");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let html = tree.document.document_element().unwrap();
+        assert_eq!(tag_names::html, html.element_ref().local_name());
+
+        let head = tree.document.head().unwrap();
+        assert_eq!(tag_names::head, head.element_ref().local_name());
+        assert_eq!(
+            tag_names::title,
+            head.get_first_child().unwrap().element_ref().local_name()
+        );
+
+        let body = tree.document.body().unwrap();
+        assert_eq!(tag_names::body, body.element_ref().local_name());
+        assert_eq!(
+            tag_names::p,
+            body.get_first_child().unwrap().element_ref().local_name()
+        );
+
+        let mut parser = test_the_str!("<frameset></frameset>");
+        parser.run();
+        let tree = parser.tree_construction();
+        assert_eq!(
+            tag_names::frameset,
+            tree.document.body().unwrap().element_ref().local_name()
+        );
+    }
+
+    #[test]
+    fn test_in_select() {
+        let mut parser = test_the_str!(
+            "<select><option>a<option>b<optgroup><option>c<hr>\
+             <option>d</select><p>e"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        let select = body.get_first_child().unwrap();
+        assert_eq!(tag_names::select, select.element_ref().local_name());
+
+        let option_a = select.get_first_child().unwrap();
+        assert_eq!(tag_names::option, option_a.element_ref().local_name());
+        assert_eq!(
+            option_a
+                .get_first_child()
+                .unwrap()
+                .character_data_ref()
+                .data(),
+            "a"
+        );
+        let option_b = option_a.next_sibling().unwrap();
+        assert_eq!(tag_names::option, option_b.element_ref().local_name());
+        assert_eq!(
+            option_b
+                .get_first_child()
+                .unwrap()
+                .character_data_ref()
+                .data(),
+            "b"
+        );
+
+        let optgroup = option_b.next_sibling().unwrap();
+        assert_eq!(
+            tag_names::optgroup,
+            optgroup.element_ref().local_name()
+        );
+        let option_c = optgroup.get_first_child().unwrap();
+        assert_eq!(tag_names::option, option_c.element_ref().local_name());
+        assert!(option_c.next_sibling().is_none());
+
+        // <hr> ferme les éléments option et optgroup ouverts.
+        let hr = optgroup.next_sibling().unwrap();
+        assert_eq!(tag_names::hr, hr.element_ref().local_name());
+        assert!(hr.get_first_child().is_none());
+        let option_d = hr.next_sibling().unwrap();
+        assert_eq!(tag_names::option, option_d.element_ref().local_name());
+
+        let p = select.next_sibling().unwrap();
+        assert_eq!(tag_names::p, p.element_ref().local_name());
+
+        // Un élément select imbriqué ferme l'élément select ouvert.
+        let mut parser =
+            test_the_str!("<select><option>a<select><option>b");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        let select = body.get_first_child().unwrap();
+        assert_eq!(tag_names::select, select.element_ref().local_name());
+        assert_eq!(select.get_first_child().unwrap().next_sibling(), None);
+        let option_b = select.next_sibling().unwrap();
+        assert_eq!(tag_names::option, option_b.element_ref().local_name());
+
+        // Dans une table, les balises de ligne ou de cellule ferment
+        // l'élément select.
+        let mut parser =
+            test_the_str!("<table><tr><td><select><option>a<td>b</table>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        let table = body.get_first_child().unwrap();
+        let tr =
+            table.get_first_child().unwrap().get_first_child().unwrap();
+        let td1 = tr.get_first_child().unwrap();
+        assert_eq!(
+            tag_names::select,
+            td1.get_first_child().unwrap().element_ref().local_name()
+        );
+        let td2 = td1.next_sibling().unwrap();
+        assert_eq!(tag_names::td, td2.element_ref().local_name());
+        assert_eq!(
+            td2.get_first_child().unwrap().character_data_ref().data(),
+            "b"
+        );
+    }
+
+    #[test]
+    fn test_image_start_tag_as_img() {
+        let mut parser = test_the_str!("<p><image src=x alt=y/>z");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let p = tree.document.body().unwrap().get_first_child().unwrap();
+        let img = p.get_first_child().unwrap();
+        assert_eq!(tag_names::img, img.element_ref().local_name());
+        assert_eq!(img.img_ref().src().as_deref(), Some("x"));
+        assert_eq!(img.img_ref().alt().as_deref(), Some("y/"));
+
+        // L'élément img est vide : le texte suivant est son frère.
+        assert!(img.get_first_child().is_none());
+        assert_eq!(
+            img.next_sibling().unwrap().character_data_ref().data(),
+            "z"
+        );
+    }
+
+    #[test]
+    fn test_max_open_elements() {
+        // html, body et 510 div : la limite par défaut n'est pas
+        // dépassée.
+        let html = "<!DOCTYPE html>".to_owned() + &"<div>".repeat(510);
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), html.chars());
+        parser.run();
+        assert!(parser.take_parse_errors().is_empty());
+
+        // L'imbrication démesurée arrête proprement l'analyse, sans
+        // épuiser la pile d'appels.
+        let html =
+            "<!DOCTYPE html>".to_owned() + &"<div>".repeat(10_000) + "x";
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), html.chars());
+        parser.run();
+        assert!(matches!(
+            parser.take_parse_errors().as_slice(),
+            [(HTMLParserError::MaxOpenElementsExceeded, _)]
+        ));
+
+        let mut depth = 0;
+        let mut node = parser.tree_construction().document.body();
+        while let Some(div) = node.and_then(|body| body.get_first_child())
+        {
+            assert_eq!(tag_names::div, div.element_ref().local_name());
+            depth += 1;
+            node = Some(div);
+        }
+        // L'élément qui dépasse la limite est retiré de l'arbre.
+        assert_eq!(depth, 510);
+
+        let mut parser =
+            HTMLParser::new(DocumentNode::new(), "<p><b><i>x".chars());
+        assert!(parser.max_open_elements(4).run_strict().is_err());
+    }
+
+    #[test]
+    fn test_style_and_script_text() {
+        use dom::node::NodeTextContentInterface;
+
+        let mut parser = test_the_str!(
+            "<style>.a{}</style><script>if (a < b) x()</script>\
+             <p>a<b>b</b></p>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let head = tree.document.head().unwrap();
+        let style = head.get_first_child().unwrap();
+        assert_eq!(style.style_text_content().as_deref(), Some(".a{}"));
+        assert_eq!(style.style_ref().tag_name(), "style");
+        assert_eq!(style.script_text(), None);
+
+        let script = style.next_sibling().unwrap();
+        assert_eq!(
+            script.script_text().as_deref(),
+            Some("if (a < b) x()")
+        );
+        assert_eq!(script.style_text_content(), None);
+
+        // Seuls les noeuds Text enfants sont pris en compte.
+        let p = tree.document.body().unwrap().get_first_child().unwrap();
+        assert_eq!(p.child_text_content(), "a");
+        assert_eq!(p.style_text_content(), None);
+    }
+
+    #[test]
+    fn test_style_and_link_elements() {
+        let mut parser = test_the_str!(
+            "<link rel='Alternate StyleSheet' href=a.css media=print>\
+             <link rel=icon href=a.ico>\
+             <style media=screen><b>x</b></style>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let head = tree.document.head().unwrap();
+
+        // <link> est un élément vide.
+        let link = head.get_first_child().unwrap();
+        assert!(link.get_first_child().is_none());
+        assert_eq!(link.element_ref().local_name(), "link");
+        let html_link = link.link_ref();
+        assert_eq!(
+            html_link.rel().as_deref(),
+            Some("Alternate StyleSheet")
+        );
+        assert_eq!(html_link.href().as_deref(), Some("a.css"));
+        assert_eq!(html_link.media().as_deref(), Some("print"));
+        assert!(html_link.is_stylesheet());
+
+        let icon = link.next_sibling().unwrap();
+        assert!(!icon.link_ref().is_stylesheet());
+        assert_eq!(icon.link_ref().media(), None);
+
+        // Le contenu de <style> est du texte brut.
+        let style = icon.next_sibling().unwrap();
+        assert_eq!(style.element_ref().local_name(), "style");
+        assert_eq!(style.style_ref().media().as_deref(), Some("screen"));
+        let text = style.get_first_child().unwrap();
+        assert!(text.is_text());
+        assert_eq!(text.character_data_ref().data(), "<b>x</b>");
+        assert!(text.next_sibling().is_none());
+    }
+
+    #[test]
+    fn test_svg_attributes_case() {
+        let mut parser = test_the_str!(
+            "<svg viewBox='0 0 1 1' PreserveAspectRatio=none>\
+             <svg VIEWBOX='0 0 2 2'></svg></svg>\
+             <math definitionURL=a></math>"
+        );
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+
+        // Le tokenizer met les noms d'attributs en minuscules, les
+        // ajustements SVG restaurent la casse attendue.
+        let svg = body.get_first_child().unwrap();
+        let svg_element = svg.element_ref();
+        assert_eq!(
+            svg_element.get_attribute("viewBox").as_deref(),
+            Some("0 0 1 1")
+        );
+        assert!(!svg_element.has_attribute("viewbox"));
+        assert_eq!(
+            svg_element.get_attribute("preserveAspectRatio").as_deref(),
+            Some("none")
+        );
+
+        // Idem dans le contenu étranger.
+        let nested_svg = svg.get_first_child().unwrap();
+        assert_eq!(
+            nested_svg.element_ref().get_attribute("viewBox").as_deref(),
+            Some("0 0 2 2")
+        );
+
+        let math = svg.next_sibling().unwrap();
+        assert!(math.element_ref().has_attribute("definitionURL"));
+        assert!(!math.element_ref().has_attribute("definitionurl"));
+    }
+
+    #[test]
+    fn test_applet_delimits_button_scope() {
+        let mut parser = test_the_str!("<p><applet></p>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+
+        // L'élément applet délimite la portée du bouton : le p ouvert
+        // avant n'y est pas, un élément p vide est inséré dans applet.
+        let p = body.get_first_child().unwrap();
+        assert_eq!(p.element_ref().local_name(), "p");
+        let applet = p.get_first_child().unwrap();
+        assert_eq!(applet.element_ref().local_name(), "applet");
+        let inner_p = applet.get_first_child().unwrap();
+        assert_eq!(inner_p.element_ref().local_name(), "p");
+        assert!(inner_p.get_first_child().is_none());
+    }
+
+    #[test]
+    fn test_li_end_tag_in_list_item_scope() {
+        let mut parser = test_the_str!("<li><ul></li>x");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+
+        // L'élément ul délimite la portée de l'élément de liste : la
+        // balise de fin li est ignorée.
+        let li = body.get_first_child().unwrap();
+        let ul = li.get_first_child().unwrap();
+        assert_eq!(ul.element_ref().local_name(), "ul");
+        let text = ul.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "x");
+    }
+
+    #[test]
+    fn test_cdata_section_in_foreign_content() {
+        let mut parser = test_the_str!("<svg><![CDATA[a<b]]>c<![CDATA[d");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        let svg = body.get_first_child().unwrap();
+        let text = svg.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "a<bcd");
+
+        let errors = parser.take_parse_errors();
+        assert!(errors
+            .iter()
+            .any(|(err, _)| *err == HTMLParserError::EofInCdata));
+    }
+
+    #[test]
+    fn test_frameset_eof_stops_parsing() {
+        let mut parser = test_the_str!("<frameset>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let frameset = tree.document.body().unwrap();
+        assert_eq!(frameset.element_ref().local_name(), "frameset");
+    }
+
+    #[test]
+    fn test_best_effort() {
+        // Le mode de récupération ne change rien aux modes d'insertion
+        // implémentés.
+        let mut parser =
+            test_the_str!("<p>a<svg></br>b</svg><foo>c</foo>d</p>e");
+        parser.run();
+        let expected = parser.tree_construction().document.visible_text();
+
+        let mut parser =
+            test_the_str!("<p>a<svg></br>b</svg><foo>c</foo>d</p>e");
+        parser.best_effort(true);
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let text = tree.document.visible_text();
+        assert_eq!(text, expected);
+        ["a", "b", "c", "d", "e"].into_iter().for_each(|data| {
+            assert!(text.contains(data), "{data}: {text}");
+        });
+    }
+
+    #[test]
+    fn test_best_effort_fallback() {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static WARNINGS: RefCell<Vec<String>> = RefCell::default();
+        }
+
+        struct WarningsLogger;
+
+        impl log::Log for WarningsLogger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Warn
+            }
+
+            fn log(&self, record: &log::Record) {
+                if self.enabled(record.metadata()) {
+                    let message = record.args().to_string();
+                    WARNINGS.with(|w| w.borrow_mut().push(message));
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&WarningsLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+
+        let mut parser =
+            test_the_str!("<table><tr><td>a</td></tr></table>b");
+        parser.best_effort(true);
+        parser.tree_construction().unimplemented_insertion_mode =
+            Some(InsertionMode::InTable);
+        parser.run();
+
+        WARNINGS.with(|w| {
+            assert!(w.borrow().iter().any(|warning| {
+                warning.starts_with(
+                    "Le mode d'insertion InTable n'est pas implémenté",
+                )
+            }));
+        });
+
+        // Les jetons du mode d'insertion "in table" sont traités selon
+        // les règles du mode d'insertion "in body" : les balises tr et
+        // td sont ignorées et aucun élément tbody n'est créé.
+        let body = parser.tree_construction().document.body().unwrap();
+        let table = body.get_first_child().unwrap();
+        assert_eq!(table.element_ref().local_name(), "table");
+        let text = table.get_first_child().unwrap();
+        assert_eq!(text.character_data_ref().data(), "a");
+        assert!(text.next_sibling().is_none());
+        let text = table.next_sibling().unwrap();
+        assert_eq!(text.character_data_ref().data(), "b");
+    }
+
+    #[test]
+    #[should_panic(expected = "n'est pas implémenté")]
+    fn test_best_effort_disabled() {
+        let mut parser =
+            test_the_str!("<table><tr><td>a</td></tr></table>b");
+        parser.tree_construction().unimplemented_insertion_mode =
+            Some(InsertionMode::InTable);
+        parser.run();
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_metrics() {
+        let mut parser = test_the_str!("<p>a</p><!--c-->");
+        assert_eq!(parser.metrics(), &parser::ParserMetrics::default());
+
+        parser.run();
+        let metrics = parser.metrics().clone();
+        // <p>, a, </p>, <!--c--> et EOF.
+        assert_eq!(metrics.tokens, 5);
+        // html, head, body, p, le noeud Text et le commentaire.
+        assert_eq!(metrics.nodes, 6);
+
+        parser.reset_metrics();
+        assert_eq!(parser.metrics(), &parser::ParserMetrics::default());
+    }
+
+    #[test]
+    fn test_tag_name_string() {
+        use html_elements::{
+            interface::HTMLElementInterface, HTMLElementVariant,
+        };
+
+        use crate::serialize_html_fragment;
+
+        let mut parser = test_the_str!("<foo-bar a=b>x");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        let unknown = body.get_first_child().unwrap();
+
+        // Un élément inconnu a un nom de balise, sans paniquer.
+        assert_eq!(unknown.element_ref().tag_name_string(), "foo-bar");
+        match unknown.iref() {
+            | HTMLElementVariant::Unknown(el) => {
+                assert_eq!(el.tag_name(), "foo-bar");
+            }
+            | _ => panic!("Élément inconnu attendu."),
+        }
+        assert_eq!(
+            serialize_html_fragment(&body),
+            "<foo-bar a=\"b\">x</foo-bar>"
+        );
+
+        // Hors des espaces de noms HTML, MathML et SVG, le nom de la
+        // balise est le nom qualifié.
+        let element =
+            Document::create_element_ns(Some(Namespace::XML), "xml:foo")
+                .unwrap();
+        assert_eq!(element.element_ref().tag_name_string(), "xml:foo");
+        let element =
+            Document::create_element_ns(Some(Namespace::SVG), "s:circle")
+                .unwrap();
+        assert_eq!(element.element_ref().tag_name_string(), "circle");
+    }
+
+    #[test]
+    fn test_any_other_end_tag() {
+        use crate::serialize_html_fragment;
+
+        let mut parser = test_the_str!("<div><span></div>x");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        assert_eq!(
+            serialize_html_fragment(&body),
+            "<div><span></span></div>x"
+        );
+
+        // Le noeud actuel (span) n'est pas l'élément fermé : span et
+        // foo-bar sont retirés de la pile.
+        let mut parser = test_the_str!("<foo-bar><span>x</foo-bar>y");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        assert_eq!(
+            serialize_html_fragment(&body),
+            "<foo-bar><span>x</span></foo-bar>y"
+        );
+
+        // Un élément spécial (div) est rencontré avant l'élément de même
+        // nom : la balise de fin est ignorée.
+        let mut parser = test_the_str!("<foo-bar><div></foo-bar>x");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        assert_eq!(
+            serialize_html_fragment(&body),
+            "<foo-bar><div>x</div></foo-bar>"
+        );
+    }
+
+    #[test]
+    fn test_br_and_p_end_tags_without_start_tag() {
+        use crate::serialize_html_fragment;
+
+        // Une balise de fin br est traitée comme une balise de début br,
+        // sans ses attributs.
+        let mut parser = test_the_str!("a</br class=x>b");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        assert_eq!(serialize_html_fragment(&body), "a<br>b");
+
+        // En contenu étranger, la balise de fin br ferme l'élément svg
+        // avant d'être retraitée.
+        let mut parser = test_the_str!("<svg></br>");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        assert_eq!(serialize_html_fragment(&body), "<svg></svg><br>");
+
+        // Une balise de fin p sans élément p dans la portée du bouton
+        // insère un élément p vide.
+        let mut parser = test_the_str!("a</p>b");
+        parser.run();
+
+        let tree = parser.tree_construction();
+        let body = tree.document.body().unwrap();
+        assert_eq!(serialize_html_fragment(&body), "a<p></p>b");
+    }
 }
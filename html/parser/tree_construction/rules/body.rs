@@ -2,20 +2,19 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use dom::node::CommentNode;
+use dom::node::Node;
 use html_elements::{
     interface::IsOneOfTagsInterface, tag_attributes, tag_names,
 };
-use infra::namespace::Namespace;
+use infra::{namespace::Namespace, structure::tree::TreeNode};
 
 use crate::{
-    state::{
-        Entry, FramesetOkFlag, InsertionMode, ScriptingFlag,
-        StackOfOpenElements,
-    },
-    tokenization::{HTMLToken, HTMLTokenizerState},
+    elements_categories,
+    state::{Entry, FramesetOkFlag, InsertionMode, ScriptingFlag},
+    tokenization::{HTMLTagAttribute, HTMLToken},
     tree_construction::{
-        HTMLTreeConstruction, HTMLTreeConstructionControlFlow,
+        AdjustedInsertionLocation, HTMLTreeConstruction,
+        HTMLTreeConstructionControlFlow,
     },
     HTMLParserFlag, HTMLParserState,
 };
@@ -31,27 +30,34 @@ impl HTMLTreeConstruction {
             token: &HTMLToken,
         ) {
             let tag_token = token.as_tag();
-
+            let tag_name = tag_token.local_name();
+
+            // 1. Initialiser node pour qu'il soit le noeud actuel (le
+            //    noeud le plus bas de la pile).
+            // 2. Boucle : si node est un élément HTML ayant le même nom de
+            //    balise que le jeton, alors : (voir plus bas).
+            // 3. Sinon, si node est dans la catégorie spéciale, il s'agit
+            //    d'une erreur d'analyse ; ignorer le jeton et retourner.
+            // 4. Définir node comme étant l'entrée précédente dans la pile
+            //    des éléments ouverts et retourner à l'étape Boucle.
             let mut index: Option<usize> = None;
             for (idx, node) in
                 tree.stack_of_open_elements.iter().enumerate().rev()
             {
-                let current_tag_name = node.element_ref().tag_name();
-                if current_tag_name == tag_token.local_name() {
-                    if node
-                        == tree.current_node().expect("Le noeud actuel")
-                    {
-                        tree.parse_error(token);
-                    }
+                let element = node.element_ref();
+                let namespace = element
+                    .namespace()
+                    .expect("Devrait être un espace de nom valide");
+                let local_name = element.local_name();
+
+                if namespace == Namespace::HTML && local_name == tag_name {
                     index = Some(idx);
                     break;
                 }
 
-                if is_special_tag(
-                    current_tag_name,
-                    node.element_ref()
-                        .namespace()
-                        .expect("Devrait être un espace de nom valide"),
+                if elements_categories::is_special(
+                    local_name.as_str(),
+                    namespace,
                 ) {
                     tree.parse_error(token);
                     return;
@@ -66,150 +72,120 @@ impl HTMLTreeConstruction {
                 }
             };
 
-            tree.generate_implied_end_tags_except_for(
-                tag_token.tag_name(),
-            );
+            // 2.1. Générer des balises de fin implicites, sauf pour les
+            //      éléments HTML ayant le même nom de balise que le
+            //      jeton.
+            tree.generate_implied_end_tags_with_predicate(|name| {
+                name != tag_name
+            });
+
+            // 2.2. Si node n'est pas le noeud actuel, il s'agit d'une
+            //      erreur d'analyse.
+            if tree.stack_of_open_elements.len() != match_idx + 1 {
+                tree.parse_error(token);
+            }
 
+            // 2.3. Retirer tous les noeuds de la pile des éléments
+            //      ouverts, depuis le noeud actuel jusqu'à node inclus,
+            //      puis arrêter ces étapes.
             while tree.stack_of_open_elements.len() > match_idx {
                 tree.stack_of_open_elements.pop();
             }
         }
 
-        /// Lorsque les étapes ci-dessous indiquent que l'agent utilisateur
-        /// doit fermer un élément p, cela signifie que l'agent
-        /// utilisateur doit exécuter les étapes suivantes :
-        ///   1. Générer des balises de fin implicites, sauf pour les
-        /// éléments p.
-        ///   2. Si le nœud actuel n'est pas un élément p, il s'agit d'une
-        /// erreur d'analyse.
-        ///   3. Extraire des éléments de la pile des éléments ouverts
-        /// jusqu'à ce qu'un élément p ait été extrait de la pile.
-        fn close_p_element(
+        /// Étapes communes aux balises de début "li", "dd" et "dt" :
+        ///   1. Initialiser le nœud comme étant le nœud actuel (le nœud le
+        ///      plus bas de la pile).
+        ///   2. "Dans une boucle" : si le nœud est l'un des éléments
+        ///      `tag_names`, alors :
+        ///      1. Générer des balises de fin implicites, sauf pour les
+        ///         éléments du même nom que le nœud.
+        ///      2. Si le nœud actuel n'est pas un élément du même nom que
+        ///         le nœud, il s'agit d'une erreur d'analyse.
+        ///      3. Extraire des éléments de la pile d'éléments ouverts
+        ///         jusqu'à ce qu'un élément du même nom que le nœud ait
+        ///         été extrait de la pile, puis passer à l'étape "done".
+        ///   3. Si le noeud est dans la catégorie spéciale, mais n'est pas
+        ///      un élément address, div ou p, alors passer à l'étape
+        ///      "done".
+        ///   4. Sinon, placer le nœud à l'entrée précédente dans la pile
+        ///      des éléments ouverts et retourner à l'étape "Dans une
+        ///      boucle".
+        ///   5. "Done" : si la pile d'éléments ouverts a un élément p dans
+        ///      la portée du bouton, alors fermer un élément p.
+        fn close_list_item_element<const N: usize>(
             tree: &mut HTMLTreeConstruction,
             token: &HTMLToken,
+            names: [tag_names; N],
         ) {
-            let tag_name = tag_names::p;
-
-            tree.generate_implied_end_tags_except_for(tag_name);
+            for node in tree.stack_of_open_elements.iter().rev() {
+                let element = node.element_ref();
+                let name = element.local_name();
+
+                // NOTE(phisyx): les éléments inconnus ne sont pas dans la
+                // catégorie spéciale, on passe à l'entrée précédente.
+                let tag_name = match name.parse::<tag_names>() {
+                    | Ok(tag_name) => tag_name,
+                    | Err(_) => continue,
+                };
 
-            if let Some(cnode) = tree.current_node() {
-                if tag_name != cnode.element_ref().local_name() {
-                    tree.parse_error(token);
+                if names.contains(&tag_name) {
+                    tree.generate_implied_end_tags_except_for(tag_name);
+                    if tag_name
+                        != tree
+                            .current_node()
+                            .expect("Le noeud actuel")
+                            .element_ref()
+                            .local_name()
+                    {
+                        tree.parse_error(token);
+                    }
+                    tree.stack_of_open_elements.pop_until_tag(tag_name);
+                    break;
                 }
-            }
-
-            tree.stack_of_open_elements.pop_until_tag(tag_name);
-        }
 
-        /// <https://html.spec.whatwg.org/multipage/parsing.html#special>
-        #[allow(deprecated)]
-        fn is_special_tag(
-            tag_name: tag_names,
-            namespace: Namespace,
-        ) -> bool {
-            match namespace {
-                | Namespace::HTML => tag_name.is_one_of([
+                if elements_categories::is_special(
+                    tag_name,
+                    element
+                        .namespace()
+                        .expect("Devrait être un espace de nom valide"),
+                ) && !name.is_one_of([
                     tag_names::address,
-                    tag_names::applet,
-                    tag_names::area,
-                    tag_names::article,
-                    tag_names::aside,
-                    tag_names::base,
-                    tag_names::basefont,
-                    tag_names::bgsound,
-                    tag_names::blockquote,
-                    tag_names::body,
-                    tag_names::br,
-                    tag_names::button,
-                    tag_names::caption,
-                    tag_names::center,
-                    tag_names::col,
-                    tag_names::colgroup,
-                    tag_names::dd,
-                    tag_names::details,
-                    tag_names::dir,
                     tag_names::div,
-                    tag_names::dl,
-                    tag_names::dt,
-                    tag_names::embed,
-                    tag_names::fieldset,
-                    tag_names::figcaption,
-                    tag_names::figure,
-                    tag_names::footer,
-                    tag_names::form,
-                    tag_names::frame,
-                    tag_names::frameset,
-                    tag_names::h1,
-                    tag_names::h2,
-                    tag_names::h3,
-                    tag_names::h4,
-                    tag_names::h5,
-                    tag_names::h6,
-                    tag_names::head,
-                    tag_names::header,
-                    tag_names::hgroup,
-                    tag_names::hr,
-                    tag_names::html,
-                    tag_names::iframe,
-                    tag_names::img,
-                    tag_names::input,
-                    tag_names::keygen,
-                    tag_names::li,
-                    tag_names::link,
-                    tag_names::listing,
-                    tag_names::main,
-                    tag_names::marquee,
-                    tag_names::menu,
-                    tag_names::meta,
-                    tag_names::nav,
-                    tag_names::noembed,
-                    tag_names::noframes,
-                    tag_names::noscript,
-                    tag_names::object,
-                    tag_names::ol,
                     tag_names::p,
-                    tag_names::param,
-                    tag_names::plaintext,
-                    tag_names::pre,
-                    tag_names::script,
-                    tag_names::section,
-                    tag_names::select,
-                    tag_names::source,
-                    tag_names::style,
-                    tag_names::summary,
-                    tag_names::table,
-                    tag_names::tbody,
-                    tag_names::td,
-                    tag_names::template,
-                    tag_names::textarea,
-                    tag_names::tfoot,
-                    tag_names::th,
-                    tag_names::thead,
-                    tag_names::title,
-                    tag_names::tr,
-                    tag_names::track,
-                    tag_names::ul,
-                    tag_names::wbr,
-                    tag_names::xmp,
-                ]),
-                | Namespace::MathML => tag_name.is_one_of([
-                    tag_names::mi,
-                    tag_names::mo,
-                    tag_names::mn,
-                    tag_names::ms,
-                    tag_names::mtext,
-                    tag_names::annotationXml,
-                ]),
-                | Namespace::SVG => tag_name.is_one_of([
-                    tag_names::foreignObject,
-                    tag_names::desc,
-                    tag_names::title,
-                ]),
+                ]) {
+                    break;
+                }
+            }
 
-                | _ => false,
+            if tree
+                .stack_of_open_elements
+                .has_element_in_button_scope(tag_names::p)
+            {
+                tree.close_a_p_element(token);
             }
         }
 
+        /// Pour chaque attribut du jeton, vérifier si l'attribut est déjà
+        /// présent sur l'élément. Si ce n'est pas le cas, ajouter
+        /// l'attribut et sa valeur correspondante à l'élément.
+        fn merge_attributes(
+            node: &TreeNode<Node>,
+            attributes: &[HTMLTagAttribute],
+        ) {
+            let element = node.element_ref();
+            attributes.iter().for_each(|attribute| {
+                if !element.has_attribute(&attribute.name) {
+                    element.set_attribute_ns(
+                        attribute.namespace_uri,
+                        attribute.prefix.as_deref(),
+                        &attribute.name,
+                        &attribute.value,
+                    );
+                }
+            });
+        }
+
         match token {
             // A character token that is U+0000 NULL
             //
@@ -245,7 +221,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -282,18 +258,11 @@ impl HTMLTreeConstruction {
                     );
                 }
 
-                attributes.iter().for_each(|attribute| {
-                    let element = self
-                        .current_node()
-                        .expect("Le noeud actuel")
-                        .element_ref();
-                    if !element.has_attribute(&attribute.name) {
-                        element.set_attribute(
-                            &attribute.name,
-                            &attribute.value,
-                        );
-                    }
-                });
+                let html_element = self
+                    .stack_of_open_elements
+                    .first()
+                    .expect("L'élément html");
+                merge_attributes(html_element, attributes);
             }
 
             // A start tag whose tag name is one of:
@@ -376,17 +345,8 @@ impl HTMLTreeConstruction {
 
                 let body_element = unsafe {
                     self.stack_of_open_elements.get_unchecked(1)
-                }
-                .element_ref();
-
-                attributes.iter().for_each(|attribute| {
-                    if !body_element.has_attribute(&attribute.name) {
-                        body_element.set_attribute(
-                            &attribute.name,
-                            &attribute.value,
-                        );
-                    }
-                });
+                };
+                merge_attributes(body_element, attributes);
             }
 
             // A start tag whose tag name is "frameset"
@@ -522,9 +482,9 @@ impl HTMLTreeConstruction {
                 is_end: true,
                 ..
             } if tag_names::body == name
-                && self.stack_of_open_elements.has_element_in_scope(
+                && !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::body,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) =>
             {
                 self.parse_error(&token);
@@ -585,9 +545,9 @@ impl HTMLTreeConstruction {
                 is_end: true,
                 ..
             } if tag_names::html == name
-                && self.stack_of_open_elements.has_element_in_scope(
+                && !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::body,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) =>
             {
                 self.parse_error(&token);
@@ -684,11 +644,11 @@ impl HTMLTreeConstruction {
                 tag_names::ul,
             ]) =>
             {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 self.insert_html_element(token.as_tag());
@@ -717,11 +677,11 @@ impl HTMLTreeConstruction {
                 tag_names::h6,
             ]) =>
             {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 if let Some(cnode) = self.current_node() {
@@ -758,11 +718,11 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if name.is_one_of([tag_names::pre, tag_names::listing]) => {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 self.insert_html_element(token.as_tag());
@@ -805,11 +765,11 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::form == name => {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 let element = self.insert_html_element(token.as_tag());
@@ -851,50 +811,8 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::li == name => {
-                const LI: tag_names = tag_names::li;
-
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
-
-                for node in self.stack_of_open_elements.iter() {
-                    let element = node.element_ref();
-                    let name = element.local_name();
-                    let tag_name = name.parse::<tag_names>().unwrap();
-
-                    if LI == tag_name {
-                        self.generate_implied_end_tags_except_for(LI);
-                        if LI
-                            == self
-                                .current_node()
-                                .expect("Le noeud actuel")
-                                .element_ref()
-                                .local_name()
-                        {
-                            self.parse_error(&token);
-                        }
-                        self.stack_of_open_elements.pop_until_tag(LI);
-                        break;
-                    }
-
-                    if is_special_tag(
-                        tag_name,
-                        element.namespace().expect(
-                            "Devrait être un espace de nom valide",
-                        ),
-                    ) && name.is_one_of([
-                        tag_names::address,
-                        tag_names::div,
-                        tag_names::p,
-                    ]) {
-                        break;
-                    }
-                }
-
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
-                }
+                close_list_item_element(self, &token, [tag_names::li]);
 
                 self.insert_html_element(token.as_tag());
             }
@@ -938,54 +856,12 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if name.is_one_of([tag_names::dd, tag_names::dt]) => {
-                const DD: tag_names = tag_names::dd;
-                const DT: tag_names = tag_names::dt;
-
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
-
-                for node in self.stack_of_open_elements.iter() {
-                    let element = node.element_ref();
-                    let name = element.local_name();
-                    let tag_name = name.parse::<tag_names>().unwrap();
-
-                    if DD == tag_name || DT == tag_name {
-                        self.generate_implied_end_tags_except_for(
-                            tag_name,
-                        );
-                        if tag_name
-                            == self
-                                .current_node()
-                                .expect("Le noeud actuel")
-                                .element_ref()
-                                .local_name()
-                        {
-                            self.parse_error(&token);
-                        }
-                        self.stack_of_open_elements
-                            .pop_until_tag(tag_name);
-                        break;
-                    }
-
-                    if is_special_tag(
-                        tag_name,
-                        element.namespace().expect(
-                            "Devrait être un espace de nom valide",
-                        ),
-                    ) && name.is_one_of([
-                        tag_names::address,
-                        tag_names::div,
-                        tag_names::p,
-                    ]) {
-                        break;
-                    }
-                }
-
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
-                }
+                close_list_item_element(
+                    self,
+                    &token,
+                    [tag_names::dd, tag_names::dt],
+                );
 
                 self.insert_html_element(token.as_tag());
             }
@@ -1008,11 +884,11 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::plaintext == name => {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 self.insert_html_element(token.as_tag());
@@ -1163,7 +1039,7 @@ impl HTMLTreeConstruction {
                             .stack_of_open_elements
                             .has_element_in_scope(
                                 element_name,
-                                StackOfOpenElements::SCOPE_ELEMENTS,
+                                elements_categories::SCOPE_ELEMENTS,
                             )
                         {
                             self.parse_error(&token);
@@ -1241,17 +1117,17 @@ impl HTMLTreeConstruction {
                 is_end: true,
                 ..
             } if tag_names::p == name => {
-                if !self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
+                if !self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
                     let p =
                         HTMLToken::new_start_tag().with_name(tag_names::p);
                     self.parse_error(&token);
                     self.insert_html_element(&p);
                 }
 
-                close_p_element(self, token.as_tag());
+                self.close_a_p_element(token.as_tag());
             }
 
             // An end tag whose tag name is "li"
@@ -1275,7 +1151,7 @@ impl HTMLTreeConstruction {
             } if tag_names::li == name => {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::li,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::LIST_ITEM_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1324,7 +1200,7 @@ impl HTMLTreeConstruction {
 
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_name,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1392,7 +1268,7 @@ impl HTMLTreeConstruction {
                 .all(|heading| {
                     !self.stack_of_open_elements.has_element_in_scope(
                         heading,
-                        StackOfOpenElements::SCOPE_ELEMENTS,
+                        elements_categories::SCOPE_ELEMENTS,
                     )
                 }) {
                     self.parse_error(&token);
@@ -1481,12 +1357,9 @@ impl HTMLTreeConstruction {
 
                 if self.stack_of_open_elements.has_element_in_scope(
                     tag_names::nobr,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) {
-                    self.run_adoption_agency_algorithm(
-                        &token,
-                        &is_special_tag,
-                    );
+                    self.run_adoption_agency_algorithm(&token);
                     self.reconstruct_active_formatting_elements();
                 }
 
@@ -1507,27 +1380,10 @@ impl HTMLTreeConstruction {
                 ref name,
                 is_end: true,
                 ..
-            } if name.is_one_of([
-                tag_names::a,
-                tag_names::b,
-                tag_names::big,
-                tag_names::code,
-                tag_names::em,
-                tag_names::font,
-                tag_names::i,
-                tag_names::nobr,
-                tag_names::s,
-                tag_names::small,
-                tag_names::strike,
-                tag_names::strong,
-                tag_names::tt,
-                tag_names::u,
-            ]) =>
+            } if name
+                .is_one_of(elements_categories::FORMATTING_ELEMENTS) =>
             {
-                self.run_adoption_agency_algorithm(
-                    &token,
-                    &is_special_tag,
-                );
+                self.run_adoption_agency_algorithm(&token);
             }
 
             // A start tag whose tag name is one of: "applet", "marquee",
@@ -1591,7 +1447,7 @@ impl HTMLTreeConstruction {
 
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_name,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1625,14 +1481,12 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::table == name => {
-                let document = self.document.document_ref();
-                if !document.isin_quirks_mode()
-                    && self.stack_of_open_elements.has_element_in_scope(
-                        tag_names::p,
-                        StackOfOpenElements::button_scope_elements(),
-                    )
+                if !self.is_quirks()
+                    && self
+                        .stack_of_open_elements
+                        .has_element_in_button_scope(tag_names::p)
                 {
-                    close_p_element(self, token.as_tag());
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 self.insert_html_element(token.as_tag());
@@ -1672,14 +1526,22 @@ impl HTMLTreeConstruction {
                         tag_names::wbr,
                     ]) =>
             {
+                // An end tag whose tag name is "br"
+                //
+                // Erreur d'analyse. Supprimer les attributs du jeton, et
+                // agir comme s'il s'agissait d'un jeton de balise de début
+                // "br" sans attributs, plutôt que du jeton de balise de
+                // fin qu'il est réellement.
                 if is_end && tag_names::br == name {
-                    token.as_tag_mut().clear_attributes();
+                    self.parse_error(&token);
+                    token = HTMLToken::new_start_tag()
+                        .with_name(tag_names::br);
                 }
 
                 self.reconstruct_active_formatting_elements();
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
             }
 
@@ -1704,7 +1566,7 @@ impl HTMLTreeConstruction {
                 self.reconstruct_active_formatting_elements();
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
 
                 if !token.as_tag().has_attributes([tag_attributes::hidden])
                 {
@@ -1732,7 +1594,7 @@ impl HTMLTreeConstruction {
             {
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
             }
 
             // A start tag whose tag name is "hr"
@@ -1749,16 +1611,16 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::hr == name => {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
             }
 
@@ -1822,19 +1684,17 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::xmp == name => {
-                if self.stack_of_open_elements.has_element_in_scope(
-                    tag_names::p,
-                    StackOfOpenElements::button_scope_elements(),
-                ) {
-                    close_p_element(self, token.as_tag());
+                if self
+                    .stack_of_open_elements
+                    .has_element_in_button_scope(tag_names::p)
+                {
+                    self.close_a_p_element(token.as_tag());
                 }
 
                 self.reconstruct_active_formatting_elements();
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
-                self.parse_generic_element(
-                    token.as_tag(),
-                    HTMLTokenizerState::RAWTEXT,
-                );
+                return self
+                    .follow_generic_raw_text_parsing(token.as_tag());
             }
 
             // A start tag whose tag name is "iframe"
@@ -1848,10 +1708,8 @@ impl HTMLTreeConstruction {
                 ..
             } if tag_names::iframe == name => {
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
-                self.parse_generic_element(
-                    token.as_tag(),
-                    HTMLTokenizerState::RAWTEXT,
-                );
+                return self
+                    .follow_generic_raw_text_parsing(token.as_tag());
             }
 
             // A start tag whose tag name is "noembed"
@@ -1869,10 +1727,8 @@ impl HTMLTreeConstruction {
                 || (tag_names::noscript == name
                     && self.scripting_flag == ScriptingFlag::Enabled) =>
             {
-                self.parse_generic_element(
-                    token.as_tag(),
-                    HTMLTokenizerState::RAWTEXT,
-                );
+                return self
+                    .follow_generic_raw_text_parsing(token.as_tag());
             }
 
             // A start tag whose tag name is "select"
@@ -1942,7 +1798,7 @@ impl HTMLTreeConstruction {
             } if name.is_one_of([tag_names::rb, tag_names::rtc]) => {
                 if self.stack_of_open_elements.has_element_in_scope(
                     tag_names::ruby,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) {
                     self.generate_implied_end_tags();
                     let cnode =
@@ -1971,7 +1827,7 @@ impl HTMLTreeConstruction {
             } if name.is_one_of([tag_names::rp, tag_names::rt]) => {
                 if self.stack_of_open_elements.has_element_in_scope(
                     tag_names::ruby,
-                    StackOfOpenElements::SCOPE_ELEMENTS,
+                    elements_categories::SCOPE_ELEMENTS,
                 ) {
                     self.generate_implied_end_tags_except_for(
                         tag_names::rtc,
@@ -1990,8 +1846,48 @@ impl HTMLTreeConstruction {
                 self.insert_html_element(token.as_tag());
             }
 
-            // TODO(html): A start tag whose tag name is one of: "math"
-            // TODO(html): A start tag whose tag name is one of: "svg"
+            // A start tag whose tag name is "math"
+            // A start tag whose tag name is "svg"
+            //
+            // Reconstruire les éléments de mise en forme actifs, s'il y en
+            // a.
+            // Ajuster les attributs MathML (resp. SVG) pour le jeton.
+            // (Cela corrige le cas des attributs MathML (resp. SVG) qui
+            // ne sont pas tous en minuscules).
+            // Ajuster les attributs étrangers pour le jeton. (Cela
+            // corrige l'utilisation d'attributs à espace de noms, en
+            // particulier XLink).
+            // Insérer un élément étranger pour le jeton, dans l'espace
+            // de noms MathML (resp. SVG).
+            // Si le jeton a son drapeau de fermeture automatique
+            // activé, retirer le noeud actuel de la pile des éléments
+            // ouverts et accuser réception du drapeau de fermeture
+            // automatique du jeton.
+            #[allow(deprecated)]
+            | HTMLToken::Tag {
+                ref name,
+                is_end: false,
+                self_closing_flag,
+                ..
+            } if name.is_one_of([tag_names::math, tag_names::svg]) => {
+                self.reconstruct_active_formatting_elements();
+
+                let namespace = if tag_names::math == name {
+                    self.adjust_mathml_attributes(token.as_tag_mut());
+                    Namespace::MathML
+                } else {
+                    self.adjust_svg_attributes(token.as_tag_mut());
+                    Namespace::SVG
+                };
+
+                self.adjust_foreign_attributes(token.as_tag_mut());
+                self.insert_foreign_element(token.as_tag(), namespace);
+
+                if self_closing_flag {
+                    self.stack_of_open_elements.pop();
+                    self.acknowledge_self_closing_flag(token.as_tag_mut());
+                }
+            }
 
             // A start tag whose tag name is one of: "caption", "col",
             // "colgroup", "frame", "head", "tbody", "td", "tfoot", "th",
@@ -2069,14 +1965,11 @@ impl HTMLTreeConstruction {
             // Insérer un commentaire comme dernier enfant du premier
             // élément de la pile d'éléments ouverts (l'élément html).
             | HTMLToken::Comment(comment) => {
-                let maybe_insertion_location =
-                    self.stack_of_open_elements.first();
-                if let Some(insertion_location) = maybe_insertion_location
-                {
-                    let comment =
-                        CommentNode::new(&self.document, comment);
-                    insertion_location.append_child(comment.to_owned());
-                }
+                let position = self
+                    .stack_of_open_elements
+                    .first()
+                    .map(AdjustedInsertionLocation::last_child_of);
+                self.insert_comment(comment, position);
             }
 
             // A DOCTYPE token
@@ -2163,8 +2056,10 @@ impl HTMLTreeConstruction {
             // Insérer un commentaire comme dernier enfant de l'objet
             // Document.
             | HTMLToken::Comment(comment) => {
-                let comment = CommentNode::new(&self.document, comment);
-                self.document.append_child(comment.to_owned());
+                let position = AdjustedInsertionLocation::last_child_of(
+                    &self.document,
+                );
+                self.insert_comment(comment, Some(position));
             }
 
             // A DOCTYPE token,
@@ -5,7 +5,8 @@
 use html_elements::{interface::IsOneOfTagsInterface, tag_names};
 
 use crate::{
-    state::{InsertionMode, StackOfOpenElements},
+    elements_categories,
+    state::InsertionMode,
     tokenization::HTMLToken,
     tree_construction::{
         HTMLTreeConstruction, HTMLTreeConstructionControlFlow,
@@ -40,7 +41,7 @@ impl HTMLTreeConstruction {
             } if tag_names::caption == name => {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::caption,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -99,7 +100,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::caption,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
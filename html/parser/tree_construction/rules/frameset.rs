@@ -2,14 +2,14 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use dom::node::CommentNode;
 use html_elements::tag_names;
 
 use crate::{
     state::InsertionMode,
     tokenization::HTMLToken,
     tree_construction::{
-        HTMLTreeConstruction, HTMLTreeConstructionControlFlow,
+        AdjustedInsertionLocation, HTMLTreeConstruction,
+        HTMLTreeConstructionControlFlow,
     },
     HTMLParserFlag, HTMLParserState,
 };
@@ -35,7 +35,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -120,7 +120,7 @@ impl HTMLTreeConstruction {
             } if tag_names::frame == name => {
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
             }
 
             // A start tag whose tag name is "noframes"
@@ -150,9 +150,6 @@ impl HTMLTreeConstruction {
                 if let Some(cnode) = self.current_node() {
                     if cnode.element_ref().tag_name() != tag_names::html {
                         self.parse_error(&token);
-                        return HTMLTreeConstructionControlFlow::Continue(
-                            HTMLParserState::Ignore,
-                        );
                     }
                 }
 
@@ -196,7 +193,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -267,8 +264,10 @@ impl HTMLTreeConstruction {
             // Insérer un commentaire comme dernier enfant de l'objet
             // [Document].
             | HTMLToken::Comment(comment) => {
-                let comment = CommentNode::new(&self.document, comment);
-                self.document.append_child(comment.to_owned());
+                let position = AdjustedInsertionLocation::last_child_of(
+                    &self.document,
+                );
+                self.insert_comment(comment, Some(position));
             }
 
             // A DOCTYPE token
@@ -33,7 +33,7 @@ impl HTMLTreeConstruction {
 
             // A comment token
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -139,7 +139,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -186,7 +186,7 @@ impl HTMLTreeConstruction {
             {
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
             }
 
             // A start tag whose tag name is "meta"
@@ -225,7 +225,7 @@ impl HTMLTreeConstruction {
             } if tag_names::meta == name => {
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
             }
 
             // A start tag whose tag name is "title"
@@ -237,10 +237,7 @@ impl HTMLTreeConstruction {
                 is_end: false,
                 ..
             } if tag_names::title == name => {
-                self.parse_generic_element(
-                    token.as_tag(),
-                    HTMLTokenizerState::RCDATA,
-                );
+                return self.follow_generic_rcdata_parsing(token.as_tag());
             }
 
             // A start tag whose tag name is "noscript", if the scripting
@@ -261,10 +258,8 @@ impl HTMLTreeConstruction {
                     tag_names::style,
                 ]) =>
             {
-                self.parse_generic_element(
-                    token.as_tag(),
-                    HTMLTokenizerState::RAWTEXT,
-                );
+                return self
+                    .follow_generic_raw_text_parsing(token.as_tag());
             }
 
             // A start tag whose tag name is "noscript", if the scripting
@@ -384,6 +379,7 @@ impl HTMLTreeConstruction {
                 self.list_of_active_formatting_elements
                     .insert_marker_at_end();
                 self.frameset_ok_flag = FramesetOkFlag::NotOk;
+                self.insertion_mode.switch_to(InsertionMode::InTemplate);
                 self.stack_of_template_insertion_modes
                     .push(InsertionMode::InTemplate);
             }
@@ -623,7 +619,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -105,6 +105,7 @@ impl HTMLTreeConstruction {
                     None,
                 )
                 .expect("Un élément DOM HTMLHtmlElement");
+                self.count_created_node();
                 element.set_document(&self.document);
                 self.document.append_child(element.to_owned());
                 self.stack_of_open_elements.put(element);
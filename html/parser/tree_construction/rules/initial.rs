@@ -73,8 +73,9 @@ impl HTMLTreeConstruction {
             //     tableau [HTMLDoctypeToken::SYSTEM_ID_DEFINED_RULE_1]
             //   - L'identifiant public commence par l'une des entrées du
             //     tableau [HTMLDoctypeToken::PUBLIC_ID_STARTS_WITH_RULE_1]
-            //   - L'identifiant système commence par l'une des entrées du
-            //     tableau [HTMLDoctypeToken::SYSTEM_ID_STARTS_WITH_RULE_1]
+            //   - L'identifiant système est manquant et l'identifiant
+            //     public commence par l'une des entrées du tableau
+            //     [HTMLDoctypeToken::PUBLIC_ID_STARTS_WITH_RULE_2_1]
             //
             // Sinon, si le document n'est pas un document iframe srcdoc,
             // que l'analyseur syntaxique ne peut pas modifier le drapeau
@@ -105,9 +106,6 @@ impl HTMLTreeConstruction {
 
                 if is_parse_error {
                     self.parse_error(&token);
-                    return HTMLTreeConstructionControlFlow::Continue(
-                        HTMLParserState::Ignore,
-                    );
                 }
 
                 let mut doctype = DocumentType::new(doctype_data.name());
@@ -134,6 +132,10 @@ impl HTMLTreeConstruction {
                 self.parse_error(&token);
                 self.document.get_mut().set_quirks_mode(QuirksMode::Yes);
                 self.insertion_mode.switch_to(InsertionMode::BeforeHTML);
+                return self.process_using_the_rules_for(
+                    self.insertion_mode,
+                    token,
+                );
             }
         }
 
@@ -5,7 +5,8 @@
 use html_elements::{interface::IsOneOfTagsInterface, tag_names};
 
 use crate::{
-    state::{InsertionMode, StackOfOpenElements},
+    elements_categories,
+    state::InsertionMode,
     tokenization::HTMLToken,
     tree_construction::{
         HTMLTreeConstruction, HTMLTreeConstructionControlFlow,
@@ -16,7 +17,7 @@ use crate::{
 impl HTMLTreeConstruction {
     pub(crate) fn handle_in_select_insertion_mode(
         &mut self,
-        token: HTMLToken,
+        mut token: HTMLToken,
     ) -> HTMLTreeConstructionControlFlow {
         match token {
             // A character token that is U+0000 NULL
@@ -38,7 +39,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -114,6 +115,42 @@ impl HTMLTreeConstruction {
                 self.insert_html_element(token.as_tag());
             }
 
+            // A start tag whose tag name is "hr"
+            //
+            // Si le noeud actuel est un élément option, il faut retirer
+            // ce noeud de la pile des éléments ouverts.
+            // Si le noeud actuel est un élément optgroup, il faut
+            // retirer ce noeud de la pile des éléments ouverts.
+            // Insérer un élément HTML pour le jeton. Retirer
+            // immédiatement le nœud actuel de la pile des éléments
+            // ouverts.
+            // Accuser réception du drapeau self-closing du jeton, s'il
+            // est activé.
+            | HTMLToken::Tag {
+                ref name,
+                is_end: false,
+                ..
+            } if tag_names::hr == name => {
+                if let Some(cnode) = self.current_node() {
+                    if cnode.element_ref().tag_name() == tag_names::option
+                    {
+                        self.stack_of_open_elements.pop();
+                    }
+                }
+
+                if let Some(cnode) = self.current_node() {
+                    if cnode.element_ref().tag_name()
+                        == tag_names::optgroup
+                    {
+                        self.stack_of_open_elements.pop();
+                    }
+                }
+
+                self.insert_html_element(token.as_tag());
+                self.stack_of_open_elements.pop();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
+            }
+
             // An end tag whose tag name is "optgroup"
             //
             // Tout d'abord, si le nœud actuel est un élément d'option et
@@ -194,7 +231,7 @@ impl HTMLTreeConstruction {
                     .stack_of_open_elements
                     .has_element_in_scope_except(
                         tag_names::select,
-                        StackOfOpenElements::select_scope_elements(),
+                        elements_categories::SELECT_SCOPE_ELEMENTS,
                     )
                 {
                     self.parse_error(&token);
@@ -208,6 +245,42 @@ impl HTMLTreeConstruction {
                 self.reset_insertion_mode_appropriately();
             }
 
+            // A start tag whose tag name is "select"
+            //
+            // Erreur d'analyse.
+            // Si la pile d'éléments ouverts ne comporte pas d'élément
+            // select dans la portée select, nous devons ignorer le jeton.
+            // (cas du fragment)
+            // Sinon:
+            // Retirer les éléments de la pile des éléments ouverts jusqu'à
+            // ce qu'un élément select ait été retiré de la pile.
+            // Réinitialiser le mode d'insertion de manière appropriée.
+            //
+            // NOTE(html): Il est juste traité comme une balise de fin.
+            | HTMLToken::Tag {
+                ref name,
+                is_end: false,
+                ..
+            } if tag_names::select == name => {
+                self.parse_error(&token);
+
+                if !self
+                    .stack_of_open_elements
+                    .has_element_in_scope_except(
+                        tag_names::select,
+                        elements_categories::SELECT_SCOPE_ELEMENTS,
+                    )
+                {
+                    return HTMLTreeConstructionControlFlow::Continue(
+                        HTMLParserState::Ignore,
+                    );
+                }
+
+                self.stack_of_open_elements
+                    .pop_until_tag(tag_names::select);
+                self.reset_insertion_mode_appropriately();
+            }
+
             // A start tag whose tag name is one of: "input", "keygen",
             // "textarea"
             //
@@ -235,7 +308,7 @@ impl HTMLTreeConstruction {
                     .stack_of_open_elements
                     .has_element_in_scope_except(
                         tag_names::select,
-                        StackOfOpenElements::select_scope_elements(),
+                        elements_categories::SELECT_SCOPE_ELEMENTS,
                     )
                 {
                     self.parse_error(&token);
@@ -368,7 +441,7 @@ impl HTMLTreeConstruction {
 
                 if !self.stack_of_open_elements.has_element_in_scope(
                     token.as_tag().tag_name(),
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     return HTMLTreeConstructionControlFlow::Continue(
                         HTMLParserState::Ignore,
@@ -5,7 +5,8 @@
 use html_elements::{interface::IsOneOfTagsInterface, tag_names};
 
 use crate::{
-    state::{Entry, InsertionMode, StackOfOpenElements},
+    elements_categories,
+    state::{Entry, InsertionMode},
     tokenization::HTMLToken,
     tree_construction::{
         HTMLTreeConstruction, HTMLTreeConstructionControlFlow,
@@ -47,7 +48,7 @@ impl HTMLTreeConstruction {
 
         match token {
             // A character token, if the current node is table, tbody,
-            // tfoot, thead, or tr element
+            // template, tfoot, thead, or tr element
             //
             // La table en attente de jetons de caractères doit être une
             // liste de jetons vide.
@@ -57,7 +58,7 @@ impl HTMLTreeConstruction {
             // le jeton.
             | HTMLToken::Character(_)
                 if self.current_node().is_some()
-                    && !self
+                    && self
                         .current_node()
                         .unwrap()
                         .element_ref()
@@ -65,6 +66,7 @@ impl HTMLTreeConstruction {
                         .is_one_of([
                             tag_names::table,
                             tag_names::tbody,
+                            tag_names::template,
                             tag_names::tfoot,
                             tag_names::thead,
                             tag_names::tr,
@@ -84,7 +86,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire.
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -230,7 +232,7 @@ impl HTMLTreeConstruction {
 
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::table,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     return HTMLTreeConstructionControlFlow::Continue(
                         HTMLParserState::Ignore,
@@ -262,7 +264,7 @@ impl HTMLTreeConstruction {
             } if tag_names::table == name => {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::table,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -356,7 +358,7 @@ impl HTMLTreeConstruction {
                     |node| element.contains(node),
                 );
 
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
             }
 
             // A start tag whose tag name is "form"
@@ -463,12 +465,12 @@ impl HTMLTreeConstruction {
             // Passer le mode d'insertion au mode d'insertion original et
             // retraiter le jeton.
             | _ => {
-                let pending_token_does_have_whitespace = self
+                let pending_token_does_have_non_whitespace = self
                     .pending_table_character_tokens
                     .iter()
                     .any(|token| !token.is_ascii_whitespace());
 
-                if pending_token_does_have_whitespace {
+                if pending_token_does_have_non_whitespace {
                     self.parse_error(&token);
 
                     for pending_token in
@@ -527,7 +529,7 @@ impl HTMLTreeConstruction {
             //
             // Insérer un commentaire
             | HTMLToken::Comment(comment) => {
-                self.insert_comment(comment);
+                self.insert_comment(comment, None);
             }
 
             // A DOCTYPE token
@@ -567,7 +569,7 @@ impl HTMLTreeConstruction {
             } if tag_names::col == name => {
                 self.insert_html_element(token.as_tag());
                 self.stack_of_open_elements.pop();
-                token.as_tag_mut().set_acknowledge_self_closing_flag();
+                self.acknowledge_self_closing_flag(token.as_tag_mut());
             }
 
             // An end tag whose tag name is "colgroup"
@@ -760,7 +762,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     token.as_tag().tag_name(),
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     /* Ignore */
@@ -801,7 +803,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_elements_in_scope(
                     [tag_names::tbody, tag_names::thead, tag_names::tfoot],
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -869,7 +871,7 @@ impl HTMLTreeConstruction {
         fn clear_stack_back_to_table_row_context(
             tree: &mut HTMLTreeConstruction,
         ) {
-            while let Some(cnode) = tree.stack_of_open_elements.pop() {
+            while let Some(cnode) = tree.current_node() {
                 if !cnode.element_ref().tag_name().is_one_of([
                     tag_names::tr,
                     tag_names::template,
@@ -880,7 +882,14 @@ impl HTMLTreeConstruction {
                     break;
                 }
             }
+
+            if let Some(cnode) = tree.current_node() {
+                if cnode.element_ref().tag_name() == tag_names::html {
+                    assert!(tree.parsing_fragment);
+                }
+            }
         }
+
         match token {
             // A start tag whose tag name is one of: "th", "td"
             //
@@ -918,7 +927,7 @@ impl HTMLTreeConstruction {
             } if tag_names::tr == name => {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::tr,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -960,7 +969,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::tr,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1004,7 +1013,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     token.as_tag().tag_name(),
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1014,7 +1023,7 @@ impl HTMLTreeConstruction {
 
                 if !self.stack_of_open_elements.has_element_in_scope(
                     tag_names::tr,
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     return HTMLTreeConstructionControlFlow::Continue(
                         HTMLParserState::Ignore,
@@ -1073,38 +1082,6 @@ impl HTMLTreeConstruction {
         &mut self,
         token: HTMLToken,
     ) -> HTMLTreeConstructionControlFlow {
-        /// Lorsque les étapes ci-dessous indiquent de fermer la cellule,
-        /// elles signifient qu'il faut exécuter l'algorithme suivant :
-        ///
-        /// 1. Générer des balises de fin implicites.
-        /// 2. Si le nœud actuel n'est pas un élément td ou un élément th,
-        /// il s'agit d'une erreur d'analyse.
-        /// 3. Retirer des éléments de la pile d'éléments ouverts jusqu'à
-        /// ce qu'un élément td ou un élément th ait été retiré de la
-        /// pile.
-        /// 4. Effacer la liste des éléments de mise en forme actifs
-        /// jusqu'au dernier marqueur.
-        /// 5. Passer le mode d'insertion à "in row".
-        fn close_cell(tree: &mut HTMLTreeConstruction, token: &HTMLToken) {
-            tree.generate_implied_end_tags();
-
-            if let Some(cnode) = tree.current_node() {
-                if !cnode
-                    .element_ref()
-                    .tag_name()
-                    .is_one_of([tag_names::td, tag_names::th])
-                {
-                    tree.parse_error(token);
-                }
-            }
-
-            tree.stack_of_open_elements
-                .pop_until_tags([tag_names::td, tag_names::th]);
-            tree.list_of_active_formatting_elements
-                .clear_up_to_the_last_marker();
-            tree.insertion_mode.switch_to(InsertionMode::InRow);
-        }
-
         match token {
             // An end tag whose tag name is one of: "td", "th"
             //
@@ -1130,7 +1107,7 @@ impl HTMLTreeConstruction {
             } if name.is_one_of([tag_names::td, tag_names::th]) => {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     token.as_tag().tag_name(),
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1181,7 +1158,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_elements_in_scope(
                     [tag_names::td, tag_names::th],
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1189,7 +1166,7 @@ impl HTMLTreeConstruction {
                     );
                 }
 
-                close_cell(self, &token);
+                self.close_the_cell(&token);
 
                 return self.process_using_the_rules_for(
                     self.insertion_mode,
@@ -1240,7 +1217,7 @@ impl HTMLTreeConstruction {
             {
                 if !self.stack_of_open_elements.has_element_in_scope(
                     token.as_tag().tag_name(),
-                    StackOfOpenElements::table_scope_elements(),
+                    elements_categories::TABLE_SCOPE_ELEMENTS,
                 ) {
                     self.parse_error(&token);
                     return HTMLTreeConstructionControlFlow::Continue(
@@ -1248,7 +1225,7 @@ impl HTMLTreeConstruction {
                     );
                 }
 
-                close_cell(self, &token);
+                self.close_the_cell(&token);
 
                 return self.process_using_the_rules_for(
                     self.insertion_mode,
@@ -56,14 +56,35 @@ impl HTMLTreeConstruction {
                 );
             }
 
-            // TODO(html): active spéculative html tree
             // An end tag whose tag name is "script"
+            //
+            // Soit script le noeud actuel (qui sera un élément script).
+            // Retirer le noeud actuel de la pile des éléments ouverts.
+            // Passer le mode d'insertion sur le mode d'insertion
+            // d'origine.
+            //
+            // Préparer l'élément script : un script sans attribut src est
+            // un script bloquant l'analyse, exécuté (par le hook
+            // d'exécution de l'analyseur) avant de traiter le jeton
+            // suivant.
+            //
+            // TODO(html): active spéculative html tree, scripts externes.
             | HTMLToken::Tag {
                 ref name,
                 is_end: true,
                 ..
             } if tag_names::script == name => {
-                todo!("active spéculative html tree")
+                let script = self.stack_of_open_elements.pop();
+                self.insertion_mode
+                    .switch_to(self.original_insertion_mode);
+
+                if let Some(script) = script {
+                    if !script.element_ref().has_attribute("src") {
+                        script.script_ref().set_already_started(true);
+                        self.pending_parsing_blocking_script
+                            .replace(script);
+                    }
+                }
             }
 
             // Any other end tag
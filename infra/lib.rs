@@ -6,3 +6,4 @@ pub mod algorithms;
 pub mod namespace;
 pub mod primitive;
 pub mod structure;
+pub mod url;
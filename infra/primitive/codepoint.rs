@@ -21,6 +21,23 @@ use std::str::Chars;
 /// entre parenthèses, pour des raisons de lisibilité.
 pub type CodePoint = char;
 
+// --------- //
+// Structure //
+// --------- //
+
+/// Itérateur de points de code décodant à la volée une suite d'octets
+/// UTF-8, sans passer par une [String] intermédiaire.
+///
+/// Chaque séquence d'octets invalide (sous-partie maximale d'une
+/// séquence mal formée) est remplacée par U+FFFD REPLACEMENT CHARACTER,
+/// comme le fait le décodeur UTF-8 de la spécification
+/// [Encoding](https://encoding.spec.whatwg.org/#utf-8-decoder).
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct UTF8CodePoints<'a> {
+    bytes: &'a [u8],
+}
+
 // --------- //
 // Interface //
 // --------- //
@@ -64,6 +81,19 @@ pub trait CodePointInterface: Copy {
 
 pub trait CodePointIterator: Iterator<Item = CodePoint> {}
 
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> UTF8CodePoints<'a> {
+    /// Longueur maximale, en octets, d'un point de code encodé en UTF-8.
+    const MAX_SEQUENCE_LENGTH: usize = 4;
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -117,3 +147,71 @@ impl CodePointInterface for CodePoint {
 }
 
 impl CodePointIterator for Chars<'_> {}
+
+impl Iterator for UTF8CodePoints<'_> {
+    type Item = CodePoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let window =
+            &self.bytes[..self.bytes.len().min(Self::MAX_SEQUENCE_LENGTH)];
+
+        // Seul le premier point de code de la fenêtre nous intéresse : les
+        // octets qui le suivent peuvent être invalides (ou tronqués par la
+        // fenêtre) sans que cela ne le concerne.
+        let (valid, error_len) = match std::str::from_utf8(window) {
+            | Ok(sequence) => (sequence, None),
+            | Err(err) => (
+                std::str::from_utf8(&window[..err.valid_up_to()])
+                    .unwrap_or_default(),
+                err.error_len(),
+            ),
+        };
+
+        let (codepoint, length) = match valid.chars().next() {
+            | Some(ch) => (ch, ch.len_utf8()),
+            // NOTE(encoding): une séquence incomplète en fin d'entrée
+            // (`error_len` vaut None) est remplacée par un seul U+FFFD.
+            | None => (
+                char::REPLACEMENT_CHARACTER,
+                error_len.unwrap_or(window.len()),
+            ),
+        };
+
+        self.bytes = &self.bytes[length..];
+        Some(codepoint)
+    }
+}
+
+impl CodePointIterator for UTF8CodePoints<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf8_code_points() {
+        let input = "aé€😀";
+        let decoded: String =
+            UTF8CodePoints::new(input.as_bytes()).collect();
+        assert_eq!(decoded, input);
+
+        // Octet de continuation isolé, séquence tronquée suivie d'un
+        // caractère ASCII, octet invalide, et séquence tronquée en fin
+        // d'entrée.
+        let bytes = b"a\x80b\xE2\x82c\xFFd\xF0\x9F\x98";
+        let decoded: String = UTF8CodePoints::new(bytes).collect();
+        assert_eq!(decoded, "a\u{FFFD}b\u{FFFD}c\u{FFFD}d\u{FFFD}");
+
+        // Un encodage trop long et un substitut encodé sont invalides :
+        // chaque octet de la sous-partie maximale est remplacé.
+        let decoded: String =
+            UTF8CodePoints::new(b"\xC0\xAF\xED\xA0\x80").collect();
+        assert_eq!(decoded, "\u{FFFD}".repeat(5));
+
+        assert_eq!(UTF8CodePoints::new(b"").next(), None);
+    }
+}
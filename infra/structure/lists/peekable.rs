@@ -19,11 +19,11 @@ pub trait PeekableInterface<T, I> {
     ///
     /// Le type générique est obligatoire.
     ///
-    /// NOTE(phisyx): ce type vaut None si la position demandée est
-    /// supérieure à la longueur de l'itération, tant bien qu'il y ait
-    /// des éléments à l'intérieur du flux. Exemple: si `lookahead_offset`
-    /// vaut 5, et le flux a dans son flux `[0, 1, 2]` cela retournera
-    /// `None`.
+    /// NOTE(phisyx): si la position demandée est supérieure à la longueur
+    /// de l'itération, seuls les éléments restants sont retournés.
+    /// Exemple: si `lookahead_offset` vaut 5, et le flux a dans son flux
+    /// `[0, 1, 2]` cela retournera `[0, 1, 2]`. Il appartient à
+    /// l'appelant de vérifier la longueur du résultat.
     fn peek_until<R: FromIterator<I>>(
         &mut self,
         lookahead_offset: usize,
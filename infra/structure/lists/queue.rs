@@ -15,6 +15,8 @@ pub struct ListQueue<T, I> {
     original_iterator: T,
     queue: Vec<Option<I>>,
     offset: usize,
+    /// Nombre d'éléments consommés depuis le début de la queue.
+    position: usize,
 }
 
 // -------------- //
@@ -27,10 +29,25 @@ impl<T, I> ListQueue<T, I> {
             original_iterator: iter,
             queue: Vec::default(),
             offset: 0,
+            position: 0,
         }
     }
 }
 
+impl<T, I> ListQueue<T, I> {
+    /// Position actuelle dans la queue, c'est-à-dire le nombre
+    /// d'éléments consommés (et non re-consommés).
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// L'itérateur d'origine, dont les éléments n'ont pas encore été
+    /// placés dans la queue.
+    pub fn original_iterator(&self) -> &T {
+        &self.original_iterator
+    }
+}
+
 impl<T, I> ListQueue<T, I>
 where
     T: Iterator<Item = I>,
@@ -71,6 +88,9 @@ where
 
     /// Ajoute un élément au début de la queue.
     pub fn reconsume(&mut self, last_consumed_input: Option<T::Item>) {
+        if last_consumed_input.is_some() {
+            self.position = self.position.saturating_sub(1);
+        }
         let mut temp = vec![last_consumed_input];
         self.queue.splice(..0, temp.drain(..));
     }
@@ -134,6 +154,10 @@ where
 
         self.decrement();
 
+        if consumed_item.is_some() {
+            self.position += 1;
+        }
+
         consumed_item
     }
 }
@@ -167,4 +191,23 @@ mod tests {
         );
         assert_eq!(stream.next(), Some('H'));
     }
+
+    #[test]
+    fn test_position() {
+        let mut stream = ListQueue::new("abc".chars());
+        assert_eq!(stream.position(), 0);
+
+        stream.peek_until::<String>(2);
+        assert_eq!(stream.position(), 0);
+
+        let a = stream.next();
+        stream.next();
+        assert_eq!(stream.position(), 2);
+
+        stream.reconsume(a);
+        assert_eq!(stream.position(), 1);
+
+        stream.by_ref().for_each(drop);
+        assert_eq!(stream.position(), 3);
+    }
 }
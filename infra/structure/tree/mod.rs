@@ -23,6 +23,15 @@ pub struct TreeNode<T> {
     node_ref: Rc<Node<T>>,
 }
 
+// --------- //
+// Interface //
+// --------- //
+
+/// Données d'un noeud de l'arbre qui peut être un élément.
+pub trait TreeElementInterface {
+    fn is_element(&self) -> bool;
+}
+
 // -------------- //
 // Implémentation //
 // -------------- //
@@ -59,7 +68,7 @@ impl<T> TreeNode<T> {
             last_node.next_sibling.replace(child.to_owned().into());
             child
                 .prev_sibling
-                .replace(TreeNodeWeak::from(&child).into());
+                .replace(TreeNodeWeak::from(last_node).into());
         }
 
         child.parent.replace(TreeNodeWeak::from(self).into());
@@ -90,7 +99,9 @@ impl<T> TreeNode<T> {
 
             if Rc::ptr_eq(self, &first_child) {
                 parent.first_child.replace(self.next_sibling());
-            } else if Rc::ptr_eq(self, &last_child) {
+            }
+
+            if Rc::ptr_eq(self, &last_child) {
                 parent.last_child.replace(self.prev_sibling());
             }
         }
@@ -121,28 +132,63 @@ impl<T> TreeNode<T> {
         self.last_child.borrow().clone()
     }
 
+    /// Insère le noeud dans l'arbre avant l'enfant. Lorsque l'enfant
+    /// est null, le noeud est ajouté comme dernier enfant.
+    ///
+    /// Le noeud est toujours inséré avant un enfant existant : le dernier
+    /// enfant du parent reste donc inchangé.
     pub fn insert_before(&self, node: Self, maybe_child: Option<&Self>) {
-        if maybe_child.is_none() {
-            self.append_child(node);
-            return;
-        }
+        let child = match maybe_child {
+            | Some(child) => child,
+            | None => {
+                self.append_child(node);
+                return;
+            }
+        };
 
         assert!(node.parent.borrow().is_none());
+        assert!(child
+            .parent_node()
+            .filter(|parent| Rc::ptr_eq(self, parent))
+            .is_some());
+
+        node.parent.replace(TreeNodeWeak::from(self).into());
+        node.next_sibling.replace(child.to_owned().into());
+
+        match child.prev_sibling() {
+            | Some(prev_sibling) => {
+                prev_sibling.next_sibling.replace(node.to_owned().into());
+                node.prev_sibling
+                    .replace(TreeNodeWeak::from(&prev_sibling).into());
+            }
+            | None => {
+                self.first_child.replace(node.to_owned().into());
+            }
+        }
 
-        if let Some(child) = maybe_child {
-            child.parent.replace(Some(self.into()));
-            match child.prev_sibling() {
-                | Some(prev_sibling) => {
-                    prev_sibling
-                        .next_sibling
-                        .replace(child.to_owned().into());
-                    child.prev_sibling.replace(Some(prev_sibling.into()));
-                }
-                | None => {
-                    self.first_child.replace(child.to_owned().into());
-                }
+        child.prev_sibling.replace(TreeNodeWeak::from(&node).into());
+    }
+
+    /// La racine d'un objet est lui-même, s'il n'a pas de parent, ou
+    /// sinon la racine de son parent.
+    pub fn get_root(&self) -> Self {
+        let mut root = self.to_owned();
+        while let Some(parent) = root.parent_node() {
+            root = parent;
+        }
+        root
+    }
+
+    /// Un ancêtre inclusif est un objet ou l'un de ses ancêtres.
+    pub fn is_inclusive_ancestor_of(&self, other: &Self) -> bool {
+        let mut maybe_node = Some(other.to_owned());
+        while let Some(node) = maybe_node {
+            if Rc::ptr_eq(self, &node) {
+                return true;
             }
+            maybe_node = node.parent_node();
         }
+        false
     }
 
     pub fn next_sibling(&self) -> Option<Self> {
@@ -166,6 +212,63 @@ impl<T> TreeNode<T> {
     }
 }
 
+impl<T> TreeNode<T>
+where
+    T: TreeElementInterface,
+{
+    /// Le premier enfant élément est le premier enfant qui est un
+    /// élément ; sinon null.
+    pub fn get_first_element_child(&self) -> Option<Self> {
+        let mut maybe_child = self.get_first_child();
+        while let Some(child) = maybe_child {
+            if child.is_element() {
+                return Some(child);
+            }
+            maybe_child = child.next_sibling();
+        }
+        None
+    }
+
+    /// Le dernier enfant élément est le dernier enfant qui est un
+    /// élément ; sinon null.
+    pub fn get_last_element_child(&self) -> Option<Self> {
+        let mut maybe_child = self.get_last_child();
+        while let Some(child) = maybe_child {
+            if child.is_element() {
+                return Some(child);
+            }
+            maybe_child = child.prev_sibling();
+        }
+        None
+    }
+
+    /// Le frère élément précédent est le premier frère précédent qui est
+    /// un élément ; sinon null.
+    pub fn previous_element_sibling(&self) -> Option<Self> {
+        let mut maybe_sibling = self.prev_sibling();
+        while let Some(sibling) = maybe_sibling {
+            if sibling.is_element() {
+                return Some(sibling);
+            }
+            maybe_sibling = sibling.prev_sibling();
+        }
+        None
+    }
+
+    /// Le frère élément suivant est le premier frère suivant qui est un
+    /// élément ; sinon null.
+    pub fn next_element_sibling(&self) -> Option<Self> {
+        let mut maybe_sibling = self.next_sibling();
+        while let Some(sibling) = maybe_sibling {
+            if sibling.is_element() {
+                return Some(sibling);
+            }
+            maybe_sibling = sibling.next_sibling();
+        }
+        None
+    }
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -189,3 +292,145 @@ impl<T> ops::Deref for TreeNode<T> {
         &self.node_ref
     }
 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    #[derive(PartialEq, Eq)]
+    enum Data {
+        Text(&'static str),
+        Element(&'static str),
+    }
+
+    impl TreeElementInterface for Data {
+        fn is_element(&self) -> bool {
+            matches!(self, Self::Element(_))
+        }
+    }
+
+    #[test]
+    fn test_get_first_last_element_child() {
+        let parent = TreeNode::new(Data::Element("p"));
+        parent.append_child(TreeNode::new(Data::Text("text")));
+        parent.append_child(TreeNode::new(Data::Element("a")));
+        parent.append_child(TreeNode::new(Data::Element("b")));
+        parent.append_child(TreeNode::new(Data::Text("text")));
+
+        let first = parent.get_first_element_child().unwrap();
+        assert_eq!(***first, Data::Element("a"));
+        let last = parent.get_last_element_child().unwrap();
+        assert_eq!(***last, Data::Element("b"));
+
+        let parent = TreeNode::new(Data::Element("p"));
+        assert!(parent.get_first_element_child().is_none());
+        parent.append_child(TreeNode::new(Data::Text("text")));
+        assert!(parent.get_first_element_child().is_none());
+        assert!(parent.get_last_element_child().is_none());
+    }
+
+    #[test]
+    fn test_previous_next_element_sibling() {
+        let parent = TreeNode::new(Data::Element("p"));
+        let a = TreeNode::new(Data::Element("a"));
+        let b = TreeNode::new(Data::Element("b"));
+        parent.append_child(TreeNode::new(Data::Text("text")));
+        parent.append_child(a.clone());
+        parent.append_child(TreeNode::new(Data::Text("text")));
+        parent.append_child(TreeNode::new(Data::Text("text")));
+        parent.append_child(b.clone());
+        parent.append_child(TreeNode::new(Data::Text("text")));
+
+        let next = a.next_element_sibling().unwrap();
+        assert_eq!(***next, Data::Element("b"));
+        let previous = b.previous_element_sibling().unwrap();
+        assert_eq!(***previous, Data::Element("a"));
+
+        assert!(a.previous_element_sibling().is_none());
+        assert!(b.next_element_sibling().is_none());
+    }
+
+    #[test]
+    fn test_insert_before() {
+        let children = |parent: &TreeNode<Data>| {
+            let mut names = Vec::new();
+            parent.foreach_child(|child| {
+                if let Data::Element(name) = ****child {
+                    names.push(name);
+                }
+            });
+            names
+        };
+
+        let parent = TreeNode::new(Data::Element("p"));
+        let a = TreeNode::new(Data::Element("a"));
+        let c = TreeNode::new(Data::Element("c"));
+        parent.append_child(a.clone());
+        parent.append_child(c.clone());
+
+        // Insertion juste avant le dernier enfant.
+        let b = TreeNode::new(Data::Element("b"));
+        parent.insert_before(b.clone(), Some(&c));
+        assert_eq!(children(&parent), ["a", "b", "c"]);
+        assert!(Rc::ptr_eq(&parent.get_last_child().unwrap(), &c));
+        assert!(Rc::ptr_eq(&b.next_sibling().unwrap(), &c));
+        assert!(Rc::ptr_eq(&b.prev_sibling().unwrap(), &a));
+        assert!(Rc::ptr_eq(&c.prev_sibling().unwrap(), &b));
+        assert!(Rc::ptr_eq(&a.next_sibling().unwrap(), &b));
+
+        // Insertion en tête.
+        let first = TreeNode::new(Data::Element("first"));
+        parent.insert_before(first.clone(), Some(&a));
+        assert_eq!(children(&parent), ["first", "a", "b", "c"]);
+        assert!(Rc::ptr_eq(&parent.get_first_child().unwrap(), &first));
+        assert!(first.prev_sibling().is_none());
+        assert!(Rc::ptr_eq(&a.prev_sibling().unwrap(), &first));
+
+        // Insertion au milieu.
+        let middle = TreeNode::new(Data::Element("middle"));
+        parent.insert_before(middle.clone(), Some(&b));
+        assert_eq!(children(&parent), ["first", "a", "middle", "b", "c"]);
+        assert!(Rc::ptr_eq(&middle.parent_node().unwrap(), &parent));
+
+        // Sans enfant de référence, le noeud est ajouté à la fin.
+        let last = TreeNode::new(Data::Element("last"));
+        parent.insert_before(last.clone(), None);
+        assert!(Rc::ptr_eq(&parent.get_last_child().unwrap(), &last));
+        assert!(Rc::ptr_eq(&c.next_sibling().unwrap(), &last));
+
+        // Parcours inverse depuis le dernier enfant.
+        let mut names = Vec::new();
+        let mut current = parent.get_last_child();
+        while let Some(node) = current {
+            if let Data::Element(name) = ***node {
+                names.push(name);
+            }
+            current = node.prev_sibling();
+        }
+        assert_eq!(names, ["last", "c", "b", "middle", "a", "first"]);
+    }
+
+    #[test]
+    fn test_get_root() {
+        let root = TreeNode::new(Data::Element("div"));
+        let p = TreeNode::new(Data::Element("p"));
+        let text = TreeNode::new(Data::Text("text"));
+        root.append_child(p.clone());
+        p.append_child(text.clone());
+
+        assert!(Rc::ptr_eq(&text.get_root(), &root));
+        assert!(Rc::ptr_eq(&p.get_root(), &root));
+        assert!(Rc::ptr_eq(&root.get_root(), &root));
+
+        // Un noeud détaché est sa propre racine, sans parent.
+        p.detach_node();
+        assert!(Rc::ptr_eq(&text.get_root(), &p));
+        assert!(text.get_root().parent_node().is_none());
+        assert!(p.get_root().parent_node().is_none());
+    }
+}
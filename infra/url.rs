@@ -0,0 +1,356 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Résolution minimale des URL relatives.
+//!
+//! Ce module ne remplace pas un analyseur d'URL complet : il couvre les
+//! cas usuels du HTML (`a[href]`, `img[src]`, `link[href]`, `@import`),
+//! en suivant l'algorithme de résolution des références de la
+//! [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2).
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Composants d'une URL (ou d'une référence relative) :
+/// `scheme:[//authority]path[?query][#fragment]`.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+struct URLComponents<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<'a> URLComponents<'a> {
+    fn parse(input: &'a str) -> Self {
+        let (input, fragment) = match input.split_once('#') {
+            | Some((input, fragment)) => (input, Some(fragment)),
+            | None => (input, None),
+        };
+
+        let (input, query) = match input.split_once('?') {
+            | Some((input, query)) => (input, Some(query)),
+            | None => (input, None),
+        };
+
+        let (scheme, input) = match scheme_of(input) {
+            | Some(scheme) => (Some(scheme), &input[scheme.len() + 1..]),
+            | None => (None, input),
+        };
+
+        let (authority, path) = match input.strip_prefix("//") {
+            | Some(input) => {
+                let end = input.find('/').unwrap_or(input.len());
+                (Some(&input[..end]), &input[end..])
+            }
+            | None => (None, input),
+        };
+
+        Self {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+
+    /// Sérialise les composants, avec le chemin `path`.
+    fn serialize(&self, path: &str) -> String {
+        let mut output = String::new();
+
+        let scheme = self.scheme.map(str::to_ascii_lowercase);
+        if let Some(scheme) = scheme.as_ref() {
+            output.push_str(scheme);
+            output.push(':');
+        }
+
+        if let Some(authority) = self.authority {
+            output.push_str("//");
+            output.push_str(authority);
+        }
+
+        // Le chemin d'une URL dont le schéma est spécial n'est jamais
+        // vide.
+        if path.is_empty()
+            && self.authority.is_some()
+            && scheme.as_deref().filter(is_special_scheme).is_some()
+        {
+            output.push('/');
+        } else {
+            output.push_str(path);
+        }
+
+        if let Some(query) = self.query {
+            output.push('?');
+            output.push_str(query);
+        }
+
+        if let Some(fragment) = self.fragment {
+            output.push('#');
+            output.push_str(fragment);
+        }
+
+        output
+    }
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Résout la référence `relative` par rapport à l'URL de base `base`.
+///
+/// Les espaces blancs et les caractères de contrôle C0 de début et de fin
+/// de la référence sont ignorés, ainsi que les tabulations et les sauts
+/// de ligne qu'elle contient. Une référence qui possède un schéma est
+/// retournée telle quelle, après suppression des segments `.` et `..`.
+pub fn resolve(base: &str, relative: &str) -> String {
+    let relative: String = relative
+        .trim_matches(|ch: char| ch.is_ascii_control() || ch == ' ')
+        .chars()
+        .filter(|ch| !matches!(ch, '\t' | '\n' | '\r'))
+        .collect();
+
+    let base = URLComponents::parse(base);
+    let reference = URLComponents::parse(&relative);
+
+    // NOTE(url): le fragment de l'URL de base n'est jamais conservé.
+    let (target, path) = if reference.scheme.is_some() {
+        (reference, remove_dot_segments(reference.path))
+    } else if reference.authority.is_some() {
+        (
+            URLComponents {
+                scheme: base.scheme,
+                ..reference
+            },
+            remove_dot_segments(reference.path),
+        )
+    } else if reference.path.is_empty() {
+        (
+            URLComponents {
+                query: reference.query.or(base.query),
+                fragment: reference.fragment,
+                ..base
+            },
+            base.path.to_owned(),
+        )
+    } else {
+        let path = if reference.path.starts_with('/') {
+            remove_dot_segments(reference.path)
+        } else {
+            remove_dot_segments(&merge(&base, reference.path))
+        };
+
+        (
+            URLComponents {
+                query: reference.query,
+                fragment: reference.fragment,
+                ..base
+            },
+            path,
+        )
+    };
+
+    target.serialize(&path)
+}
+
+/// Fusionne le chemin de la référence relative avec celui de l'URL de
+/// base : le dernier segment du chemin de base est remplacé.
+fn merge(base: &URLComponents, path: &str) -> String {
+    if base.authority.is_some() && base.path.is_empty() {
+        return format!("/{path}");
+    }
+
+    match base.path.rfind('/') {
+        | Some(idx) => format!("{}{path}", &base.path[..=idx]),
+        | None => path.to_owned(),
+    }
+}
+
+/// Supprime les segments `.` et `..` d'un chemin.
+///
+/// Voir <https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4>
+fn remove_dot_segments(path: &str) -> String {
+    let is_absolute = path.starts_with('/');
+    let path = path.strip_prefix('/').unwrap_or(path);
+
+    let segments: Vec<&str> = path.split('/').collect();
+    let mut output: Vec<&str> = Vec::with_capacity(segments.len());
+
+    for (idx, segment) in segments.iter().enumerate() {
+        let is_last = idx + 1 == segments.len();
+
+        match *segment {
+            | "." | "%2e" | "%2E" => {}
+            | ".." | ".%2e" | ".%2E" | "%2e." | "%2E." | "%2e%2e"
+            | "%2E%2E" | "%2e%2E" | "%2E%2e" => {
+                output.pop();
+            }
+            | segment => {
+                output.push(segment);
+                continue;
+            }
+        }
+
+        // Un chemin qui se termine par un segment `.` ou `..` désigne un
+        // répertoire.
+        if is_last {
+            output.push("");
+        }
+    }
+
+    let mut result = String::with_capacity(path.len() + 1);
+    if is_absolute {
+        result.push('/');
+    }
+    result.push_str(&output.join("/"));
+    result
+}
+
+/// Un schéma commence par une lettre ASCII, suivie de caractères
+/// alphanumériques ASCII, `+`, `-` ou `.`, et se termine par `:`.
+fn scheme_of(input: &str) -> Option<&str> {
+    let end = input.find(':')?;
+    let scheme = &input[..end];
+
+    let mut chars = scheme.chars();
+    let is_valid =
+        chars.next().filter(char::is_ascii_alphabetic).is_some()
+            && chars.all(|ch| {
+                ch.is_ascii_alphanumeric() || "+-.".contains(ch)
+            });
+
+    is_valid.then_some(scheme)
+}
+
+/// Un schéma spécial est un schéma dont la valeur est l'une des
+/// suivantes : "ftp", "file", "http", "https", "ws", "wss".
+fn is_special_scheme(scheme: &&str) -> bool {
+    matches!(*scheme, "ftp" | "file" | "http" | "https" | "ws" | "wss")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "http://a/b/c/d;p?q";
+
+    #[test]
+    fn test_resolve_normal_examples() {
+        // Voir <https://www.rfc-editor.org/rfc/rfc3986#section-5.4.1>
+        [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g/"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ]
+        .into_iter()
+        .for_each(|(relative, expected)| {
+            assert_eq!(resolve(BASE, relative), expected, "{relative}");
+        });
+    }
+
+    #[test]
+    fn test_resolve_abnormal_examples() {
+        // Voir <https://www.rfc-editor.org/rfc/rfc3986#section-5.4.2>
+        [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+        ]
+        .into_iter()
+        .for_each(|(relative, expected)| {
+            assert_eq!(resolve(BASE, relative), expected, "{relative}");
+        });
+    }
+
+    #[test]
+    fn test_resolve_html_references() {
+        let base = "https://example.com/dir/page.html?x=1#top";
+
+        assert_eq!(
+            resolve(base, "img/a.png"),
+            "https://example.com/dir/img/a.png"
+        );
+        assert_eq!(
+            resolve(base, "/style.css"),
+            "https://example.com/style.css"
+        );
+        assert_eq!(
+            resolve(base, "../script.js?v=2"),
+            "https://example.com/script.js?v=2"
+        );
+        assert_eq!(
+            resolve(base, "#section"),
+            "https://example.com/dir/page.html?x=1#section"
+        );
+        assert_eq!(
+            resolve(base, "//cdn.example.org/lib.js"),
+            "https://cdn.example.org/lib.js"
+        );
+        assert_eq!(
+            resolve(base, "HTTPS://Other.test/a/./b/../c"),
+            "https://Other.test/a/c"
+        );
+        assert_eq!(
+            resolve(base, "mailto:user@example.com"),
+            "mailto:user@example.com"
+        );
+        assert_eq!(
+            resolve(base, "  \n img/\tb.png \n"),
+            "https://example.com/dir/img/b.png"
+        );
+
+        // Le chemin d'une URL de base sans chemin est "/".
+        assert_eq!(
+            resolve("https://example.com", "a"),
+            "https://example.com/a"
+        );
+        assert_eq!(
+            resolve("https://example.com", ""),
+            "https://example.com/"
+        );
+        assert_eq!(
+            resolve("https://example.com", "?q"),
+            "https://example.com/?q"
+        );
+    }
+}
@@ -17,6 +17,11 @@ pub trait StreamIterator {
     ///   - 2 = 1
     ///   - 3 = 2
     /// etc...
+    ///
+    /// Avancer de `n` consomme exactement `n` entrées et retourne la
+    /// dernière d'entre elles. Avancer de 0 ne consomme rien et retourne
+    /// `None`. Au-delà de la fin du flux, toutes les entrées restantes
+    /// sont consommées et `None` est retourné.
     fn advance(&mut self, n: usize) -> Option<Self::Item> {
         unimplemented!("Avancer de {n} dans le flux.");
     }
@@ -10,6 +10,10 @@ pub mod decoder;
 mod preprocessor;
 
 mod interface;
+#[cfg(feature = "metrics")]
+mod metrics;
 pub mod stream;
 
 pub use interface::*;
+#[cfg(feature = "metrics")]
+pub use metrics::ParserMetrics;
@@ -0,0 +1,36 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::time::Duration;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Compteurs de profilage d'une instance d'analyseur, qui permettent de
+/// détecter les régressions de performance et les comportements
+/// pathologiques (une boucle qui émet des milliers de jetons, ...).
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct ParserMetrics {
+    /// Nombre de jetons émis.
+    pub tokens: usize,
+    /// Nombre de nœuds créés.
+    pub nodes: usize,
+    /// Temps passé à analyser.
+    pub elapsed: Duration,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ParserMetrics {
+    /// Remet les compteurs à zéro.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
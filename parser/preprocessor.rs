@@ -147,11 +147,11 @@ where
 {
     type Item = I;
 
-    fn advance(&mut self, mut n: usize) -> Option<Self::Item> {
-        if n >= 1 {
-            n -= 1;
+    fn advance(&mut self, n: usize) -> Option<Self::Item> {
+        if n == 0 {
+            return None;
         }
-        self.nth(n)
+        self.nth(n - 1)
     }
 
     fn advance_as_long_as_possible_with_limit<
@@ -190,16 +190,15 @@ where
     type Input = I;
 
     fn consume_next_input(&mut self) -> Option<Self::Input> {
-        if let Some(pre_scan) = &self.pre_scan {
+        let maybe_item = if let Some(pre_scan) = &self.pre_scan {
             (pre_scan)(self.queue.next())
         } else {
             self.queue.next()
-        }
-        .and_then(|item| {
-            let some_item = Some(item);
-            self.current_input = some_item.to_owned();
-            some_item
-        })
+        };
+        // NOTE(phisyx): à la fin du flux, l'entrée actuelle est EOF : la
+        // re-consommer ne doit pas re-consommer le dernier caractère.
+        self.current_input = maybe_item.to_owned();
+        maybe_item
     }
 
     fn current_input(&self) -> Option<&Self::Input> {
@@ -219,6 +218,9 @@ where
         self.queue.peek_until(n).unwrap_or_default()
     }
 
+    /// Remet l'entrée actuelle au début du flux : seule la dernière
+    /// entrée consommée est restituée. À la fin du flux, l'entrée actuelle
+    /// est EOF et la prochaine consommation retourne à nouveau `None`.
     fn reconsume_current_input(&mut self) {
         let cloned_current_input = self.current_input.clone();
         self.reconsume(cloned_current_input);
@@ -272,4 +274,58 @@ mod tests {
         stream.consume_next_input(); // e
         stream.reconsume_current_input(); // H
     }
+
+    #[test]
+    fn test_peek_until_does_not_consume() {
+        let mut stream = get_input_stream("abc");
+        assert_eq!(stream.peek_until::<String>(2), Some("ab".into()));
+        assert_eq!(stream.peek_until::<String>(3), Some("abc".into()));
+        assert_eq!(stream.position(), 0);
+        assert_eq!(stream.consume_next_input(), Some('a'));
+    }
+
+    #[test]
+    fn test_peek_until_beyond_the_end() {
+        let mut stream = get_input_stream("ab");
+        assert_eq!(stream.peek_until::<String>(5), Some("ab".into()));
+        assert_eq!(stream.next_n_input_character(5), "ab");
+        assert_eq!(stream.consume_next_input(), Some('a'));
+        assert_eq!(stream.consume_next_input(), Some('b'));
+        assert_eq!(stream.consume_next_input(), None);
+
+        let mut stream = get_input_stream("");
+        assert_eq!(stream.peek_until::<String>(3), Some("".into()));
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut stream = get_input_stream("abcdef");
+        assert_eq!(stream.advance(0), None);
+        assert_eq!(stream.position(), 0);
+        assert_eq!(stream.advance(1), Some('a'));
+        assert_eq!(stream.advance(3), Some('d'));
+        assert_eq!(stream.position(), 4);
+        assert_eq!(stream.consume_next_input(), Some('e'));
+
+        // Au-delà de la fin du flux.
+        assert_eq!(stream.advance(5), None);
+        assert_eq!(stream.consume_next_input(), None);
+    }
+
+    #[test]
+    fn test_reconsume_restores_a_single_input() {
+        let mut stream = get_input_stream("abc");
+        stream.consume_next_input(); // a
+        stream.consume_next_input(); // b
+        stream.reconsume_current_input();
+        assert_eq!(stream.position(), 1);
+        assert_eq!(stream.consume_next_input(), Some('b'));
+        assert_eq!(stream.consume_next_input(), Some('c'));
+        assert_eq!(stream.consume_next_input(), None);
+
+        // À la fin du flux, re-consommer ne restitue pas le dernier
+        // caractère.
+        stream.reconsume_current_input();
+        assert_eq!(stream.consume_next_input(), None);
+    }
 }
@@ -27,6 +27,7 @@ pub struct TokenStream<Token> {
     list_of_tokens: Vec<Token>,
     token_currently_being_operated_on: Option<Token>,
     reconsume_now: bool,
+    tokens_consumed: usize,
 }
 
 // -------------- //
@@ -39,6 +40,7 @@ impl<I> TokenStream<I> {
             list_of_tokens: Default::default(),
             token_currently_being_operated_on: Default::default(),
             reconsume_now: Default::default(),
+            tokens_consumed: Default::default(),
         }
     }
 }
@@ -64,6 +66,7 @@ where
             list_of_tokens: list,
             token_currently_being_operated_on: Default::default(),
             reconsume_now: Default::default(),
+            tokens_consumed: Default::default(),
         }
     }
 
@@ -76,6 +79,7 @@ where
             list_of_tokens: iter_list.collect(),
             token_currently_being_operated_on: Default::default(),
             reconsume_now: Default::default(),
+            tokens_consumed: Default::default(),
         }
     }
 }
@@ -88,14 +92,15 @@ where
         self.list_of_tokens.push(token);
     }
 
-    pub fn prepend(&mut self, token: I) {
-        let mut temp = vec![token];
-        self.list_of_tokens.splice(..0, temp.drain(..));
-    }
-
     pub fn replace_current_token_with(&mut self, token: I) {
         self.token_currently_being_operated_on.replace(token);
     }
+
+    /// Nombre de jetons retirés du flux jusqu'à présent. Les jetons
+    /// re-consommés et le jeton EOF ne sont pas comptés.
+    pub fn tokens_consumed(&self) -> usize {
+        self.tokens_consumed
+    }
 }
 
 // -------------- //
@@ -153,6 +158,7 @@ where
         } else {
             self.token_currently_being_operated_on =
                 Some(self.list_of_tokens.remove(0));
+            self.tokens_consumed += 1;
         }
 
         self.token_currently_being_operated_on.clone()
@@ -0,0 +1,136 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Test d'intégration de bout en bout : le document HTML est analysé,
+//! le contenu de son élément `<style>` est analysé comme une feuille de
+//! style, puis les règles sont appliquées aux éléments du DOM.
+
+use std::collections::HashMap;
+
+use css::parser::{
+    trim_whitespace, CSSComponentValue, CSSParser, CSSRule, CSSStyleBlock,
+    CSSToken,
+};
+use dom::node::{DocumentNode, Node, NodeTextContentInterface};
+use html::parser::HTMLParser;
+use infra::structure::tree::TreeNode;
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Le texte de l'élément `<style>` du head du document.
+fn style_sheet_text(document: &DocumentNode) -> String {
+    let head = document.head().expect("Élément head");
+    let style = head.get_first_child().expect("Élément style");
+    style.style_text_content().expect("Élément style")
+}
+
+/// Le prélude de la règle correspond-il à l'élément ? Seuls les
+/// sélecteurs simples `type`, `.class` et `#id` sont pris en charge par
+/// ce test.
+fn matches(
+    prelude: &[CSSComponentValue],
+    element: &TreeNode<Node>,
+) -> bool {
+    let element = element.element_ref();
+    let tokens = trim_whitespace(prelude)
+        .iter()
+        .flat_map(|value| value.tokens())
+        .collect::<Vec<_>>();
+
+    match tokens.as_slice() {
+        | [CSSToken::Ident(name)] => *name == element.local_name(),
+        | [CSSToken::Hash(id, _)] => {
+            element.get_attribute("id").as_ref() == Some(id)
+        }
+        | [CSSToken::Delim('.'), CSSToken::Ident(class)] => {
+            element.get_attribute("class").is_some_and(|classes| {
+                classes.split_ascii_whitespace().any(|c| c == class)
+            })
+        }
+        | _ => false,
+    }
+}
+
+/// Les styles calculés de l'élément : les déclarations des règles qui
+/// correspondent à l'élément, par ordre d'apparition.
+fn computed_style(
+    rules: &[CSSRule],
+    element: &TreeNode<Node>,
+) -> HashMap<String, String> {
+    let mut matched = rules
+        .iter()
+        .filter_map(|rule| match rule {
+            | CSSRule::QualifiedRule(rule) => Some(rule),
+            | CSSRule::AtRule(_) => None,
+        })
+        .filter(|rule| matches(rule.prelude(), element))
+        .collect::<Vec<_>>();
+    matched.sort_by_key(|rule| rule.source_order());
+
+    let mut style = HashMap::new();
+    for rule in matched {
+        for block in rule.declarations() {
+            if let CSSStyleBlock::Declaration(declaration) = block {
+                style.insert(
+                    declaration.name().to_owned(),
+                    declaration.value_string(),
+                );
+            }
+        }
+    }
+    style
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[test]
+fn test_html_style_element_cascade() {
+    let html = "<!DOCTYPE html>\
+        <html><head><style>\
+            p { color: red; margin: 0 }\n\
+            .note { color: blue; margin: 0  auto }\n\
+            #main { display: block }\n\
+        </style></head>\
+        <body><p>a</p><p class='x note'>b</p>\
+        <div id=main><p>c</p></div></body></html>";
+
+    let document = DocumentNode::new();
+    let mut parser = HTMLParser::new(document.clone(), html.chars());
+    parser.run();
+
+    let text = style_sheet_text(&document);
+    assert!(text.starts_with("p { color: red; margin: 0 }"));
+
+    let rules = CSSParser::new(text.chars()).stylesheet();
+    assert_eq!(rules.len(), 3);
+
+    let body = document.body().expect("Élément body");
+    let p1 = body.get_first_child().unwrap();
+    let p2 = p1.next_sibling().unwrap();
+    let div = p2.next_sibling().unwrap();
+    let p3 = div.get_first_child().unwrap();
+
+    let style = computed_style(&rules, &p1);
+    assert_eq!(style["color"], "red");
+    assert_eq!(style["margin"], "0");
+
+    // La règle `.note` apparaît après la règle `p`.
+    let style = computed_style(&rules, &p2);
+    assert_eq!(style["color"], "blue");
+    assert_eq!(style["margin"], "0 auto");
+
+    let style = computed_style(&rules, &p3);
+    assert_eq!(style["color"], "red");
+    assert!(!style.contains_key("display"));
+
+    let style = computed_style(&rules, &div);
+    assert_eq!(style["display"], "block");
+    assert!(!style.contains_key("color"));
+
+    assert!(computed_style(&rules, &body).is_empty());
+}